@@ -0,0 +1,12 @@
+-- Create favorite_contracts table (per-user quick access list)
+CREATE TABLE IF NOT EXISTS favorite_contracts (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    contract_id VARCHAR(56) NOT NULL,
+    label VARCHAR(100),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    UNIQUE (user_id, contract_id)
+);
+
+-- Create index on user_id for listing a user's favorites
+CREATE INDEX IF NOT EXISTS idx_favorite_contracts_user_id ON favorite_contracts(user_id);
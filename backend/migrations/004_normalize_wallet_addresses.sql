@@ -0,0 +1,21 @@
+-- Normalize stored wallet addresses to their canonical form
+-- The repository now trims and uppercases wallet addresses (and checks the strkey)
+-- before every insert and lookup, so rows stored before that must match.
+
+-- Backfill: canonicalize rows whose normalized form isn't already taken
+UPDATE users u
+SET wallet_address = UPPER(TRIM(u.wallet_address))
+WHERE u.wallet_address <> UPPER(TRIM(u.wallet_address))
+  AND NOT EXISTS (
+      SELECT 1 FROM users other
+      WHERE other.id <> u.id
+        AND other.wallet_address = UPPER(TRIM(u.wallet_address))
+  );
+
+-- Rows skipped above are duplicates of another account and need a manual merge.
+-- Checksums can't be verified in SQL; rows with an invalid strkey are left as-is
+-- and will no longer match any lookup. Find both with:
+--   SELECT id, username, wallet_address FROM users
+--   WHERE wallet_address <> '' AND (wallet_address <> UPPER(TRIM(wallet_address))
+--      OR wallet_address !~ '^G[A-Z2-7]{55}$');
+COMMENT ON COLUMN users.wallet_address IS 'Canonical G... strkey (trimmed, uppercase), or empty for password-only users';
@@ -1,16 +1,41 @@
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tracing::{info, warn, debug};
-use crate::services::stellar::XdrConfig;
-use crate::services::soroban::ScalableContractManager;
+use crate::services::stellar::{validate_tx_timeout, LocalSigner, Signer, XdrConfig, DEFAULT_TX_TIMEOUT_SECS};
+use crate::utils::DEFAULT_EXPOSED_HEADERS;
+use crate::server::ServerConfig;
+use crate::services::soroban::{AuthMode, CostLimits, NetworkType, ScalableContractManager, SimulationOptions, WarmupConfig};
 use axum::extract::FromRef;
 use sqlx::{Pool, Postgres};
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Default upper bound on a single contract call
+pub const DEFAULT_CONTRACT_CALL_TIMEOUT_SECS: u64 = 30;
+/// Shortest allowed contract call timeout
+pub const MIN_CONTRACT_CALL_TIMEOUT_SECS: u64 = 1;
+/// Longest allowed contract call timeout; past this the client has long gone
+pub const MAX_CONTRACT_CALL_TIMEOUT_SECS: u64 = 300;
+
+/// Ensure a contract call timeout is neither zero (every call would time out)
+/// nor long enough to pin handlers on a stuck RPC
+pub fn validate_contract_call_timeout(timeout_secs: u64) -> crate::error::Result<()> {
+    if !(MIN_CONTRACT_CALL_TIMEOUT_SECS..=MAX_CONTRACT_CALL_TIMEOUT_SECS).contains(&timeout_secs) {
+        return Err(crate::error::AppError::Config(format!(
+            "CONTRACT_CALL_TIMEOUT_SECS must be between {} and {}, got: {}",
+            MIN_CONTRACT_CALL_TIMEOUT_SECS, MAX_CONTRACT_CALL_TIMEOUT_SECS, timeout_secs
+        )));
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Serialize, Deserialize)]
 pub struct AppConfig {
     // Server configuration
     pub port: u16,
     pub allowed_origins: Vec<String>,
+    pub cors_exposed_headers: Vec<String>,
+    // HTTP/2 and keep-alive tuning (trade-offs documented in `server`)
+    pub server: ServerConfig,
 
     // Stellar/Soroban configuration
     pub contract_id: String,
@@ -27,6 +52,88 @@ pub struct AppConfig {
     pub cookie_secure: bool,
     pub cookie_http_only: bool,
     pub cookie_same_site: String,
+
+    // Simulation cost limits (admins get their own, higher budget)
+    pub cost_limits: CostLimits,
+    pub admin_cost_limits: CostLimits,
+
+    // Server-side signing (testnet automation only)
+    pub enable_local_signer: bool,
+    #[serde(skip_serializing)]
+    pub local_signer_secret: Option<String>,
+
+    // Background cache warmup run after the Soroban manager starts
+    pub warmup: WarmupConfig,
+
+    // Auth mode for read-only simulations when the caller doesn't specify one
+    pub simulation_auth_mode: AuthMode,
+
+    // Upper bound on a single call-function request before it is abandoned
+    pub contract_call_timeout_secs: u64,
+
+    // How long generated transactions stay valid before the network rejects them
+    pub tx_timeout_secs: u64,
+
+    // Refuse to start without a database; when false, auth is disabled instead
+    pub require_database: bool,
+}
+
+// Written by hand so secrets never end up in logs; destructured so new fields aren't missed
+impl std::fmt::Debug for AppConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const REDACTED: &str = "[REDACTED]";
+        let AppConfig {
+            port,
+            allowed_origins,
+            cors_exposed_headers,
+            server,
+            contract_id,
+            network_passphrase,
+            rpc_url,
+            jwt_secret: _,
+            jwt_expiration_hours,
+            cookie_name,
+            cookie_domain,
+            cookie_secure,
+            cookie_http_only,
+            cookie_same_site,
+            cost_limits,
+            admin_cost_limits,
+            enable_local_signer,
+            local_signer_secret,
+            warmup,
+            simulation_auth_mode,
+            contract_call_timeout_secs,
+            tx_timeout_secs,
+            require_database,
+        } = self;
+
+        f.debug_struct("AppConfig")
+            .field("port", port)
+            .field("allowed_origins", allowed_origins)
+            .field("cors_exposed_headers", cors_exposed_headers)
+            .field("server", server)
+            .field("contract_id", contract_id)
+            .field("network_passphrase", network_passphrase)
+            .field("rpc_url", rpc_url)
+            .field("jwt_secret", &REDACTED)
+            .field("jwt_expiration_hours", jwt_expiration_hours)
+            .field("cookie_name", cookie_name)
+            .field("cookie_domain", cookie_domain)
+            .field("cookie_secure", cookie_secure)
+            .field("cookie_http_only", cookie_http_only)
+            .field("cookie_same_site", cookie_same_site)
+            .field("cost_limits", cost_limits)
+            .field("admin_cost_limits", admin_cost_limits)
+            .field("enable_local_signer", enable_local_signer)
+            .field("local_signer_secret", &local_signer_secret.as_ref().map(|_| REDACTED))
+            .field("warmup", warmup)
+            .field("simulation_auth_mode", simulation_auth_mode)
+            .field("contract_call_timeout_secs", contract_call_timeout_secs)
+            .field("tx_timeout_secs", tx_timeout_secs)
+            .field("require_database", require_database)
+            .finish()
+    }
 }
 
 impl Default for AppConfig {
@@ -41,6 +148,8 @@ impl Default for AppConfig {
                 "http://localhost:8083".to_string(),
                 "http://127.0.0.1:8083".to_string(),
             ],
+            cors_exposed_headers: DEFAULT_EXPOSED_HEADERS.iter().map(|h| h.to_string()).collect(),
+            server: ServerConfig::default(),
             contract_id: "CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF".to_string(),
             network_passphrase: "Test SDF Network ; September 2015".to_string(),
             rpc_url: "https://soroban-testnet.stellar.org".to_string(),
@@ -51,6 +160,15 @@ impl Default for AppConfig {
             cookie_secure: false,
             cookie_http_only: true,
             cookie_same_site: "Lax".to_string(),
+            cost_limits: CostLimits::standard(),
+            admin_cost_limits: CostLimits::admin(),
+            enable_local_signer: false,
+            local_signer_secret: None,
+            warmup: WarmupConfig::default(),
+            simulation_auth_mode: AuthMode::Record,
+            contract_call_timeout_secs: DEFAULT_CONTRACT_CALL_TIMEOUT_SECS,
+            tx_timeout_secs: DEFAULT_TX_TIMEOUT_SECS,
+            require_database: true,
         }
     }
 }
@@ -70,6 +188,13 @@ impl AppConfig {
                 .map(|s| s.trim().to_string())
                 .filter(|s| !s.is_empty())
                 .collect(),
+            cors_exposed_headers: std::env::var("CORS_EXPOSED_HEADERS")
+                .unwrap_or_else(|_| DEFAULT_EXPOSED_HEADERS.join(","))
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect(),
+            server: ServerConfig::from_env(),
             contract_id: std::env::var("CONTRACT_ID")
                 .unwrap_or_else(|_| "CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF".to_string()),
             network_passphrase: std::env::var("NETWORK_PASSPHRASE")
@@ -103,6 +228,59 @@ impl AppConfig {
                 .unwrap_or(true),
             cookie_same_site: std::env::var("COOKIE_SAME_SITE")
                 .unwrap_or_else(|_| "Lax".to_string()),
+
+            // Simulation cost limits
+            cost_limits: CostLimits {
+                max_cpu_instructions: std::env::var("MAX_CPU_INSTRUCTIONS")
+                    .unwrap_or_else(|_| CostLimits::standard().max_cpu_instructions.to_string())
+                    .parse()
+                    .unwrap_or(CostLimits::standard().max_cpu_instructions),
+                max_resource_fee: std::env::var("MAX_RESOURCE_FEE")
+                    .unwrap_or_else(|_| CostLimits::standard().max_resource_fee.to_string())
+                    .parse()
+                    .unwrap_or(CostLimits::standard().max_resource_fee),
+            },
+            admin_cost_limits: CostLimits {
+                max_cpu_instructions: std::env::var("ADMIN_MAX_CPU_INSTRUCTIONS")
+                    .unwrap_or_else(|_| CostLimits::admin().max_cpu_instructions.to_string())
+                    .parse()
+                    .unwrap_or(CostLimits::admin().max_cpu_instructions),
+                max_resource_fee: std::env::var("ADMIN_MAX_RESOURCE_FEE")
+                    .unwrap_or_else(|_| CostLimits::admin().max_resource_fee.to_string())
+                    .parse()
+                    .unwrap_or(CostLimits::admin().max_resource_fee),
+            },
+
+            // Server-side signing
+            enable_local_signer: std::env::var("ENABLE_LOCAL_SIGNER")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .unwrap_or(false),
+            local_signer_secret: std::env::var("LOCAL_SIGNER_SECRET").ok(),
+
+            // Cache warmup
+            warmup: WarmupConfig::from_env(),
+
+            // Simulation auth mode for reads
+            simulation_auth_mode: std::env::var("SIMULATION_AUTH_MODE")
+                .ok()
+                .and_then(|mode| AuthMode::parse(&mode))
+                .unwrap_or(AuthMode::Record),
+
+            contract_call_timeout_secs: std::env::var("CONTRACT_CALL_TIMEOUT_SECS")
+                .unwrap_or_else(|_| DEFAULT_CONTRACT_CALL_TIMEOUT_SECS.to_string())
+                .parse()
+                .unwrap_or(DEFAULT_CONTRACT_CALL_TIMEOUT_SECS),
+
+            tx_timeout_secs: std::env::var("TX_TIMEOUT_SECS")
+                .unwrap_or_else(|_| DEFAULT_TX_TIMEOUT_SECS.to_string())
+                .parse()
+                .unwrap_or(DEFAULT_TX_TIMEOUT_SECS),
+
+            require_database: std::env::var("REQUIRE_DATABASE")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse()
+                .unwrap_or(true),
         }
     }
 
@@ -131,6 +309,9 @@ impl AppConfig {
             return Err(crate::error::AppError::Config("RPC URL cannot be empty".to_string()));
         }
 
+        validate_tx_timeout(self.tx_timeout_secs)?;
+        validate_contract_call_timeout(self.contract_call_timeout_secs)?;
+
         // JWT validation
         if self.jwt_secret.len() < 32 {
             warn!("[CONFIG] ❌ JWT_SECRET is too short ({} characters), must be at least 32 characters", self.jwt_secret.len());
@@ -154,6 +335,19 @@ impl AppConfig {
             return Err(crate::error::AppError::Config(format!("COOKIE_SAME_SITE must be 'Strict', 'Lax', or 'None', got: {}", self.cookie_same_site)));
         }
 
+        // Local signer validation - never allowed outside testnet
+        if self.enable_local_signer {
+            if !self.is_testnet() {
+                return Err(crate::error::AppError::Config("ENABLE_LOCAL_SIGNER is only allowed on testnet".to_string()));
+            }
+
+            if self.local_signer_secret.as_deref().unwrap_or("").is_empty() {
+                return Err(crate::error::AppError::Config("LOCAL_SIGNER_SECRET must be set when ENABLE_LOCAL_SIGNER=true".to_string()));
+            }
+
+            warn!("[CONFIG] ⚠️  Local signer enabled - server-side signing is for testnet automation only");
+        }
+
         info!("[CONFIG] ✅ Configuration validation passed");
         Ok(())
     }
@@ -162,6 +356,36 @@ impl AppConfig {
     pub fn jwt_expiration_seconds(&self) -> i64 {
         self.jwt_expiration_hours * 3600
     }
+
+    /// Whether the configured network is the public testnet
+    pub fn is_testnet(&self) -> bool {
+        NetworkType::from_passphrase(&self.network_passphrase) == Some(NetworkType::Testnet)
+    }
+
+    /// How long a contract call may run before the handler gives up on it
+    pub fn contract_call_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.contract_call_timeout_secs)
+    }
+
+    /// Simulation options for a read, falling back to the configured auth mode
+    ///
+    /// Record is the right default for reads: it records whatever authorization the
+    /// call would need instead of enforcing signatures nobody has provided, so view
+    /// functions never fail with "auth required" just because the RPC's default mode
+    /// differs between networks.
+    pub fn read_simulation_options(&self, requested: Option<SimulationOptions>) -> SimulationOptions {
+        requested
+            .unwrap_or_default()
+            .with_default_auth_mode(self.simulation_auth_mode)
+    }
+
+    /// Get the simulation cost limits that apply to a (possibly anonymous) user
+    pub fn cost_limits_for(&self, user: Option<&crate::middleware::CurrentUser>) -> CostLimits {
+        match user {
+            Some(user) if user.is_admin() => self.admin_cost_limits,
+            _ => self.cost_limits,
+        }
+    }
 }
 
 /// AppState is the shared application state available to all handlers
@@ -172,6 +396,9 @@ pub struct AppState {
     pub xdr_config: XdrConfig,
     pub soroban_manager: Option<Arc<ScalableContractManager>>,
     pub pool: Pool<Postgres>,
+    /// False when running degraded without a database (`pool` is a placeholder)
+    pub database_available: bool,
+    pub signer: Option<Arc<dyn Signer>>,
 }
 
 // Implement FromRef to allow extracting Config from AppState
@@ -197,13 +424,18 @@ impl AppState {
             contract_id: config.contract_id.clone(),
             network_passphrase: config.network_passphrase.clone(),
             rpc_url: config.rpc_url.clone(),
+            tx_timeout_secs: config.tx_timeout_secs,
         };
 
+        let signer = Self::create_signer(&config)?;
+
         Ok(Self {
             config,
             xdr_config,
             soroban_manager: None,
             pool,
+            database_available: true,
+            signer,
         })
     }
 
@@ -211,20 +443,119 @@ impl AppState {
     pub async fn with_soroban_manager(config: AppConfig, pool: Pool<Postgres>) -> crate::error::Result<Self> {
         config.validate()?;
 
+        // Initialize the ScalableContractManager
+        let manager = Arc::new(ScalableContractManager::new().await?);
+
+        Self::with_manager(config, pool, manager)
+    }
+
+    /// Create AppState around an already constructed manager (e.g. one backed by a mock RPC)
+    pub fn with_manager(
+        config: AppConfig,
+        pool: Pool<Postgres>,
+        manager: Arc<ScalableContractManager>,
+    ) -> crate::error::Result<Self> {
+        config.validate()?;
+
         let xdr_config = XdrConfig {
             contract_id: config.contract_id.clone(),
             network_passphrase: config.network_passphrase.clone(),
             rpc_url: config.rpc_url.clone(),
+            tx_timeout_secs: config.tx_timeout_secs,
         };
 
-        // Initialize the ScalableContractManager
-        let manager = ScalableContractManager::new().await?;
+        let signer = Self::create_signer(&config)?;
+
+        // Warm the cache in the background so startup isn't blocked on RPC
+        if config.warmup.enabled && !config.warmup.calls.is_empty() {
+            let manager = manager.clone();
+            let calls = config.warmup.calls.clone();
+            let options = config.read_simulation_options(None);
+            tokio::spawn(async move {
+                manager.warm_cache(&calls, &options).await;
+            });
+        }
 
         Ok(Self {
             config,
             xdr_config,
-            soroban_manager: Some(Arc::new(manager)),
+            soroban_manager: Some(manager),
             pool,
+            database_available: true,
+            signer,
         })
     }
-}
\ No newline at end of file
+
+    /// Mark the database as unavailable, so auth routes answer 503 instead of querying `pool`
+    pub fn without_database(self) -> Self {
+        Self {
+            database_available: false,
+            ..self
+        }
+    }
+
+    /// Create the local signer if it is enabled in config
+    fn create_signer(config: &AppConfig) -> crate::error::Result<Option<Arc<dyn Signer>>> {
+        if !config.enable_local_signer {
+            return Ok(None);
+        }
+
+        let secret = config.local_signer_secret.as_deref().unwrap_or_default();
+        let signer = LocalSigner::from_secret(secret)?;
+        info!("[CONFIG] Local signer enabled for account {}", crate::utils::truncate_address(&signer.public_key()));
+
+        Ok(Some(Arc::new(signer)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let config = AppConfig {
+            jwt_secret: "jwt-secret-value".to_string(),
+            local_signer_secret: Some("SSIGNERSECRETVALUE".to_string()),
+            ..AppConfig::default()
+        };
+
+        let debug = format!("{:?}", config);
+
+        assert!(!debug.contains("jwt-secret-value"));
+        assert!(!debug.contains("SSIGNERSECRETVALUE"));
+        assert!(debug.contains("local_signer_secret: Some(\"[REDACTED]\")"));
+        assert!(debug.contains("rpc_url"));
+    }
+
+    #[test]
+    fn test_is_testnet_uses_network_type() {
+        let testnet = AppConfig {
+            network_passphrase: format!(" {} ", NetworkType::Testnet.default_passphrase()),
+            ..AppConfig::default()
+        };
+        let mainnet = AppConfig {
+            network_passphrase: NetworkType::Mainnet.default_passphrase().to_string(),
+            ..AppConfig::default()
+        };
+
+        assert!(testnet.is_testnet());
+        assert!(!mainnet.is_testnet());
+    }
+
+    #[test]
+    fn test_contract_call_timeout_bounds() {
+        assert!(validate_contract_call_timeout(DEFAULT_CONTRACT_CALL_TIMEOUT_SECS).is_ok());
+        assert!(validate_contract_call_timeout(MIN_CONTRACT_CALL_TIMEOUT_SECS).is_ok());
+        assert!(validate_contract_call_timeout(MAX_CONTRACT_CALL_TIMEOUT_SECS).is_ok());
+
+        assert!(matches!(
+            validate_contract_call_timeout(0),
+            Err(crate::error::AppError::Config(_))
+        ));
+        assert!(matches!(
+            validate_contract_call_timeout(86_400),
+            Err(crate::error::AppError::Config(_))
+        ));
+    }
+}
@@ -1,3 +1,4 @@
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
 use sqlx::PgPool;
 use tracing::{info, error};
 
@@ -5,7 +6,7 @@ pub type DbPool = PgPool;
 
 pub async fn create_pool(database_url: &str) -> Result<DbPool, sqlx::Error> {
     info!("Attempting database connection...");
-    info!("   Database URL: {}...", &database_url[..50]);
+    info!("   Database URL: {}...", &database_url[..50.min(database_url.len())]);
 
     match PgPool::connect(database_url).await {
         Ok(pool) => {
@@ -28,4 +29,12 @@ pub async fn create_pool(database_url: &str) -> Result<DbPool, sqlx::Error> {
             Err(e)
         }
     }
+}
+
+/// A pool that never connects until used, standing in when no database is available
+///
+/// Lets the server start degraded; routes that need the database must not be
+/// served (see `AppState::without_database`).
+pub fn unavailable_pool() -> DbPool {
+    PgPoolOptions::new().connect_lazy_with(PgConnectOptions::new())
 }
\ No newline at end of file
@@ -106,4 +106,14 @@ impl User {
     pub fn is_chapter_lead(&self) -> bool {
         self.has_role(UserRole::ChapterLead)
     }
-}
\ No newline at end of file
+}
+
+/// A contract pinned by a user for quick access
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct FavoriteContract {
+    pub id: i32,
+    pub user_id: i32,
+    pub contract_id: String,
+    pub label: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,89 @@
+use crate::database::models::FavoriteContract;
+use crate::database::connection::DbPool;
+use sqlx::Error as SqlxError;
+use tracing::{info, error, debug};
+
+pub struct FavoriteRepository;
+
+impl FavoriteRepository {
+    /// Add a contract to the user's favorites (updates the label if already present)
+    pub async fn add(
+        pool: &DbPool,
+        user_id: i32,
+        contract_id: &str,
+        label: Option<&str>,
+    ) -> Result<FavoriteContract, SqlxError> {
+        info!("[REPOSITORY] Adding favorite - user_id={}, contract={}", user_id, contract_id);
+
+        let result = sqlx::query_as::<_, FavoriteContract>(
+            r#"
+            INSERT INTO favorite_contracts (user_id, contract_id, label, created_at)
+            VALUES ($1, $2, $3, NOW())
+            ON CONFLICT (user_id, contract_id) DO UPDATE SET label = EXCLUDED.label
+            RETURNING id, user_id, contract_id, label, created_at
+            "#
+        )
+        .bind(user_id)
+        .bind(contract_id)
+        .bind(label)
+        .fetch_one(pool)
+        .await;
+
+        match result {
+            Ok(favorite) => {
+                info!("[REPOSITORY] ✅ Favorite saved - id={}, user_id={}", favorite.id, favorite.user_id);
+                Ok(favorite)
+            }
+            Err(e) => {
+                error!("[REPOSITORY] ❌ Failed to save favorite: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// Remove a contract from the user's favorites
+    /// Returns false if it was not a favorite of this user
+    pub async fn remove(
+        pool: &DbPool,
+        user_id: i32,
+        contract_id: &str,
+    ) -> Result<bool, SqlxError> {
+        info!("[REPOSITORY] Removing favorite - user_id={}, contract={}", user_id, contract_id);
+
+        let result = sqlx::query(
+            r#"
+            DELETE FROM favorite_contracts
+            WHERE user_id = $1 AND contract_id = $2
+            "#
+        )
+        .bind(user_id)
+        .bind(contract_id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// List the user's favorites, most recently added first
+    pub async fn list_for_user(
+        pool: &DbPool,
+        user_id: i32,
+    ) -> Result<Vec<FavoriteContract>, SqlxError> {
+        debug!("[REPOSITORY] Listing favorites for user_id={}", user_id);
+
+        let favorites = sqlx::query_as::<_, FavoriteContract>(
+            r#"
+            SELECT id, user_id, contract_id, label, created_at
+            FROM favorite_contracts
+            WHERE user_id = $1
+            ORDER BY created_at DESC, id DESC
+            "#
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await?;
+
+        debug!("[REPOSITORY] ✅ Found {} favorites for user_id={}", favorites.len(), user_id);
+        Ok(favorites)
+    }
+}
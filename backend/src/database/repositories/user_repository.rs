@@ -2,9 +2,15 @@ use crate::database::models::User;
 use crate::database::connection::DbPool;
 use sqlx::{Error as SqlxError, Row};
 use tracing::{info, error, debug};
+use crate::utils::{normalize_wallet_address, truncate_address};
 
 pub struct UserRepository;
 
+/// Canonical wallet address, or `InvalidArgument` so callers can answer 400
+fn normalized_wallet(wallet_address: &str) -> Result<String, SqlxError> {
+    normalize_wallet_address(wallet_address).map_err(SqlxError::InvalidArgument)
+}
+
 impl UserRepository {
     /// Create a new guest user (wallet-only, no password)
     pub async fn create_guest(
@@ -12,7 +18,8 @@ impl UserRepository {
         username: &str,
         wallet_address: &str,
     ) -> Result<User, SqlxError> {
-        info!("[REPOSITORY] Inserting new guest user - username={}, wallet={}", username, wallet_address);
+        let wallet_address = &normalized_wallet(wallet_address)?;
+        info!("[REPOSITORY] Inserting new guest user - username={}, wallet={}", username, truncate_address(wallet_address));
 
         let result = sqlx::query(
             r#"
@@ -137,6 +144,7 @@ impl UserRepository {
         pool: &DbPool,
         wallet_address: &str,
     ) -> Result<Option<User>, SqlxError> {
+        let wallet_address = &normalized_wallet(wallet_address)?;
         debug!("[REPOSITORY] Querying user by wallet_address={}", wallet_address);
 
         let row = sqlx::query(
@@ -214,7 +222,8 @@ impl UserRepository {
         wallet_address: &str,
         new_username: &str,
     ) -> Result<User, SqlxError> {
-        info!("[REPOSITORY] Updating username for wallet={}", wallet_address);
+        let wallet_address = &normalized_wallet(wallet_address)?;
+        info!("[REPOSITORY] Updating username for wallet={}", truncate_address(wallet_address));
 
         let row = sqlx::query(
             r#"
@@ -273,7 +282,8 @@ impl UserRepository {
         user_id: i32,
         wallet_address: &str,
     ) -> Result<User, SqlxError> {
-        info!("[REPOSITORY] Linking wallet to user_id={}, wallet={}", user_id, wallet_address);
+        let wallet_address = &normalized_wallet(wallet_address)?;
+        info!("[REPOSITORY] Linking wallet to user_id={}, wallet={}", user_id, truncate_address(wallet_address));
 
         // Check if wallet is already linked to another account
         if let Some(existing_user) = Self::find_by_wallet_address(pool, wallet_address).await? {
@@ -308,7 +318,7 @@ impl UserRepository {
             last_login: row.try_get("last_login")?,
         };
 
-        info!("[REPOSITORY] ✅ Wallet linked successfully - user_id={}, wallet={}", user.id, user.wallet_address);
+        info!("[REPOSITORY] ✅ Wallet linked successfully - user_id={}, wallet={}", user.id, truncate_address(&user.wallet_address));
         Ok(user)
     }
 }
\ No newline at end of file
@@ -3,7 +3,45 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+
+/// Standard JSON-RPC 2.0 error codes returned by Soroban RPC
+pub mod rpc_codes {
+    pub const PARSE_ERROR: i64 = -32700;
+    pub const INVALID_REQUEST: i64 = -32600;
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    pub const INVALID_PARAMS: i64 = -32602;
+    pub const INTERNAL_ERROR: i64 = -32603;
+}
+
+/// JSON-RPC error object (`{"code", "message", "data"}`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+impl RpcError {
+    /// Parse the `error` member of a JSON-RPC response
+    ///
+    /// Non-conforming errors (e.g. a bare string) keep their text with the
+    /// internal error code, so nothing is lost.
+    pub fn from_value(error: &serde_json::Value) -> Self {
+        serde_json::from_value(error.clone()).unwrap_or_else(|_| Self {
+            code: rpc_codes::INTERNAL_ERROR,
+            message: error.as_str().map(str::to_string).unwrap_or_else(|| error.to_string()),
+            data: None,
+        })
+    }
+}
+
+impl std::fmt::Display for RpcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (code {})", self.message, self.code)
+    }
+}
 
 #[derive(Debug, thiserror::Error)]
 pub enum AppError {
@@ -13,6 +51,10 @@ pub enum AppError {
     #[error("Stellar RPC error: {0}")]
     StellarRpc(String),
 
+    /// Structured JSON-RPC error returned by the RPC server
+    #[error("Stellar RPC error: {0}")]
+    Rpc(RpcError),
+
     #[error("Transaction error: {0}")]
     Transaction(String),
 
@@ -52,6 +94,31 @@ pub enum AppError {
 
     #[error("Not found: {0}")]
     NotFound(String),
+
+    // Contract interaction errors
+    #[error("Function not found: {0}")]
+    FunctionNotFound(String),
+
+    #[error("Cost limit exceeded: {0}")]
+    CostLimitExceeded(String),
+
+    #[error("Invalid contract id: {0}")]
+    InvalidContractId(String),
+
+    /// The contract itself rejected the call (e.g. insufficient balance, unauthorized)
+    #[error("Contract error: {0}")]
+    Contract(String),
+
+    #[error("Timed out: {0}")]
+    Timeout(String),
+
+    /// Server-side capacity limit reached; the client should retry shortly
+    #[error("Too many requests: {0}")]
+    TooManyRequests(String),
+
+    /// Running without a database, so accounts can't be looked up
+    #[error("Authentication unavailable: {0}")]
+    AuthUnavailable(String),
 }
 
 impl AppError {
@@ -60,7 +127,13 @@ impl AppError {
             AppError::Config(_) | AppError::Internal(_) | AppError::TaskExecution(_) | AppError::Database(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
-            AppError::InvalidInput(_) | AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            AppError::InvalidInput(_)
+            | AppError::BadRequest(_)
+            | AppError::FunctionNotFound(_)
+            | AppError::CostLimitExceeded(_)
+            | AppError::InvalidContractId(_) => {
+                StatusCode::BAD_REQUEST
+            }
             AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
             AppError::Forbidden(_) => StatusCode::FORBIDDEN,
             AppError::Conflict(_) => StatusCode::CONFLICT,
@@ -68,7 +141,17 @@ impl AppError {
             AppError::StellarRpc(_) | AppError::Transaction(_) | AppError::Account(_) => {
                 StatusCode::BAD_GATEWAY
             }
-            AppError::XdrEncoding(_) | AppError::XdrDecoding(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::XdrEncoding(_) | AppError::XdrDecoding(_) | AppError::Contract(_) => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+            AppError::Timeout(_) => StatusCode::GATEWAY_TIMEOUT,
+            AppError::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
+            AppError::AuthUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            AppError::Rpc(error) => match error.code {
+                rpc_codes::INVALID_REQUEST | rpc_codes::INVALID_PARAMS => StatusCode::BAD_REQUEST,
+                rpc_codes::METHOD_NOT_FOUND => StatusCode::NOT_IMPLEMENTED,
+                _ => StatusCode::BAD_GATEWAY,
+            },
         }
     }
 
@@ -89,6 +172,19 @@ impl AppError {
             AppError::Forbidden(_) => "FORBIDDEN",
             AppError::Conflict(_) => "CONFLICT",
             AppError::NotFound(_) => "NOT_FOUND",
+            AppError::FunctionNotFound(_) => "FUNCTION_NOT_FOUND",
+            AppError::CostLimitExceeded(_) => "COST_LIMIT_EXCEEDED",
+            AppError::InvalidContractId(_) => "INVALID_CONTRACT_ID",
+            AppError::Contract(_) => "CONTRACT_ERROR",
+            AppError::Timeout(_) => "TIMEOUT",
+            AppError::TooManyRequests(_) => "TOO_MANY_REQUESTS",
+            AppError::AuthUnavailable(_) => "AUTH_UNAVAILABLE",
+            AppError::Rpc(error) => match error.code {
+                rpc_codes::INVALID_REQUEST => "RPC_INVALID_REQUEST",
+                rpc_codes::INVALID_PARAMS => "RPC_INVALID_PARAMS",
+                rpc_codes::METHOD_NOT_FOUND => "RPC_METHOD_NOT_FOUND",
+                _ => "STELLAR_RPC_ERROR",
+            },
         }
     }
 }
@@ -100,16 +196,27 @@ struct ErrorResponse {
     message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     details: Option<String>,
+    /// JSON-RPC error code, for errors returned by the RPC server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<i64>,
 }
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let status = self.status_code();
+        let (details, code) = match &self {
+            AppError::Rpc(error) => (
+                error.data.as_ref().map(|d| d.as_str().map(str::to_string).unwrap_or_else(|| d.to_string())),
+                Some(error.code),
+            ),
+            _ => (None, None),
+        };
         let error_response = ErrorResponse {
             success: false,
             error_type: self.error_type(),
             message: self.to_string(),
-            details: None,
+            details,
+            code,
         };
 
         (status, Json(error_response)).into_response()
@@ -124,8 +231,105 @@ impl From<tokio::task::JoinError> for AppError {
     }
 }
 
+impl From<RpcError> for AppError {
+    fn from(err: RpcError) -> Self {
+        AppError::Rpc(err)
+    }
+}
+
 impl From<sqlx::Error> for AppError {
     fn from(err: sqlx::Error) -> Self {
-        AppError::Database(err.to_string())
+        match err {
+            // Repositories reject bad input (e.g. malformed wallet addresses) this way
+            sqlx::Error::InvalidArgument(message) => AppError::InvalidInput(message),
+            err => AppError::Database(err.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::BodyExt;
+
+    #[test]
+    fn test_rpc_error_codes_map_to_statuses() {
+        let invalid_params: AppError = RpcError::from_value(&serde_json::json!({
+            "code": -32602,
+            "message": "invalid parameters",
+            "data": "startLedger must be positive"
+        })).into();
+        assert_eq!(invalid_params.status_code(), StatusCode::BAD_REQUEST);
+        assert_eq!(invalid_params.error_type(), "RPC_INVALID_PARAMS");
+
+        let method_not_found: AppError = RpcError::from_value(&serde_json::json!({
+            "code": -32601,
+            "message": "method not found"
+        })).into();
+        assert_eq!(method_not_found.status_code(), StatusCode::NOT_IMPLEMENTED);
+        assert_eq!(method_not_found.error_type(), "RPC_METHOD_NOT_FOUND");
+
+        let internal: AppError = RpcError::from_value(&serde_json::json!({
+            "code": -32603,
+            "message": "internal error"
+        })).into();
+        assert_eq!(internal.status_code(), StatusCode::BAD_GATEWAY);
+        assert_eq!(internal.error_type(), "STELLAR_RPC_ERROR");
+    }
+
+    #[test]
+    fn test_non_conforming_rpc_error_keeps_text() {
+        let error = RpcError::from_value(&serde_json::json!("rate limited"));
+        assert_eq!(error.code, rpc_codes::INTERNAL_ERROR);
+        assert_eq!(error.message, "rate limited");
+    }
+
+    #[tokio::test]
+    async fn test_rpc_error_response_includes_code() {
+        let error: AppError = RpcError {
+            code: rpc_codes::INVALID_PARAMS,
+            message: "invalid parameters".to_string(),
+            data: Some(serde_json::json!("bad key")),
+        }.into();
+
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["code"], -32602);
+        assert_eq!(json["error_type"], "RPC_INVALID_PARAMS");
+        assert_eq!(json["details"], "bad key");
+    }
+
+    #[tokio::test]
+    async fn test_contract_error_is_422_and_transport_error_is_5xx() {
+        let contract = AppError::Contract("contract error 10: insufficient balance".to_string());
+        assert_eq!(contract.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let response = contract.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["error_type"], "CONTRACT_ERROR");
+        assert_eq!(json["message"], "Contract error: contract error 10: insufficient balance");
+
+        let transport = AppError::StellarRpc("connection refused".to_string());
+        assert!(transport.status_code().is_server_error());
+        assert_eq!(transport.error_type(), "STELLAR_RPC_ERROR");
+    }
+
+    #[test]
+    fn test_timeout_is_504() {
+        let timeout = AppError::Timeout("contract call exceeded 30s".to_string());
+        assert_eq!(timeout.status_code(), StatusCode::GATEWAY_TIMEOUT);
+        assert_eq!(timeout.error_type(), "TIMEOUT");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_too_many_requests_is_429() {
+        let error = AppError::TooManyRequests("simulation limit reached".to_string());
+        assert_eq!(error.status_code(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(error.error_type(), "TOO_MANY_REQUESTS");
+    }
+}
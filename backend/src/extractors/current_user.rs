@@ -53,6 +53,33 @@ impl std::ops::Deref for CurrentUser {
     }
 }
 
+/// Extractor for an optional CurrentUser
+/// Never rejects; yields `None` for anonymous requests
+///
+/// Pair with `optional_auth_middleware` on public routes:
+/// ```ignore
+/// async fn my_handler(MaybeCurrentUser(user): MaybeCurrentUser) -> impl IntoResponse {
+///     format!("Admin: {}", user.map(|u| u.is_admin()).unwrap_or(false))
+/// }
+/// ```
+pub struct MaybeCurrentUser(pub Option<crate::middleware::auth::CurrentUser>);
+
+impl<S> FromRequestParts<S> for MaybeCurrentUser
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(MaybeCurrentUser(
+            parts
+                .extensions
+                .get::<crate::middleware::auth::CurrentUser>()
+                .cloned(),
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
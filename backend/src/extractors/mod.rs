@@ -3,4 +3,4 @@
 pub mod current_user;
 
 // Re-export for convenience
-pub use current_user::CurrentUser;
+pub use current_user::{CurrentUser, MaybeCurrentUser};
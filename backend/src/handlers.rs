@@ -1,4 +1,8 @@
+pub mod account;
 pub mod auth;
+pub mod favorites;
+pub mod leaderboard;
+pub mod payment;
 pub mod soroban;
 
 use axum::{extract::Query, response::Json, extract::State};
@@ -6,16 +10,20 @@ use tracing::info;
 
 use crate::config::AppState;
 use crate::error::Result;
+use crate::extractors::MaybeCurrentUser;
 use crate::types::{XdrRequest, XdrResponse, SubmitRequest, SubmitResponse, HealthResponse};
-use crate::services::stellar::{generate_hello_yew_xdr, submit_signed_transaction};
+use crate::services::stellar::{estimate_hello_yew_fee, generate_hello_yew_transaction, submit_signed_transaction};
+use crate::types::ContractFunction;
 use crate::utils::truncate_address;
+use shared::dto::soroban::ListFunctionsResponse;
 
 pub async fn generate_xdr_handler(
     State(state): State<AppState>,
+    MaybeCurrentUser(user): MaybeCurrentUser,
     Query(params): Query<XdrRequest>,
 ) -> Result<Json<XdrResponse>> {
     let wallet_info = params.wallet_type.as_deref().unwrap_or("unknown");
-    info!("XDR generation request received for account: {} (wallet: {})", params.source_account, wallet_info);
+    info!("XDR generation request received for account: {} (wallet: {})", truncate_address(&params.source_account), wallet_info);
 
     params.validate()?;
 
@@ -32,12 +40,25 @@ pub async fn generate_xdr_handler(
     info!("Using contract: {}", xdr_config.contract_id);
     info!("Network: {}", xdr_config.network_passphrase);
 
+    // Fee-only dry run: skip the envelope entirely
+    if params.estimate_only {
+        let estimate = estimate_hello_yew_fee(&xdr_config, &source_account, &function).await?;
+        info!("Fee estimate generated for {} wallet", wallet_info);
+        return Ok(Json(XdrResponse::estimate(
+            estimate,
+            &xdr_config,
+            format!("Fee estimated for {} wallet", wallet_info),
+        )));
+    }
+
     // Directly await the async function - no need for spawn_blocking
-    let xdr = generate_hello_yew_xdr(&xdr_config, &source_account, &function).await?;
+    let cost_limits = state.config.cost_limits_for(user.as_ref());
+    let generated = generate_hello_yew_transaction(&xdr_config, &source_account, &function, &cost_limits).await?;
 
     info!("XDR generated successfully for {} wallet signing", wallet_info);
     Ok(Json(XdrResponse::success(
-        xdr,
+        generated,
+        &xdr_config,
         format!("XDR generated successfully for {} wallet", wallet_info),
     )))
 }
@@ -69,6 +90,17 @@ pub async fn submit_transaction_handler(
     )))
 }
 
+/// List the contract functions the generate-xdr flow can build, for client-side pickers
+pub async fn list_functions_handler() -> Json<ListFunctionsResponse> {
+    let functions: Vec<_> = ContractFunction::all_functions().iter().map(ContractFunction::info).collect();
+    let count = functions.len();
+
+    Json(ListFunctionsResponse {
+        success: true,
+        functions,
+        count,
+    })
+}
 
 pub async fn health_handler() -> Json<HealthResponse> {
     Json(HealthResponse::healthy())
@@ -0,0 +1,44 @@
+use axum::{extract::{Path, State}, response::Json};
+use tracing::info;
+
+use crate::config::AppState;
+use crate::error::Result;
+use crate::utils::truncate_address;
+use crate::services::account::get_account;
+use shared::dto::account::AccountResponse;
+
+/// Look up a classic account's native balance and sub-entries
+///
+/// Unfunded addresses are not an error: they return `funded: false` so the
+/// frontend can prompt the user to fund the account.
+pub async fn get_account_handler(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+) -> Result<Json<AccountResponse>> {
+    info!("[HANDLER] Account lookup - address: {}", truncate_address(&address));
+
+    let lookup = get_account(&state.xdr_config, &address).await?;
+
+    let response = match lookup.account {
+        Some(account) => AccountResponse {
+            address,
+            funded: true,
+            balance: Some(account.balance_xlm()),
+            balance_stroops: Some(account.balance_stroops),
+            sequence: Some(account.sequence.to_string()),
+            num_sub_entries: Some(account.num_sub_entries),
+            latest_ledger: lookup.latest_ledger,
+        },
+        None => AccountResponse {
+            address,
+            funded: false,
+            balance: None,
+            balance_stroops: None,
+            sequence: None,
+            num_sub_entries: None,
+            latest_ledger: lookup.latest_ledger,
+        },
+    };
+
+    Ok(Json(response))
+}
@@ -4,6 +4,7 @@ use crate::database::repositories::user_repository::UserRepository;
 use crate::config::AppConfig;
 use crate::extractors::CurrentUser;
 use crate::error::{AppError, Result};
+use crate::utils::truncate_address;
 use axum::{
     extract::{Json, State},
     response::IntoResponse,
@@ -35,15 +36,19 @@ pub struct MeResponse {
     pub email_verified: bool,
 }
 
+/// Stand-in for auth and favorites routes while running without a database
+pub async fn auth_unavailable() -> AppError {
+    AppError::AuthUnavailable("the database is not connected".to_string())
+}
+
 /// Legacy wallet-only registration (guest user)
 pub async fn register_guest(
     State(pool): State<PgPool>,
     Json(guest): Json<Guest>,
 ) -> impl IntoResponse {
-    info!("[AUTH] Received guest registration - username: {}, wallet: {}...{}",
+    info!("[AUTH] Received guest registration - username: {}, wallet: {}",
           guest.username,
-          &guest.wallet_address[..6],
-          &guest.wallet_address[guest.wallet_address.len()-6..]);
+          truncate_address(&guest.wallet_address));
 
     match AuthService::register_or_login_guest(&pool, guest).await {
         Ok(response) => {
@@ -233,10 +238,9 @@ pub async fn link_wallet(
     current_user: CurrentUser,
     Json(request): Json<LinkWalletRequest>,
 ) -> Result<impl IntoResponse> {
-    info!("[AUTH] Link wallet request - user_id: {}, wallet: {}...{}",
+    info!("[AUTH] Link wallet request - user_id: {}, wallet: {}",
           current_user.user_id,
-          &request.wallet_address[..6],
-          &request.wallet_address[request.wallet_address.len()-6..]);
+          truncate_address(&request.wallet_address));
 
     // Link wallet to user
     let user = UserRepository::link_wallet(&pool, current_user.user_id, &request.wallet_address).await
@@ -245,10 +249,11 @@ pub async fn link_wallet(
                 warn!("[AUTH] ❌ Wallet already linked to another account");
                 AppError::Conflict("Wallet address already linked to another account".to_string())
             }
+            sqlx::Error::InvalidArgument(message) => AppError::InvalidInput(message),
             _ => AppError::Database(format!("Failed to link wallet: {}", e))
         })?;
 
-    info!("[AUTH] ✅ Wallet linked successfully - user_id: {}, wallet: {}", user.id, user.wallet_address);
+    info!("[AUTH] ✅ Wallet linked successfully - user_id: {}, wallet: {}", user.id, truncate_address(&user.wallet_address));
 
     Ok((
         StatusCode::OK,
@@ -0,0 +1,91 @@
+use sqlx::PgPool;
+use crate::database::models::FavoriteContract;
+use crate::database::repositories::favorite_repository::FavoriteRepository;
+use crate::extractors::CurrentUser;
+use crate::error::{AppError, Result};
+use crate::utils::ensure_valid_contract_id;
+use axum::{
+    extract::{Json, Path, State},
+    response::IntoResponse,
+    http::StatusCode,
+};
+use shared::dto::favorites::{AddFavoriteRequest, Favorite};
+use shared::dto::common::ApiResponse;
+use tracing::{info, debug};
+
+/// Maximum length of a favorite's label (matches the column size)
+const MAX_LABEL_LENGTH: usize = 100;
+
+impl From<FavoriteContract> for Favorite {
+    fn from(favorite: FavoriteContract) -> Self {
+        Self {
+            contract_id: favorite.contract_id,
+            label: favorite.label,
+            created_at: favorite.created_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+/// List the current user's favorite contracts
+pub async fn list_favorites(
+    State(pool): State<PgPool>,
+    current_user: CurrentUser,
+) -> Result<impl IntoResponse> {
+    debug!("[FAVORITES] Listing favorites - user_id: {}", current_user.user_id);
+
+    let favorites: Vec<Favorite> = FavoriteRepository::list_for_user(&pool, current_user.user_id).await
+        .map_err(|e| AppError::Database(format!("Failed to list favorites: {}", e)))?
+        .into_iter()
+        .map(Favorite::from)
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success(favorites, "Favorite contracts"))
+    ))
+}
+
+/// Add a contract to the current user's favorites
+pub async fn add_favorite(
+    State(pool): State<PgPool>,
+    current_user: CurrentUser,
+    Json(request): Json<AddFavoriteRequest>,
+) -> Result<impl IntoResponse> {
+    info!("[FAVORITES] Add favorite - user_id: {}, contract: {}", current_user.user_id, request.contract_id);
+
+    ensure_valid_contract_id(&request.contract_id)?;
+
+    let label = request.label.as_deref().map(str::trim).filter(|l| !l.is_empty());
+    if label.is_some_and(|l| l.chars().count() > MAX_LABEL_LENGTH) {
+        return Err(AppError::BadRequest(format!("Label must be at most {} characters", MAX_LABEL_LENGTH)));
+    }
+
+    let favorite = FavoriteRepository::add(&pool, current_user.user_id, &request.contract_id, label).await
+        .map_err(|e| AppError::Database(format!("Failed to add favorite: {}", e)))?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::success(Favorite::from(favorite), "Favorite added"))
+    ))
+}
+
+/// Remove a contract from the current user's favorites
+pub async fn remove_favorite(
+    State(pool): State<PgPool>,
+    current_user: CurrentUser,
+    Path(contract_id): Path<String>,
+) -> Result<impl IntoResponse> {
+    info!("[FAVORITES] Remove favorite - user_id: {}, contract: {}", current_user.user_id, contract_id);
+
+    let removed = FavoriteRepository::remove(&pool, current_user.user_id, &contract_id).await
+        .map_err(|e| AppError::Database(format!("Failed to remove favorite: {}", e)))?;
+
+    if !removed {
+        return Err(AppError::NotFound(format!("Contract {} is not in your favorites", contract_id)));
+    }
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse::<()>::success_no_data("Favorite removed"))
+    ))
+}
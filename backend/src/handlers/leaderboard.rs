@@ -0,0 +1,69 @@
+use axum::{extract::{Query, State}, response::Json};
+use serde::Deserialize;
+use tracing::info;
+
+use crate::config::AppState;
+use crate::error::{AppError, Result};
+use crate::extractors::MaybeCurrentUser;
+use crate::services::leaderboard::{submit_score_xdr, top_scores};
+use shared::dto::leaderboard::{
+    LeaderboardResponse, SubmitScoreRequest, SubmitScoreResponse, DEFAULT_TOP_N, MAX_TOP_N,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct TopScoresQuery {
+    /// Number of entries to return (default 10, max 100)
+    #[serde(default)]
+    pub n: Option<u32>,
+}
+
+/// Build an unsigned `submit_score` transaction for the player's wallet to sign
+///
+/// The returned XDR goes through the usual sign → `/submit-transaction` flow.
+pub async fn submit_score_handler(
+    State(state): State<AppState>,
+    MaybeCurrentUser(user): MaybeCurrentUser,
+    Json(request): Json<SubmitScoreRequest>,
+) -> Result<Json<SubmitScoreResponse>> {
+    info!("[HANDLER] Submit score request - score: {}", request.score);
+
+    let cost_limits = state.config.cost_limits_for(user.as_ref());
+    let xdr = submit_score_xdr(&state.xdr_config, &request.player, request.score, &cost_limits).await?;
+
+    Ok(Json(SubmitScoreResponse {
+        success: true,
+        xdr,
+        function_name: "submit_score".to_string(),
+        network: state.xdr_config.network_passphrase.clone(),
+    }))
+}
+
+/// Read and decode the highest scores, best first
+pub async fn top_scores_handler(
+    State(state): State<AppState>,
+    Query(query): Query<TopScoresQuery>,
+) -> Result<Json<LeaderboardResponse>> {
+    let n = query.n.unwrap_or(DEFAULT_TOP_N);
+    info!("[HANDLER] Leaderboard top request - n: {}", n);
+
+    if n == 0 || n > MAX_TOP_N {
+        return Err(AppError::InvalidInput(format!("n must be between 1 and {}", MAX_TOP_N)));
+    }
+
+    let manager = state.soroban_manager.as_ref()
+        .ok_or_else(|| AppError::Config("Soroban manager not initialized".to_string()))?;
+
+    let entries = top_scores(
+        manager,
+        &state.xdr_config.contract_id,
+        n,
+        state.config.read_simulation_options(None),
+    ).await?;
+    let count = entries.len();
+
+    Ok(Json(LeaderboardResponse {
+        success: true,
+        entries,
+        count,
+    }))
+}
@@ -0,0 +1,44 @@
+use axum::{extract::State, response::Json};
+use tracing::info;
+
+use crate::config::AppState;
+use crate::error::{AppError, Result};
+use crate::services::stellar::generate_payment_xdr;
+use shared::dto::payment::{PaymentRequest, PaymentXdrResponse};
+
+/// Build an unsigned classic payment for the source account's wallet to sign
+///
+/// The returned XDR goes through the usual sign → `/submit-transaction` flow.
+pub async fn generate_payment_xdr_handler(
+    State(state): State<AppState>,
+    Json(request): Json<PaymentRequest>,
+) -> Result<Json<PaymentXdrResponse>> {
+    info!("[HANDLER] Payment XDR request - amount: {}", request.amount);
+
+    // A transaction built for another network would be signed for the wrong one
+    if let Some(network) = request.network.as_deref() {
+        if network != state.xdr_config.network_passphrase {
+            return Err(AppError::InvalidInput(format!(
+                "Server builds transactions for '{}', not '{}'",
+                state.xdr_config.network_passphrase, network
+            )));
+        }
+    }
+
+    let payment = generate_payment_xdr(
+        &state.xdr_config,
+        &request.source,
+        &request.destination,
+        &request.amount,
+        request.asset.as_deref(),
+    ).await?;
+
+    Ok(Json(PaymentXdrResponse {
+        success: true,
+        xdr: payment.xdr,
+        amount_stroops: payment.amount_stroops,
+        asset: payment.asset,
+        network: state.xdr_config.network_passphrase.clone(),
+        valid_until: payment.valid_until.map(|t| t.to_rfc3339()),
+    }))
+}
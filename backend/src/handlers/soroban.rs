@@ -1,13 +1,18 @@
-use axum::{extract::State, response::Json, extract::Path};
-use tracing::{info, debug};
+use axum::{extract::State, response::Json, extract::{Path, Query}};
+use serde::Deserialize;
+use tracing::{info, debug, warn};
+use soroban_client::xdr::{ReadXdr, ScVal};
 
 use crate::config::AppState;
 use crate::error::{AppError, Result};
-use crate::services::soroban::{EventFilter, EventType as BackendEventType, Pagination, Topic};
+use crate::extractors::MaybeCurrentUser;
+use crate::utils::ensure_valid_contract_id;
+use crate::services::soroban::client::{check_scval_depth, decode_contract_data_value, scval_read_limits};
+use crate::services::soroban::{decode_result_xdr, extract_error, token_balance, EventFilter, EventType as BackendEventType, Pagination, ReflectorPriceEvent, Topic};
 use shared::dto::soroban::{
-    MetricsResponse, ContractInfoResponse, SorobanHealthResponse, ListContractsResponse,
+    MetricsResponse, ContractInfoResponse, ContractsInfoRequest, ContractsInfoResponse, SorobanHealthResponse, ListContractsResponse,
     QueryEventsRequest, QueryEventsResponse, EventType as SharedEventType, EventPagination,
-    EventDto, GetEventsDto, CallContractFunctionRequest, CallContractFunctionResponse
+    EventDto, GetEventsDto, ReflectorPriceEventDto, CallContractFunctionRequest, CallContractFunctionResponse
 };
 
 /// Get Soroban service metrics
@@ -35,6 +40,7 @@ pub async fn contract_info_handler(
     Path(contract_id): Path<String>,
 ) -> Result<Json<ContractInfoResponse>> {
     info!("Contract info request for: {}", contract_id);
+    ensure_valid_contract_id(&contract_id)?;
 
     let manager = state
         .soroban_manager
@@ -49,6 +55,68 @@ pub async fn contract_info_handler(
     }))
 }
 
+/// Largest number of contracts accepted by one batch info request
+const MAX_CONTRACTS_INFO: usize = 50;
+
+/// Get several contracts' information in one request
+///
+/// Lookups run concurrently. Ids that aren't registered are listed in
+/// `not_found` rather than failing the whole request.
+pub async fn contracts_info_handler(
+    State(state): State<AppState>,
+    Json(request): Json<ContractsInfoRequest>,
+) -> Result<Json<ContractsInfoResponse>> {
+    info!("[HANDLER] Contracts info request for {} contracts", request.contract_ids.len());
+
+    if request.contract_ids.is_empty() {
+        return Err(AppError::InvalidInput("At least one contract id is required".to_string()));
+    }
+    if request.contract_ids.len() > MAX_CONTRACTS_INFO {
+        return Err(AppError::InvalidInput(format!(
+            "At most {} contracts per request, got {}",
+            MAX_CONTRACTS_INFO,
+            request.contract_ids.len()
+        )));
+    }
+    for contract_id in &request.contract_ids {
+        ensure_valid_contract_id(contract_id)?;
+    }
+
+    let manager = state.soroban_manager.clone()
+        .ok_or_else(|| AppError::Config("Soroban manager not initialized".to_string()))?;
+
+    let handles: Vec<_> = request.contract_ids
+        .into_iter()
+        .map(|contract_id| {
+            let manager = manager.clone();
+            tokio::spawn(async move {
+                let info = manager.get_contract_info(&contract_id).await;
+                (contract_id, info)
+            })
+        })
+        .collect();
+
+    let mut contracts = Vec::with_capacity(handles.len());
+    let mut not_found = Vec::new();
+
+    for handle in handles {
+        match handle.await? {
+            (_, Ok(info)) => contracts.push(info.into()),
+            (contract_id, Err(_)) => not_found.push(contract_id),
+        }
+    }
+
+    if !not_found.is_empty() {
+        debug!("[HANDLER] Contracts not registered: {:?}", not_found);
+    }
+
+    Ok(Json(ContractsInfoResponse {
+        success: true,
+        contracts,
+        not_found,
+    }))
+}
+
 /// Get Soroban service health status
 pub async fn soroban_health_handler(
     State(state): State<AppState>,
@@ -95,6 +163,7 @@ pub async fn query_events_handler(
     Json(request): Json<QueryEventsRequest>,
 ) -> Result<Json<QueryEventsResponse>> {
     info!("[HANDLER] Query events request - contract: {}", request.contract_id);
+    ensure_valid_contract_id(&request.contract_id)?;
     debug!("[HANDLER] Filters: {:?}, Pagination: {:?}", request.filters.len(), request.pagination);
 
     let manager = state
@@ -119,6 +188,29 @@ pub async fn query_events_handler(
         EventPagination::Cursor { cursor } => Pagination::Cursor(cursor),
     };
 
+    // Starts before the RPC's retention window are rejected, so clamp them to the oldest ledger
+    let (pagination, clamped_to) = match pagination {
+        Pagination::Cursor(_) => (pagination, None),
+        _ => match manager.oldest_ledger(&request.contract_id).await {
+            Ok(oldest) => clamp_event_start(pagination, oldest),
+            Err(e) => {
+                warn!("[HANDLER] ⚠️  Could not read oldest ledger, querying unclamped: {}", e);
+                (pagination, None)
+            }
+        },
+    };
+
+    // Reconnecting subscriptions resume from their last delivered cursor
+    let pagination = match request.subscription_id.as_deref() {
+        Some(subscription_id) => {
+            manager
+                .subscriptions()
+                .resume(&request.contract_id, subscription_id, pagination)
+                .await
+        }
+        None => pagination,
+    };
+
     // Convert shared filters to backend filters
     let filters: Vec<EventFilter> = request
         .filters
@@ -163,22 +255,44 @@ pub async fn query_events_handler(
 
     info!("[HANDLER] ✅ Query events successful - {} events returned", events_result.events.len());
 
+    if let (Some(subscription_id), Some(cursor)) = (request.subscription_id.as_deref(), events_result.cursor.clone()) {
+        manager
+            .subscriptions()
+            .record(&request.contract_id, subscription_id, cursor)
+            .await;
+    }
+
+    // Known oracles get their price updates decoded; other contracts keep generic events
+    let decode_prices = manager.is_price_oracle(&request.contract_id).await;
+
     // Convert backend response to shared DTO
     let events_dto = GetEventsDto {
         events: events_result
             .events
             .into_iter()
-            .map(|event| EventDto {
-                event_type: event.event_type,
-                ledger: event.ledger,
-                ledger_closed_at: event.ledger_closed_at,
-                contract_id: event.contract_id,
-                id: event.id,
-                paging_token: event.paging_token,
-                topic: event.topic,
-                value: event.value,
-                in_successful_contract_call: event.in_successful_contract_call,
-                transaction_hash: event.transaction_hash,
+            .map(|event| {
+                let price_update = decode_prices
+                    .then(|| ReflectorPriceEvent::from_event(&event))
+                    .flatten()
+                    .map(|price| ReflectorPriceEventDto {
+                        asset: price.asset,
+                        price: price.price,
+                        timestamp: price.timestamp,
+                    });
+
+                EventDto {
+                    event_type: event.event_type,
+                    ledger: event.ledger,
+                    ledger_closed_at: event.ledger_closed_at,
+                    contract_id: event.contract_id,
+                    id: event.id,
+                    paging_token: event.paging_token,
+                    topic: event.topic,
+                    value: event.value,
+                    in_successful_contract_call: event.in_successful_contract_call,
+                    transaction_hash: event.transaction_hash,
+                    price_update,
+                }
             })
             .collect(),
         cursor: events_result.cursor,
@@ -186,6 +300,7 @@ pub async fn query_events_handler(
         oldest_ledger: events_result.oldest_ledger,
         latest_ledger_close_time: events_result.latest_ledger_close_time,
         oldest_ledger_close_time: events_result.oldest_ledger_close_time,
+        clamped_to,
     };
 
     Ok(Json(QueryEventsResponse {
@@ -194,6 +309,17 @@ pub async fn query_events_handler(
     }))
 }
 
+/// Move a ledger-based start up to `oldest`, returning the new start if it changed
+fn clamp_event_start(pagination: Pagination, oldest: u32) -> (Pagination, Option<u32>) {
+    match pagination {
+        Pagination::From(start) if start < oldest => (Pagination::From(oldest), Some(oldest)),
+        Pagination::FromTo(start, end) if start < oldest => {
+            (Pagination::FromTo(oldest, end.max(oldest)), Some(oldest))
+        }
+        other => (other, None),
+    }
+}
+
 /// Simulate a transaction before submitting it to the network
 ///
 /// This handler allows frontend to test transactions before actual submission,
@@ -204,39 +330,36 @@ pub async fn query_events_handler(
 /// - Preview of transaction results and state changes
 pub async fn simulate_transaction_handler(
     State(state): State<AppState>,
+    MaybeCurrentUser(user): MaybeCurrentUser,
     Json(request): Json<shared::dto::soroban::SimulateTransactionRequest>,
 ) -> Result<Json<shared::dto::soroban::SimulateTransactionResponseDto>> {
     info!("[HANDLER] Simulate transaction request - contract: {}", request.contract_id);
+    ensure_valid_contract_id(&request.contract_id)?;
 
     let manager = state.soroban_manager.as_ref()
         .ok_or_else(|| AppError::Config("Soroban manager not initialized".to_string()))?;
 
-    // Convert shared SimulationOptions to backend SimulationOptions
-    let backend_options = request.options.map(|opt| {
-        crate::services::soroban::simulation::SimulationOptions {
-            cpu_instructions: opt.cpu_instructions,
-            auth_mode: opt.auth_mode.map(|mode| match mode {
-                shared::dto::soroban::AuthModeDto::Enforce =>
-                    crate::services::soroban::simulation::AuthMode::Enforce,
-                shared::dto::soroban::AuthModeDto::Record =>
-                    crate::services::soroban::simulation::AuthMode::Record,
-            }),
-        }
-    });
-
     // Simulate transaction via manager
     let simulation_result = manager.simulate_transaction(
         &request.contract_id,
         &request.transaction_xdr,
-        backend_options,
+        to_backend_simulation_options(request.options),
     ).await?;
 
+    // The contract rejected the transaction: a caller problem, not an outage
+    if !simulation_result.is_success() {
+        let reason = simulation_failure_reason(&simulation_result);
+        info!("[HANDLER] ⚠️ Simulation rejected by contract: {}", reason);
+        return Err(AppError::Contract(reason));
+    }
+
+    // Refuse simulations that would blow the caller's cost budget
+    state.config.cost_limits_for(user.as_ref()).check_simulation(&simulation_result)?;
+
     // Convert backend response to shared DTO
     let response_dto = shared::dto::soroban::SimulateTransactionResponseDto {
-        success: simulation_result.is_success(),
         latest_ledger: simulation_result.latest_ledger,
         min_resource_fee: simulation_result.min_resource_fee.clone(),
-        error: simulation_result.error.clone(),
         results: simulation_result.results.as_ref().map(|results| {
             results.iter().map(|r| shared::dto::soroban::SimulationResultDto {
                 auth: r.auth.clone(),
@@ -250,6 +373,8 @@ pub async fn simulate_transaction_handler(
                 transaction_data: rp.transaction_data.clone(),
             }
         }),
+        requires_restore: simulation_result.needs_restoration(),
+        restore_fee_stroops: simulation_result.restoration_fee(),
         events: simulation_result.events.clone(),
         state_changes: simulation_result.state_changes.as_ref().map(|changes| {
             changes.iter().map(|sc| shared::dto::soroban::StateChangeDto {
@@ -269,14 +394,129 @@ pub async fn simulate_transaction_handler(
     };
 
     info!(
-        "[HANDLER] ✅ Simulate transaction {} - fee: {:?}",
-        if response_dto.success { "successful" } else { "failed" },
+        "[HANDLER] ✅ Simulate transaction successful - fee: {:?}",
         response_dto.min_resource_fee
     );
 
     Ok(Json(response_dto))
 }
 
+/// Maximum number of simulations in one batch request
+const MAX_BATCH_SIMULATIONS: usize = 10;
+
+/// Simulate several transaction variants concurrently and compare their costs
+///
+/// Each simulation succeeds or fails on its own; failures are reported per item
+/// (with their error type) instead of failing the whole batch.
+pub async fn simulate_batch_handler(
+    State(state): State<AppState>,
+    MaybeCurrentUser(user): MaybeCurrentUser,
+    Json(request): Json<shared::dto::soroban::SimulateBatchRequest>,
+) -> Result<Json<shared::dto::soroban::SimulateBatchResponse>> {
+    info!("[HANDLER] Simulate batch request - {} simulations", request.simulations.len());
+
+    if request.simulations.is_empty() {
+        return Err(AppError::InvalidInput("At least one simulation is required".to_string()));
+    }
+    if request.simulations.len() > MAX_BATCH_SIMULATIONS {
+        return Err(AppError::InvalidInput(format!(
+            "At most {} simulations per batch, got {}",
+            MAX_BATCH_SIMULATIONS,
+            request.simulations.len()
+        )));
+    }
+    for simulation in &request.simulations {
+        ensure_valid_contract_id(&simulation.contract_id)?;
+    }
+
+    let manager = state.soroban_manager.clone()
+        .ok_or_else(|| AppError::Config("Soroban manager not initialized".to_string()))?;
+
+    let handles: Vec<_> = request.simulations
+        .into_iter()
+        .map(|simulation| {
+            let manager = manager.clone();
+            let options = to_backend_simulation_options(simulation.options);
+            tokio::spawn(async move {
+                let result = manager
+                    .simulate_transaction(&simulation.contract_id, &simulation.transaction_xdr, options)
+                    .await;
+                (simulation.contract_id, result)
+            })
+        })
+        .collect();
+
+    let cost_limits = state.config.cost_limits_for(user.as_ref());
+    let mut results = Vec::with_capacity(handles.len());
+
+    for (index, handle) in handles.into_iter().enumerate() {
+        let (contract_id, result) = handle.await?;
+
+        let result = result.and_then(|simulation| {
+            if !simulation.is_success() {
+                return Err(AppError::Contract(simulation_failure_reason(&simulation)));
+            }
+            cost_limits.check_simulation(&simulation)?;
+            Ok(simulation)
+        });
+
+        results.push(match result {
+            Ok(simulation) => shared::dto::soroban::SimulateBatchItem {
+                index,
+                contract_id,
+                success: true,
+                min_resource_fee: simulation.min_resource_fee.clone(),
+                cpu_instructions: simulation.get_cpu_instructions(),
+                error: None,
+                error_type: None,
+            },
+            Err(e) => shared::dto::soroban::SimulateBatchItem {
+                index,
+                contract_id,
+                success: false,
+                min_resource_fee: None,
+                cpu_instructions: None,
+                error: Some(e.to_string()),
+                error_type: Some(e.error_type().to_string()),
+            },
+        });
+    }
+
+    info!(
+        "[HANDLER] ✅ Simulate batch complete - {}/{} succeeded",
+        results.iter().filter(|r| r.success).count(),
+        results.len()
+    );
+
+    Ok(Json(shared::dto::soroban::SimulateBatchResponse {
+        success: true,
+        results,
+    }))
+}
+
+/// Convert shared SimulationOptions to backend SimulationOptions
+fn to_backend_simulation_options(
+    options: Option<shared::dto::soroban::SimulationOptionsDto>,
+) -> Option<crate::services::soroban::simulation::SimulationOptions> {
+    options.map(|opt| crate::services::soroban::simulation::SimulationOptions {
+        cpu_instructions: opt.cpu_instructions,
+        auth_mode: opt.auth_mode.map(|mode| match mode {
+            shared::dto::soroban::AuthModeDto::Enforce =>
+                crate::services::soroban::simulation::AuthMode::Enforce,
+            shared::dto::soroban::AuthModeDto::Record =>
+                crate::services::soroban::simulation::AuthMode::Record,
+        }),
+    })
+}
+
+/// Contract's own reason from diagnostic events, falling back to the raw simulation error
+fn simulation_failure_reason(simulation: &crate::services::soroban::SimulateTransactionResponse) -> String {
+    match simulation.events.as_deref().and_then(extract_error) {
+        Some(diagnostic) => diagnostic.reason(),
+        None => simulation.error.clone().unwrap_or_else(|| "Unknown simulation error".to_string()),
+    }
+}
+
 /// Get contract storage data
 ///
 /// This handler allows reading contract storage directly without executing transactions,
@@ -294,25 +534,24 @@ pub async fn get_contract_data_handler(
         "[HANDLER] Get contract data request - contract: {}, durability: {:?}",
         request.contract_id, request.durability
     );
+    ensure_valid_contract_id(&request.contract_id)?;
 
     let manager = state.soroban_manager.as_ref()
         .ok_or_else(|| AppError::Config("Soroban manager not initialized".to_string()))?;
 
-    // Convert shared Durability to backend Durability
-    let backend_durability = match request.durability {
-        shared::dto::soroban::DurabilityDto::Temporary =>
-            crate::services::soroban::state::Durability::Temporary,
-        shared::dto::soroban::DurabilityDto::Persistent =>
-            crate::services::soroban::state::Durability::Persistent,
-    };
-
     // Get contract data via manager
     match manager.get_contract_data(
         &request.contract_id,
         &request.key,
-        backend_durability,
+        to_backend_durability(request.durability),
     ).await {
-        Ok(entry) => {
+        Ok(read) => {
+            let entry = read.entry;
+            let value = decode_contract_data_value(&entry).unwrap_or_else(|e| {
+                warn!("[HANDLER] ⚠️ Could not decode contract data value: {}", e);
+                None
+            });
+
             // Convert backend LedgerEntryResult to shared DTO
             let entry_dto = shared::dto::soroban::LedgerEntryResultDto {
                 last_modified_ledger_seq: entry.last_modified_ledger_seq,
@@ -327,6 +566,8 @@ pub async fn get_contract_data_handler(
             Ok(Json(shared::dto::soroban::GetContractDataResponse {
                 success: true,
                 data: Some(entry_dto),
+                value,
+                latest_ledger: Some(read.latest_ledger),
                 error: None,
             }))
         }
@@ -336,14 +577,119 @@ pub async fn get_contract_data_handler(
             Ok(Json(shared::dto::soroban::GetContractDataResponse {
                 success: false,
                 data: None,
+                value: None,
+                latest_ledger: None,
                 error: Some(e.to_string()),
             }))
         }
     }
 }
 
+/// Maximum keys per TTL report (read as a batch of getLedgerEntries calls)
+const MAX_TTL_REPORT_KEYS: usize = 1_000;
+
+/// TTL report for a set of contract storage keys
+///
+/// Reads every entry in one batched RPC request and returns them sorted by
+/// `ledgers_until_expiry`, so the entries closest to archival come first.
+/// Entries already past their TTL are flagged `expired`.
+pub async fn ttl_report_handler(
+    State(state): State<AppState>,
+    Path(contract_id): Path<String>,
+    Json(request): Json<shared::dto::soroban::TtlReportRequest>,
+) -> Result<Json<shared::dto::soroban::TtlReportResponse>> {
+    info!("[HANDLER] TTL report request - contract: {}, {} keys", contract_id, request.keys.len());
+    ensure_valid_contract_id(&contract_id)?;
+
+    if request.keys.is_empty() {
+        return Err(AppError::InvalidInput("At least one storage key is required".to_string()));
+    }
+    if request.keys.len() > MAX_TTL_REPORT_KEYS {
+        return Err(AppError::InvalidInput(format!(
+            "At most {} storage keys per report, got {}",
+            MAX_TTL_REPORT_KEYS,
+            request.keys.len()
+        )));
+    }
+    for key in &request.keys {
+        let scval = ScVal::from_xdr_base64(&key.key, scval_read_limits())
+            .map_err(|e| AppError::InvalidInput(format!("Invalid storage key '{}': {}", key.key, e)))?;
+        check_scval_depth(&scval)?;
+    }
+
+    let manager = state.soroban_manager.as_ref()
+        .ok_or_else(|| AppError::Config("Soroban manager not initialized".to_string()))?;
+
+    let keys = request.keys
+        .into_iter()
+        .map(|k| (k.key, to_backend_durability(k.durability)))
+        .collect();
+
+    match manager.get_ttl_report(&contract_id, keys).await {
+        Ok(report) => {
+            info!("[HANDLER] ✅ TTL report successful - {} entries", report.entries.len());
+
+            Ok(Json(shared::dto::soroban::TtlReportResponse {
+                success: true,
+                latest_ledger: report.latest_ledger,
+                entries: report.entries
+                    .into_iter()
+                    .map(|entry| shared::dto::soroban::TtlReportEntryDto {
+                        key: entry.key,
+                        durability: to_shared_durability(entry.durability),
+                        found: entry.found,
+                        live_until_ledger_seq: entry.live_until_ledger_seq,
+                        ledgers_until_expiry: entry.ledgers_until_expiry,
+                        expired: entry.expired,
+                    })
+                    .collect(),
+                error: None,
+            }))
+        }
+        Err(e) => {
+            info!("[HANDLER] ⚠️ TTL report failed: {}", e);
+
+            Ok(Json(shared::dto::soroban::TtlReportResponse {
+                success: false,
+                latest_ledger: 0,
+                entries: Vec::new(),
+                error: Some(e.to_string()),
+            }))
+        }
+    }
+}
+
+/// Convert shared Durability to backend Durability
+fn to_backend_durability(durability: shared::dto::soroban::DurabilityDto) -> crate::services::soroban::state::Durability {
+    match durability {
+        shared::dto::soroban::DurabilityDto::Temporary =>
+            crate::services::soroban::state::Durability::Temporary,
+        shared::dto::soroban::DurabilityDto::Persistent =>
+            crate::services::soroban::state::Durability::Persistent,
+    }
+}
+
+fn to_shared_durability(durability: crate::services::soroban::state::Durability) -> shared::dto::soroban::DurabilityDto {
+    match durability {
+        crate::services::soroban::state::Durability::Temporary =>
+            shared::dto::soroban::DurabilityDto::Temporary,
+        crate::services::soroban::state::Durability::Persistent =>
+            shared::dto::soroban::DurabilityDto::Persistent,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CallFunctionQuery {
+    /// Return the built transaction XDR and decoded operation without simulating
+    #[serde(default)]
+    pub explain: bool,
+}
+
 /// Call a generic contract function (read-only via simulation)
 ///
+/// With `?explain=true` nothing is sent to the RPC: the response carries the
+/// unprepared transaction XDR and its decoded operation instead of a result.
+///
 /// This handler allows the frontend to call ANY Soroban contract function by:
 /// - Accepting function name and typed parameters
 /// - Building transaction XDR
@@ -357,30 +703,258 @@ pub async fn get_contract_data_handler(
 /// - Build real-time dashboards from contract data
 pub async fn call_contract_function_handler(
     State(state): State<AppState>,
+    MaybeCurrentUser(user): MaybeCurrentUser,
+    Query(query): Query<CallFunctionQuery>,
     Json(request): Json<CallContractFunctionRequest>,
 ) -> Result<Json<CallContractFunctionResponse>> {
     info!(
-        "[HANDLER] Call contract function - contract: {}, function: {}",
-        request.contract_id, request.function_name
+        "[HANDLER] Call contract function - contract: {}, function: {}, explain: {}",
+        request.contract_id, request.function_name, query.explain
     );
     debug!("[HANDLER] Parameters: {} params", request.parameters.len());
+    ensure_valid_contract_id(&request.contract_id)?;
 
     let manager = state.soroban_manager.as_ref()
         .ok_or_else(|| AppError::Config("Soroban manager not initialized".to_string()))?;
 
-    // Call contract function via manager
-    let result = manager.call_contract_function(
+    if query.explain {
+        let explained = manager.explain_contract_call(
+            &request.contract_id,
+            &request.function_name,
+            &request.parameters,
+            request.source_account.as_deref(),
+        ).await?;
+        info!("[HANDLER] ✅ Explained call to {} without simulating", request.function_name);
+        return Ok(Json(explained));
+    }
+
+    // Call contract function via manager. If the client disconnects, hyper drops this
+    // future, which aborts the in-flight RPC work and releases the pooled connection;
+    // the timeout covers clients that stay connected to a stalled RPC.
+    let timeout = state.config.contract_call_timeout();
+    let call = manager.call_contract_function(
         &request.contract_id,
         &request.function_name,
         request.parameters,
         request.source_account.as_deref(),
-    ).await?;
+        Some(state.config.read_simulation_options(None)),
+        request.cache_mode,
+    );
+    let mut result = tokio::select! {
+        result = call => result?,
+        _ = tokio::time::sleep(timeout) => {
+            warn!("[HANDLER] ⚠️ Call contract function timed out after {:?}", timeout);
+            return Err(AppError::Timeout(format!(
+                "Contract call did not complete within {}s",
+                timeout.as_secs()
+            )));
+        }
+    };
+
+    // Contract-level failures (panics, application errors) are 422s, not 5xx
+    if !result.success {
+        let reason = result.error.unwrap_or_else(|| "Unknown contract error".to_string());
+        info!("[HANDLER] ⚠️ Call contract function rejected by contract: {}", reason);
+        return Err(AppError::Contract(reason));
+    }
+
+    // Re-decode the raw result when the caller told us what type to expect
+    if let (Some(return_type), Some(result_xdr)) = (&request.return_type, &result.result_xdr) {
+        debug!("[HANDLER] Decoding result with return type hint: {:?}", return_type);
+        result.result = Some(decode_result_xdr(result_xdr, Some(return_type))?);
+    }
+
+    // Refuse calls whose simulated cost exceeds the caller's budget
+    if let Some(simulation) = &result.simulation {
+        state.config.cost_limits_for(user.as_ref()).check(
+            simulation.cpu_instructions,
+            simulation.min_resource_fee.as_ref().and_then(|fee| fee.parse().ok()),
+        )?;
+    }
 
     info!(
-        "[HANDLER] ✅ Call contract function {} - result: {}",
-        if result.success { "successful" } else { "failed" },
+        "[HANDLER] ✅ Call contract function successful - result: {}",
         result.result.as_ref().map(|r| format!("{:?}", r)).unwrap_or_else(|| "None".to_string())
     );
 
     Ok(Json(result))
 }
+
+/// Read an account's balance of a token contract
+///
+/// Convenience over call-function: callers don't need to know the token
+/// interface. Contracts without SEP-41 `balance` (or `decimals`, when scaling)
+/// are reported as not being tokens (422).
+pub async fn token_balance_handler(
+    State(state): State<AppState>,
+    Path(contract_id): Path<String>,
+    Json(request): Json<shared::dto::soroban::TokenBalanceRequest>,
+) -> Result<Json<shared::dto::soroban::TokenBalanceResponse>> {
+    info!("[HANDLER] Token balance request - contract: {}, scaled: {}", contract_id, request.scaled);
+    ensure_valid_contract_id(&contract_id)?;
+
+    let account = request.account.trim().to_string();
+    match stellar_strkey::Strkey::from_string(&account) {
+        Ok(stellar_strkey::Strkey::PublicKeyEd25519(_)) | Ok(stellar_strkey::Strkey::Contract(_)) => {}
+        _ => return Err(AppError::InvalidInput("Account must be a Stellar account (G...) or contract (C...) address".to_string())),
+    }
+
+    let manager = state.soroban_manager.as_ref()
+        .ok_or_else(|| AppError::Config("Soroban manager not initialized".to_string()))?;
+
+    let timeout = state.config.contract_call_timeout();
+    let read = token_balance(
+        manager,
+        &contract_id,
+        &account,
+        request.scaled,
+        state.config.read_simulation_options(None),
+    );
+    let balance = tokio::time::timeout(timeout, read).await.map_err(|_| {
+        warn!("[HANDLER] ⚠️ Token balance timed out after {:?}", timeout);
+        AppError::Timeout(format!("Token balance did not complete within {}s", timeout.as_secs()))
+    })??;
+
+    info!("[HANDLER] ✅ Token balance: {} (decimals: {:?})", balance.balance, balance.decimals);
+
+    Ok(Json(shared::dto::soroban::TokenBalanceResponse {
+        success: true,
+        contract_id,
+        account,
+        balance: balance.balance.to_string(),
+        decimals: balance.decimals,
+        amount: balance.decimals.map(|decimals| shared::amount::format_amount(balance.balance, decimals)),
+    }))
+}
+
+/// Sign a transaction with the server's local signer and submit it
+///
+/// Testnet automation only:
+/// - Requires `ENABLE_LOCAL_SIGNER=true` (rejected by config outside testnet)
+/// - Route is restricted to admins
+pub async fn sign_and_submit_handler(
+    State(state): State<AppState>,
+    MaybeCurrentUser(user): MaybeCurrentUser,
+    Json(request): Json<shared::dto::soroban::SignAndSubmitRequest>,
+) -> Result<Json<shared::dto::soroban::SignAndSubmitResponse>> {
+    info!("[HANDLER] Sign and submit request - XDR length: {}", request.transaction_xdr.len());
+
+    if !state.config.is_testnet() {
+        return Err(AppError::Forbidden("Server-side signing is only available on testnet".to_string()));
+    }
+
+    let signer = state.signer.as_ref()
+        .ok_or_else(|| AppError::Forbidden("Local signer is not enabled".to_string()))?;
+
+    // Refuse to sign anything whose prepared budget exceeds the caller's limits
+    state.config.cost_limits_for(user.as_ref()).check_envelope_xdr(&request.transaction_xdr)?;
+
+    let signed_xdr = signer.sign_envelope(&request.transaction_xdr, &state.config.network_passphrase)?;
+    let submission = crate::services::soroban::client::send_transaction(&state.xdr_config, &signed_xdr).await?;
+
+    info!(
+        "[HANDLER] ✅ Sign and submit {} - hash: {}",
+        submission.status, submission.hash
+    );
+
+    Ok(Json(shared::dto::soroban::SignAndSubmitResponse {
+        success: submission.is_accepted(),
+        signer: signer.public_key(),
+        error: if submission.is_accepted() {
+            None
+        } else {
+            Some(format!("Transaction rejected with status {}", submission.status))
+        },
+        status: submission.status,
+        hash: submission.hash,
+        signed_xdr,
+    }))
+}
+
+/// List operations waiting in the submission queue (admin only)
+pub async fn queue_status_handler(
+    State(state): State<AppState>,
+) -> Result<Json<shared::dto::soroban::QueueStatusResponse>> {
+    let manager = state.soroban_manager.as_ref()
+        .ok_or_else(|| AppError::Config("Soroban manager not initialized".to_string()))?;
+
+    let pending = manager.pending_operations();
+    debug!("[HANDLER] Queue status request - {} pending", pending.len());
+
+    let mut counts_by_priority = std::collections::BTreeMap::new();
+    for operation in &pending {
+        *counts_by_priority.entry(format!("{:?}", operation.priority)).or_insert(0) += 1;
+    }
+
+    Ok(Json(shared::dto::soroban::QueueStatusResponse {
+        success: true,
+        pending: pending.len(),
+        counts_by_priority,
+        operations: pending
+            .into_iter()
+            .map(|operation| shared::dto::soroban::QueuedOperationDto {
+                id: operation.id,
+                contract_id: operation.contract_id,
+                function_name: operation.function_name,
+                source_account: operation.source_account,
+                priority: format!("{:?}", operation.priority),
+                retry_count: operation.retry_count,
+                created_at: operation.created_at.to_rfc3339(),
+            })
+            .collect(),
+    }))
+}
+
+/// Cancel every operation still waiting in the submission queue (admin only)
+///
+/// Operations already being processed run to completion.
+pub async fn queue_flush_handler(
+    State(state): State<AppState>,
+) -> Result<Json<shared::dto::soroban::QueueFlushResponse>> {
+    let manager = state.soroban_manager.as_ref()
+        .ok_or_else(|| AppError::Config("Soroban manager not initialized".to_string()))?;
+
+    let dropped = manager.flush_queue();
+    info!("[HANDLER] ✅ Queue flushed - {} operations dropped", dropped);
+
+    Ok(Json(shared::dto::soroban::QueueFlushResponse {
+        success: true,
+        dropped,
+    }))
+}
+
+/// Ready-made call parameters for a known contract's functions
+///
+/// Unknown contracts get an empty list rather than an error.
+pub async fn contract_presets_handler(
+    Path(contract_id): Path<String>,
+) -> Result<Json<shared::dto::presets::ContractPresetsResponse>> {
+    debug!("[HANDLER] Parameter presets request - contract: {}", contract_id);
+    ensure_valid_contract_id(&contract_id)?;
+
+    let presets = shared::dto::presets::presets_for_contract(&contract_id);
+
+    Ok(Json(shared::dto::presets::ContractPresetsResponse {
+        success: true,
+        contract_id,
+        presets,
+    }))
+}
+
+/// Convert a contract id between `C...` strkey and hex
+///
+/// Accepts either form and returns both, so clients can normalize pasted ids.
+pub async fn convert_contract_id_handler(
+    Json(request): Json<shared::dto::soroban::ConvertContractIdRequest>,
+) -> Result<Json<shared::dto::soroban::ConvertContractIdResponse>> {
+    debug!("[HANDLER] Convert contract id: {}", request.contract_id);
+
+    let (strkey, hex) = crate::utils::convert_contract_id(&request.contract_id)
+        .map_err(AppError::InvalidInput)?;
+
+    Ok(Json(shared::dto::soroban::ConvertContractIdResponse {
+        success: true,
+        strkey,
+        hex,
+    }))
+}
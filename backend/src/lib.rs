@@ -2,6 +2,8 @@ pub mod config;
 pub mod database;
 pub mod error;
 pub mod handlers;
+pub mod logging;
+pub mod server;
 pub mod services;
 pub mod types;
 pub mod utils;
@@ -11,9 +13,12 @@ pub mod auth;
 pub mod middleware;
 pub mod extractors;
 
-use axum::{routing::{get, post}, Router, middleware as axum_middleware};
+#[cfg(test)]
+mod test_support;
+
+use axum::{routing::{any, delete, get, post}, Router, middleware as axum_middleware};
 use tower_cookies::CookieManagerLayer;
-use tracing::info;
+use tracing::{info, warn};
 
 pub use config::{AppConfig, AppState};
 pub use error::{AppError, Result};
@@ -24,75 +29,130 @@ pub use utils::create_cors_layer;
 
 // Auth re-exports
 pub use auth::{encode_jwt, decode_jwt, hash_password, verify_password, create_auth_cookie, create_logout_cookie};
-pub use middleware::{auth_middleware, require_admin, require_chapter_lead};
+pub use middleware::{auth_middleware, optional_auth_middleware, require_admin, require_chapter_lead};
 pub use extractors::CurrentUser;
 
 pub async fn create_app(config: AppConfig, db_pool: sqlx::PgPool) -> Result<Router> {
+    let state = create_state(config, db_pool).await?;
+
+    Ok(create_router(state))
+}
+
+/// Build the app without a database: Soroban routes work, auth routes answer 503
+pub async fn create_app_without_database(config: AppConfig) -> Result<Router> {
+    let state = create_state(config, database::connection::unavailable_pool()).await?;
+
+    Ok(create_router(state.without_database()))
+}
+
+async fn create_state(config: AppConfig, db_pool: sqlx::PgPool) -> Result<AppState> {
     // Try to create state with Soroban manager and database pool
-    let state = match AppState::with_soroban_manager(config.clone(), db_pool.clone()).await {
+    match AppState::with_soroban_manager(config.clone(), db_pool.clone()).await {
         Ok(s) => {
             info!("✅ AppState initialized with Soroban manager and database");
-            s
+            Ok(s)
         }
         Err(e) => {
             info!("⚠️  Soroban manager initialization failed, using basic state: {}", e);
-            AppState::new(config.clone(), db_pool.clone())?
+            AppState::new(config, db_pool)
         }
-    };
+    }
+}
+
+/// Build the full router (public, Soroban, auth and admin routes) around an existing state
+pub fn create_router(state: AppState) -> Router {
+    let config = state.config.clone();
 
     // Public routes (no authentication required)
     let mut app = Router::new()
         .route("/generate-xdr", get(generate_xdr_handler))
         .route("/submit-transaction", post(submit_transaction_handler))
-        .route("/health", get(health_handler));
+        .route("/health", get(health_handler))
+        .route("/api/soroban/functions", get(handlers::list_functions_handler))
+        .route("/api/leaderboard/submit", post(handlers::leaderboard::submit_score_handler))
+        .route("/api/leaderboard/top", get(handlers::leaderboard::top_scores_handler))
+        .route("/api/payment/generate-xdr", post(handlers::payment::generate_payment_xdr_handler))
+        .route("/api/account/{address}", get(handlers::account::get_account_handler));
 
     // Add Soroban routes if manager is available
     if state.soroban_manager.is_some() {
         info!("Registering Soroban advanced routes");
-        app = app
+        let soroban_routes = Router::new()
             .route("/api/soroban/metrics", get(metrics_handler))
             .route("/api/soroban/health", get(soroban_health_handler))
             .route("/api/soroban/contracts", get(list_contracts_handler))
             .route("/api/soroban/contract/{id}", get(contract_info_handler))
+            .route("/api/soroban/contracts/info", post(handlers::soroban::contracts_info_handler))
+            .route("/api/soroban/contract/{id}/ttl-report", post(handlers::soroban::ttl_report_handler))
+            .route("/api/soroban/contract/{id}/presets", get(handlers::soroban::contract_presets_handler))
+            .route("/api/soroban/contract/{id}/balance", post(handlers::soroban::token_balance_handler))
             .route("/api/soroban/events", post(handlers::soroban::query_events_handler))
             .route("/api/soroban/simulate", post(handlers::soroban::simulate_transaction_handler))
+            .route("/api/soroban/simulate-batch", post(handlers::soroban::simulate_batch_handler))
             .route("/api/soroban/contract-data", post(handlers::soroban::get_contract_data_handler))
-            .route("/api/soroban/call-function", post(handlers::soroban::call_contract_function_handler));
+            .route("/api/soroban/call-function", post(handlers::soroban::call_contract_function_handler))
+            .route("/api/soroban/convert-contract-id", post(handlers::soroban::convert_contract_id_handler))
+            // Identify callers when possible so cost limits can depend on role
+            .layer(axum_middleware::from_fn_with_state(state.clone(), optional_auth_middleware));
+        app = app.merge(soroban_routes);
         info!("Soroban routes registered successfully (events, simulation, state querying, function calls)");
     } else {
         info!("WARNING: Soroban routes NOT registered (manager not initialized)");
     }
 
-    // Add public auth routes (signup, login, logout)
-    info!("Registering public auth routes");
-    app = app
-        .route("/api/auth/register-guest", post(handlers::auth::register_guest))
-        .route("/api/auth/signup", post(handlers::auth::signup_with_password))
-        .route("/api/auth/login", post(handlers::auth::login))
-        .route("/api/auth/logout", post(handlers::auth::logout));
-    info!("Public auth routes registered: /api/auth/{{register-guest,signup,login,logout}}");
-
-    // Protected routes (require authentication)
-    info!("Registering protected auth routes");
-    let protected_routes = Router::new()
-        .route("/api/auth/me", get(handlers::auth::me))
-        .route("/api/auth/link-wallet", post(handlers::auth::link_wallet))
+    let protected_routes = if state.database_available {
+        // Add public auth routes (signup, login, logout)
+        info!("Registering public auth routes");
+        app = app
+            .route("/api/auth/register-guest", post(handlers::auth::register_guest))
+            .route("/api/auth/signup", post(handlers::auth::signup_with_password))
+            .route("/api/auth/login", post(handlers::auth::login))
+            .route("/api/auth/logout", post(handlers::auth::logout));
+        info!("Public auth routes registered: /api/auth/{{register-guest,signup,login,logout}}");
+
+        // Protected routes (require authentication)
+        info!("Registering protected auth routes");
+        let protected_routes = Router::new()
+            .route("/api/auth/me", get(handlers::auth::me))
+            .route("/api/auth/link-wallet", post(handlers::auth::link_wallet))
+            .route("/api/favorites", get(handlers::favorites::list_favorites).post(handlers::favorites::add_favorite))
+            .route("/api/favorites/{contract_id}", delete(handlers::favorites::remove_favorite))
+            .layer(axum_middleware::from_fn_with_state(state.clone(), auth_middleware));
+        info!("Protected auth routes registered: /api/auth/{{me,link-wallet}}, /api/favorites");
+        protected_routes
+    } else {
+        // Every database-backed route answers 503 AUTH_UNAVAILABLE instead of 404
+        warn!("WARNING: Auth and favorites routes disabled (no database), answering 503");
+        Router::new()
+            .route("/api/auth/{*path}", any(handlers::auth::auth_unavailable))
+            .route("/api/favorites", any(handlers::auth::auth_unavailable))
+            .route("/api/favorites/{*path}", any(handlers::auth::auth_unavailable))
+    };
+
+    // Admin-only routes (auth_middleware runs first, then require_admin)
+    let admin_routes = Router::new()
+        .route("/api/soroban/sign-and-submit", post(handlers::soroban::sign_and_submit_handler))
+        .route("/api/soroban/queue", get(handlers::soroban::queue_status_handler))
+        .route("/api/soroban/queue/flush", post(handlers::soroban::queue_flush_handler))
+        .layer(axum_middleware::from_fn(require_admin))
         .layer(axum_middleware::from_fn_with_state(state.clone(), auth_middleware));
-    info!("Protected auth routes registered: /api/auth/{{me,link-wallet}}");
+    info!("Admin routes registered: /api/soroban/{{sign-and-submit,queue,queue/flush}}");
 
     // Merge protected routes with main app
     let mut app = app
         .merge(protected_routes)
+        .merge(admin_routes)
         .with_state(state);
 
+    // Tag every request's logs with a request id (echoed as x-request-id)
+    app = app.layer(axum_middleware::from_fn(middleware::request_id_middleware));
+
     // CRITICAL: Add CookieManagerLayer AFTER routes, BEFORE CORS
     info!("Adding CookieManagerLayer");
     app = app.layer(CookieManagerLayer::new());
 
     // Add CORS layer (must be last)
-    let app = app.layer(create_cors_layer(config.allowed_origins.clone()));
-
-    Ok(app)
+    app.layer(create_cors_layer(config.allowed_origins.clone(), config.cors_exposed_headers.clone()))
 }
 
 pub async fn run_server(config: AppConfig) -> Result<()> {
@@ -102,20 +162,19 @@ pub async fn run_server(config: AppConfig) -> Result<()> {
     info!("  Allowed origins: {:?}", config.allowed_origins);
 
     // Connect to database (REQUIRED for auth features)
-    info!("Connecting to database...");
-    let database_url = std::env::var("DATABASE_URL")
-        .map_err(|_| AppError::Config(
-            "DATABASE_URL not set. Please set DATABASE_URL in backend/.env file".to_string()
-        ))?;
-
-    info!("DATABASE_URL found: {}...", &database_url[..50.min(database_url.len())]);
-
-    let db_pool = database::connection::create_pool(&database_url).await
-        .map_err(|e| AppError::Database(format!("Failed to connect to database: {}", e)))?;
-
-    info!("✅ Database connected successfully");
-
-    let app = create_app(config.clone(), db_pool).await?;
+    let app = match connect_database().await {
+        Ok(db_pool) => create_app(config.clone(), db_pool).await?,
+        Err(e) if config.require_database => return Err(e),
+        Err(e) => {
+            warn!("==================================================================");
+            warn!("⚠️  DEGRADED MODE: starting without a database ({})", e);
+            warn!("⚠️  Auth and favorites routes answer 503 AUTH_UNAVAILABLE");
+            warn!("⚠️  Soroban read routes remain available");
+            warn!("⚠️  Set REQUIRE_DATABASE=true to refuse to start instead");
+            warn!("==================================================================");
+            create_app_without_database(config.clone()).await?
+        }
+    };
 
     let bind_address = format!("127.0.0.1:{}", config.port);
     let listener = tokio::net::TcpListener::bind(&bind_address)
@@ -126,9 +185,27 @@ pub async fn run_server(config: AppConfig) -> Result<()> {
     info!("Health check: http://{}/health", bind_address);
     info!("Auth endpoints: http://{}/api/auth/{{signup,login,logout,me}}", bind_address);
 
-    axum::serve(listener, app)
+    info!("  HTTP/2: {}, idle timeout: {}s", config.server.http2_enabled, config.server.idle_timeout_secs);
+
+    server::serve(listener, app, config.server.clone())
         .await
         .map_err(|e| AppError::Internal(format!("Server error: {}", e)))?;
 
     Ok(())
+}
+
+async fn connect_database() -> Result<sqlx::PgPool> {
+    info!("Connecting to database...");
+    let database_url = std::env::var("DATABASE_URL")
+        .map_err(|_| AppError::Config(
+            "DATABASE_URL not set. Please set DATABASE_URL in backend/.env file".to_string()
+        ))?;
+
+    info!("DATABASE_URL found: {}...", &database_url[..50.min(database_url.len())]);
+
+    let db_pool = database::connection::create_pool(&database_url).await
+        .map_err(|e| AppError::Database(format!("Failed to connect to database: {}", e)))?;
+
+    info!("✅ Database connected successfully");
+    Ok(db_pool)
 }
\ No newline at end of file
@@ -0,0 +1,117 @@
+//! Log output format
+//!
+//! Pretty, human-readable lines are the default for local development. Set
+//! `LOG_FORMAT=json` in production to emit one JSON object per line for log
+//! aggregators. Each line carries the current span's fields, including the
+//! `request_id` set by [`crate::middleware::request_id_middleware`] (wrapped here
+//! for readability):
+//!
+//! ```text
+//! {"timestamp":"2025-01-01T00:00:00.000000Z","level":"INFO","fields":{"message":"[HANDLER] Call contract function ..."},
+//!  "span":{"method":"POST","path":"/api/soroban/call-function","request_id":"6f1c...","name":"request"},
+//!  "spans":[{"method":"POST","path":"/api/soroban/call-function","request_id":"6f1c...","name":"request"}],"threadId":"ThreadId(3)"}
+//! ```
+
+use tracing::Subscriber;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// How log lines are rendered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable lines (development)
+    #[default]
+    Pretty,
+    /// One JSON object per line, with span fields (production log pipelines)
+    Json,
+}
+
+impl LogFormat {
+    pub fn parse(format: &str) -> Option<Self> {
+        match format.trim().to_lowercase().as_str() {
+            "pretty" | "text" => Some(Self::Pretty),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// Read `LOG_FORMAT`, falling back to pretty when unset or unrecognized
+    ///
+    /// Runs before logging is initialized, so a bad value is reported on stderr.
+    pub fn from_env() -> Self {
+        match std::env::var("LOG_FORMAT") {
+            Ok(value) => Self::parse(&value).unwrap_or_else(|| {
+                eprintln!("WARNING: Unknown LOG_FORMAT '{}', expected 'json' or 'pretty'; using pretty", value);
+                Self::Pretty
+            }),
+            Err(_) => Self::Pretty,
+        }
+    }
+}
+
+/// Install the global subscriber writing to stdout in `format`
+pub fn init(format: LogFormat) {
+    match format {
+        LogFormat::Pretty => tracing_subscriber::fmt()
+            .with_target(false)
+            .with_thread_ids(true)
+            .with_level(true)
+            .init(),
+        LogFormat::Json => json_subscriber(std::io::stdout).init(),
+    }
+}
+
+/// JSON subscriber writing to `writer`, including the current span and its parents
+pub fn json_subscriber<W>(writer: W) -> impl Subscriber + Send + Sync
+where
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    tracing_subscriber::fmt()
+        .json()
+        .with_current_span(true)
+        .with_span_list(true)
+        .with_target(false)
+        .with_thread_ids(true)
+        .with_writer(writer)
+        .finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::CapturedLogs;
+    use tracing::{info, info_span};
+
+    #[test]
+    fn test_parse_log_format() {
+        assert_eq!(LogFormat::parse("json"), Some(LogFormat::Json));
+        assert_eq!(LogFormat::parse(" JSON "), Some(LogFormat::Json));
+        assert_eq!(LogFormat::parse("pretty"), Some(LogFormat::Pretty));
+        assert_eq!(LogFormat::parse("xml"), None);
+        assert_eq!(LogFormat::default(), LogFormat::Pretty);
+    }
+
+    #[test]
+    fn test_json_output_includes_request_id() {
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = json_subscriber(move || writer.clone());
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = info_span!("request", request_id = "req-123", path = "/health");
+            let _entered = span.enter();
+            info!(contract = "CCFF", "Call contract function");
+        });
+
+        let output = logs.output();
+        let line: serde_json::Value = serde_json::from_str(output.lines().next().unwrap())
+            .unwrap_or_else(|e| panic!("not JSON ({}): {}", e, output));
+
+        assert_eq!(line["level"], "INFO");
+        assert_eq!(line["fields"]["message"], "Call contract function");
+        assert_eq!(line["fields"]["contract"], "CCFF");
+        assert_eq!(line["span"]["request_id"], "req-123");
+        assert_eq!(line["span"]["path"], "/health");
+        assert_eq!(line["spans"][0]["name"], "request");
+    }
+}
@@ -1,6 +1,7 @@
 use tracing::info;
 
 use stellar_xdr_service::{AppConfig, run_server};
+use stellar_xdr_service::logging::{self, LogFormat};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -10,11 +11,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Err(e) => eprintln!("WARNING: Failed to load .env: {}", e),
     }
 
-    tracing_subscriber::fmt()
-        .with_target(false)
-        .with_thread_ids(true)
-        .with_level(true)
-        .init();
+    // LOG_FORMAT=json for log aggregators; pretty by default
+    logging::init(LogFormat::from_env());
 
     info!("Starting Stellar XDR Service");
 
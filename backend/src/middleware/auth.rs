@@ -131,6 +131,36 @@ pub async fn auth_middleware(
     Ok(next.run(request).await)
 }
 
+/// Optional authentication middleware
+/// Inserts CurrentUser into request extensions when a valid token is present,
+/// but lets anonymous requests through unchanged.
+///
+/// Used on public routes whose behaviour depends on the caller's role.
+pub async fn optional_auth_middleware(
+    State(app_state): State<AppState>,
+    cookies: Cookies,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let token = crate::auth::get_token_from_cookies(&cookies, &app_state.config);
+
+    if let Some(token) = token.filter(|t| !t.is_empty()) {
+        match validate_token(&token, &app_state.config.jwt_secret)
+            .and_then(|claims| CurrentUser::from_claims(&claims))
+        {
+            Ok(user) => {
+                debug!("[MIDDLEWARE] Optional auth: request from {} (role: {})", user.username, user.role);
+                request.extensions_mut().insert(user);
+            }
+            Err(e) => {
+                debug!("[MIDDLEWARE] Optional auth: ignoring invalid token: {}", e);
+            }
+        }
+    }
+
+    next.run(request).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
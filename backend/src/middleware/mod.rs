@@ -1,7 +1,9 @@
 /// Middleware module for authentication and authorization
 pub mod auth;
+pub mod request_id;
 pub mod require_role;
 
 // Re-export for convenience
-pub use auth::{CurrentUser, auth_middleware};
+pub use auth::{CurrentUser, auth_middleware, optional_auth_middleware};
+pub use request_id::request_id_middleware;
 pub use require_role::{require_admin, require_chapter_lead};
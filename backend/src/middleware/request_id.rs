@@ -0,0 +1,80 @@
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use tracing::{info_span, Instrument};
+
+/// Header carrying the request id in both directions
+pub static REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest client-supplied request id we accept; longer ones are replaced
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// Run each request inside a `request` span tagged with its request id
+///
+/// Reuses the caller's `x-request-id` (e.g. from a load balancer) when it is a
+/// sane value, otherwise generates one, and echoes it on the response. Every
+/// log line emitted while handling the request carries the span's fields,
+/// which JSON log output includes verbatim.
+pub async fn request_id_middleware(request: Request, next: Next) -> Response {
+    let request_id = request
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| !id.is_empty() && id.len() <= MAX_REQUEST_ID_LEN)
+        .map(str::to_string)
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+    let span = info_span!(
+        "request",
+        request_id = %request_id,
+        method = %request.method(),
+        path = %request.uri().path(),
+    );
+
+    let mut response = next.run(request).instrument(span).await;
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER.clone(), value);
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, middleware::from_fn, routing::get, Router};
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        Router::new()
+            .route("/health", get(|| async { "ok" }))
+            .layer(from_fn(request_id_middleware))
+    }
+
+    #[tokio::test]
+    async fn test_request_id_generated_and_echoed() {
+        let response = app()
+            .oneshot(Request::builder().uri("/health").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let request_id = response.headers().get(&REQUEST_ID_HEADER).unwrap().to_str().unwrap();
+        assert!(uuid::Uuid::parse_str(request_id).is_ok(), "expected a UUID, got {}", request_id);
+    }
+
+    #[tokio::test]
+    async fn test_incoming_request_id_reused() {
+        let request = Request::builder()
+            .uri("/health")
+            .header(&REQUEST_ID_HEADER, "lb-trace-42")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.headers().get(&REQUEST_ID_HEADER).unwrap(), "lb-trace-42");
+    }
+}
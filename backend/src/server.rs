@@ -0,0 +1,192 @@
+//! HTTP server: accept loop and per-connection protocol tuning
+//!
+//! `axum::serve` uses fixed hyper settings, so connections are driven through
+//! hyper-util's auto builder instead. It detects the protocol per connection,
+//! serving HTTP/1.1 and cleartext HTTP/2 ("prior knowledge" h2c) on the same port.
+//!
+//! Trade-offs of the knobs in [`ServerConfig`]:
+//! - HTTP/2 multiplexes the frontend's parallel contract calls over one
+//!   connection, but one client can then hold many in-flight requests;
+//!   `http2_max_concurrent_streams` caps that per connection.
+//! - HTTP/2 keep-alive pings detect dead peers (sleeping laptops, dropped NAT
+//!   mappings) so their streams are freed, at the cost of a little idle traffic.
+//! - The idle timeout closes HTTP/1.1 keep-alive connections that never send
+//!   their next request. Too low and browsers reconnect constantly; too high
+//!   and idle sockets pile up behind a load balancer.
+//! - Connections are served with upgrade support, so WebSocket (HTTP/1.1
+//!   `Upgrade`) keeps working. SSE is an ordinary long-lived response: the idle
+//!   timeout only applies while waiting for request headers, never mid-stream.
+
+use axum::Router;
+use hyper_util::rt::{TokioExecutor, TokioIo, TokioTimer};
+use hyper_util::server::conn::auto::Builder;
+use hyper_util::service::TowerToHyperService;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tracing::{debug, warn};
+
+/// HTTP protocol and keep-alive settings for incoming connections
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerConfig {
+    /// Accept cleartext HTTP/2 alongside HTTP/1.1
+    pub http2_enabled: bool,
+    /// Interval between HTTP/2 keep-alive pings (0 disables pings)
+    pub http2_keep_alive_interval_secs: u64,
+    /// Close an HTTP/2 connection if a ping isn't acknowledged in time
+    pub http2_keep_alive_timeout_secs: u64,
+    /// Maximum concurrent streams per HTTP/2 connection
+    pub http2_max_concurrent_streams: u32,
+    /// Reuse HTTP/1.1 connections between requests
+    pub http1_keep_alive: bool,
+    /// How long an open connection may wait for the next request's headers
+    pub idle_timeout_secs: u64,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            http2_enabled: true,
+            http2_keep_alive_interval_secs: 20,
+            http2_keep_alive_timeout_secs: 10,
+            http2_max_concurrent_streams: 200,
+            http1_keep_alive: true,
+            idle_timeout_secs: 60,
+        }
+    }
+}
+
+impl ServerConfig {
+    /// Load from `HTTP2_ENABLED`, `HTTP2_KEEP_ALIVE_INTERVAL_SECS`,
+    /// `HTTP2_KEEP_ALIVE_TIMEOUT_SECS`, `HTTP2_MAX_CONCURRENT_STREAMS`,
+    /// `HTTP1_KEEP_ALIVE` and `HTTP_IDLE_TIMEOUT_SECS`
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        Self {
+            http2_enabled: std::env::var("HTTP2_ENABLED")
+                .unwrap_or_else(|_| defaults.http2_enabled.to_string())
+                .parse()
+                .unwrap_or(defaults.http2_enabled),
+            http2_keep_alive_interval_secs: std::env::var("HTTP2_KEEP_ALIVE_INTERVAL_SECS")
+                .unwrap_or_else(|_| defaults.http2_keep_alive_interval_secs.to_string())
+                .parse()
+                .unwrap_or(defaults.http2_keep_alive_interval_secs),
+            http2_keep_alive_timeout_secs: std::env::var("HTTP2_KEEP_ALIVE_TIMEOUT_SECS")
+                .unwrap_or_else(|_| defaults.http2_keep_alive_timeout_secs.to_string())
+                .parse()
+                .unwrap_or(defaults.http2_keep_alive_timeout_secs),
+            http2_max_concurrent_streams: std::env::var("HTTP2_MAX_CONCURRENT_STREAMS")
+                .unwrap_or_else(|_| defaults.http2_max_concurrent_streams.to_string())
+                .parse()
+                .unwrap_or(defaults.http2_max_concurrent_streams),
+            http1_keep_alive: std::env::var("HTTP1_KEEP_ALIVE")
+                .unwrap_or_else(|_| defaults.http1_keep_alive.to_string())
+                .parse()
+                .unwrap_or(defaults.http1_keep_alive),
+            idle_timeout_secs: std::env::var("HTTP_IDLE_TIMEOUT_SECS")
+                .unwrap_or_else(|_| defaults.idle_timeout_secs.to_string())
+                .parse()
+                .unwrap_or(defaults.idle_timeout_secs),
+        }
+    }
+
+    /// Build the per-connection hyper builder from these settings
+    pub fn connection_builder(&self) -> Builder<TokioExecutor> {
+        let mut builder = Builder::new(TokioExecutor::new());
+
+        builder
+            .http1()
+            .timer(TokioTimer::new())
+            .keep_alive(self.http1_keep_alive)
+            .header_read_timeout(Duration::from_secs(self.idle_timeout_secs));
+
+        let ping_interval = (self.http2_keep_alive_interval_secs > 0)
+            .then(|| Duration::from_secs(self.http2_keep_alive_interval_secs));
+        builder
+            .http2()
+            .timer(TokioTimer::new())
+            .keep_alive_interval(ping_interval)
+            .keep_alive_timeout(Duration::from_secs(self.http2_keep_alive_timeout_secs))
+            .max_concurrent_streams(self.http2_max_concurrent_streams);
+
+        if self.http2_enabled {
+            builder
+        } else {
+            builder.http1_only()
+        }
+    }
+}
+
+/// Serve `app` on `listener` until the process exits
+pub async fn serve(listener: TcpListener, app: Router, config: ServerConfig) -> std::io::Result<()> {
+    let builder = config.connection_builder();
+
+    loop {
+        let (stream, peer) = match listener.accept().await {
+            Ok(connection) => connection,
+            Err(e) => {
+                // Usually fd exhaustion; back off instead of spinning
+                warn!("[SERVER] ⚠️  Failed to accept connection: {}", e);
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                continue;
+            }
+        };
+
+        let builder = builder.clone();
+        let service = TowerToHyperService::new(app.clone());
+
+        tokio::spawn(async move {
+            if let Err(e) = builder.serve_connection_with_upgrades(TokioIo::new(stream), service).await {
+                debug!("[SERVER] Connection from {} closed: {}", peer, e);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::routing::get;
+
+    async fn spawn_server(config: ServerConfig) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = Router::new().route("/health", get(|| async { "ok" }));
+
+        tokio::spawn(serve(listener, app, config));
+
+        format!("http://{}/health", addr)
+    }
+
+    #[tokio::test]
+    async fn test_accepts_http2_request() {
+        let url = spawn_server(ServerConfig::default()).await;
+        let client = reqwest::Client::builder().http2_prior_knowledge().build().unwrap();
+
+        let response = client.get(&url).send().await.unwrap();
+
+        assert_eq!(response.status().as_u16(), 200);
+        assert_eq!(response.version(), reqwest::Version::HTTP_2);
+        assert_eq!(response.text().await.unwrap(), "ok");
+    }
+
+    #[tokio::test]
+    async fn test_http1_still_served_on_same_port() {
+        let url = spawn_server(ServerConfig::default()).await;
+        let client = reqwest::Client::builder().http1_only().build().unwrap();
+
+        let response = client.get(&url).send().await.unwrap();
+
+        assert_eq!(response.status().as_u16(), 200);
+        assert_eq!(response.version(), reqwest::Version::HTTP_11);
+    }
+
+    #[tokio::test]
+    async fn test_http2_can_be_disabled() {
+        let url = spawn_server(ServerConfig { http2_enabled: false, ..ServerConfig::default() }).await;
+        let client = reqwest::Client::builder().http2_prior_knowledge().build().unwrap();
+
+        assert!(client.get(&url).send().await.is_err());
+    }
+}
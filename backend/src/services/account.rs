@@ -0,0 +1,175 @@
+use soroban_client::xdr::{
+    AccountId, LedgerEntryData, LedgerKey, LedgerKeyAccount, Limits, PublicKey, ReadXdr, Uint256, WriteXdr,
+};
+use tracing::{info, debug};
+
+use crate::error::{AppError, Result};
+use crate::services::stellar::XdrConfig;
+use crate::utils::truncate_address;
+
+/// Stroops per XLM
+const STROOPS_PER_XLM: i64 = 10_000_000;
+
+/// Classic account fields read from its ledger entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountInfo {
+    pub balance_stroops: i64,
+    pub sequence: i64,
+    pub num_sub_entries: u32,
+}
+
+impl AccountInfo {
+    /// Native balance formatted as XLM with 7 decimals
+    pub fn balance_xlm(&self) -> String {
+        let sign = if self.balance_stroops < 0 { "-" } else { "" };
+        let stroops = self.balance_stroops.unsigned_abs();
+        let per_xlm = STROOPS_PER_XLM as u64;
+        format!("{}{}.{:07}", sign, stroops / per_xlm, stroops % per_xlm)
+    }
+}
+
+/// Result of an account lookup; `account` is `None` for unfunded addresses
+#[derive(Debug, Clone)]
+pub struct AccountLookup {
+    pub account: Option<AccountInfo>,
+    pub latest_ledger: u32,
+}
+
+/// Build the base64 XDR Account LedgerKey for a `G...` address
+pub fn account_ledger_key(address: &str) -> Result<String> {
+    let public_key = stellar_strkey::ed25519::PublicKey::from_string(address)
+        .map_err(|_| AppError::InvalidInput(format!("Invalid account address: {}", address)))?;
+
+    LedgerKey::Account(LedgerKeyAccount {
+        account_id: AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(public_key.0))),
+    })
+    .to_xdr_base64(Limits::none())
+    .map_err(|e| AppError::XdrEncoding(format!("Failed to encode account key: {}", e)))
+}
+
+/// Read a classic account via `getLedgerEntries`
+pub async fn get_account(config: &XdrConfig, address: &str) -> Result<AccountLookup> {
+    info!("[RPC] get_account called - address: {}", truncate_address(address));
+    debug!("[RPC] Full account address: {}", address);
+
+    let key = account_ledger_key(address)?;
+    let response = crate::services::soroban::get_ledger_entries(config, vec![key]).await?;
+
+    let Some(entry) = response.first_entry() else {
+        debug!("[RPC] Account {} not found (unfunded)", address);
+        return Ok(AccountLookup { account: None, latest_ledger: response.latest_ledger });
+    };
+
+    let account = match LedgerEntryData::from_xdr_base64(&entry.xdr, Limits::none())
+        .map_err(|e| AppError::XdrDecoding(format!("Failed to decode account entry: {}", e)))?
+    {
+        LedgerEntryData::Account(account) => AccountInfo {
+            balance_stroops: account.balance,
+            sequence: account.seq_num.0,
+            num_sub_entries: account.num_sub_entries,
+        },
+        other => {
+            return Err(AppError::XdrDecoding(format!(
+                "Expected an account entry, got {}",
+                other.name()
+            )))
+        }
+    };
+
+    info!("[RPC] ✅ get_account successful - balance: {} XLM", account.balance_xlm());
+
+    Ok(AccountLookup { account: Some(account), latest_ledger: response.latest_ledger })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{spawn_mock_rpc, CapturedLogs};
+    use soroban_client::xdr::{AccountEntry, AccountEntryExt, SequenceNumber, Thresholds};
+
+    const FUNDED: &str = "GB43KVROR7TFJ6KAPCYRF2FJROTZAH4FHLTJLPWX4DRZCC5NASLGITR6";
+    const UNFUNDED: &str = "GDAT5HWTGIU4TSSZ4752OUC4SABDLTLZFRPZUJ3D6LKBNEPA7V2CIG54";
+
+    fn funded_entry_xdr() -> String {
+        let public_key = stellar_strkey::ed25519::PublicKey::from_string(FUNDED).unwrap();
+        LedgerEntryData::Account(AccountEntry {
+            account_id: AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(public_key.0))),
+            balance: 1_234_567_890,
+            seq_num: SequenceNumber(42),
+            num_sub_entries: 3,
+            inflation_dest: None,
+            flags: 0,
+            home_domain: Default::default(),
+            thresholds: Thresholds([1, 0, 0, 0]),
+            signers: Default::default(),
+            ext: AccountEntryExt::V0,
+        })
+        .to_xdr_base64(Limits::none())
+        .unwrap()
+    }
+
+    /// Mock RPC answering getLedgerEntries for one funded account
+    async fn spawn_account_rpc() -> String {
+        let funded_key = account_ledger_key(FUNDED).unwrap();
+        spawn_mock_rpc(move |body| {
+            assert_eq!(body["method"], "getLedgerEntries");
+            let key = body["params"]["keys"][0].as_str().unwrap().to_string();
+            let entries = if key == funded_key {
+                serde_json::json!([{ "key": key, "xdr": funded_entry_xdr(), "lastModifiedLedgerSeq": 900 }])
+            } else {
+                serde_json::json!([])
+            };
+            serde_json::json!({ "entries": entries, "latestLedger": 1000 })
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_funded_account() {
+        let config = XdrConfig { rpc_url: spawn_account_rpc().await, ..XdrConfig::default() };
+
+        let lookup = get_account(&config, FUNDED).await.unwrap();
+        let account = lookup.account.expect("funded account");
+
+        assert_eq!(lookup.latest_ledger, 1000);
+        assert_eq!(account.balance_stroops, 1_234_567_890);
+        assert_eq!(account.balance_xlm(), "123.4567890");
+        assert_eq!(account.sequence, 42);
+        assert_eq!(account.num_sub_entries, 3);
+    }
+
+    #[tokio::test]
+    async fn test_unfunded_account() {
+        let config = XdrConfig { rpc_url: spawn_account_rpc().await, ..XdrConfig::default() };
+
+        let lookup = get_account(&config, UNFUNDED).await.unwrap();
+
+        assert!(lookup.account.is_none());
+        assert_eq!(lookup.latest_ledger, 1000);
+    }
+
+    #[tokio::test]
+    async fn test_info_logs_redact_address() {
+        let config = XdrConfig { rpc_url: spawn_account_rpc().await, ..XdrConfig::default() };
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::INFO)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+
+        let _guard = tracing::subscriber::set_default(subscriber);
+        get_account(&config, UNFUNDED).await.unwrap();
+
+        let output = logs.output();
+        assert!(output.contains(&truncate_address(UNFUNDED)), "expected truncated address in: {}", output);
+        assert!(!output.contains(UNFUNDED), "full address leaked into INFO logs: {}", output);
+    }
+
+    #[test]
+    fn test_account_ledger_key_rejects_bad_address() {
+        assert!(account_ledger_key("GNOTANADDRESS").is_err());
+        assert!(account_ledger_key("CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF").is_err());
+    }
+}
@@ -4,13 +4,14 @@ use crate::database::models::User;
 use crate::error::Result;
 use shared::dto::auth::Guest;
 use shared::dto::user::{SignUpResponse, UserPublic};
+use crate::utils::truncate_address;
 use tracing::{info, error};
 
 pub struct AuthService;
 
 impl AuthService {
     pub async fn register_or_login_guest(pool: &DbPool, guest: Guest) -> Result<SignUpResponse> {
-        info!("AUTH SERVICE: Processing registration - username={}, wallet_address={}", guest.username, guest.wallet_address);
+        info!("AUTH SERVICE: Processing registration - username={}, wallet_address={}", guest.username, truncate_address(&guest.wallet_address));
 
         // Check if user already exists
         info!("AUTH SERVICE: Checking if wallet already exists in database...");
@@ -0,0 +1,136 @@
+use tracing::{info, debug};
+
+use crate::error::{AppError, Result};
+use crate::services::soroban::client::decode_result_xdr;
+use crate::services::soroban::manager::ScalableContractManager;
+use crate::services::soroban::simulation::SimulationOptions;
+use crate::services::soroban::CostLimits;
+use crate::services::stellar::{generate_hello_yew_xdr, XdrConfig};
+use crate::types::ContractFunction;
+use crate::utils::truncate_address;
+use shared::dto::leaderboard::LeaderboardEntry;
+use shared::dto::soroban::{CallCacheMode, FunctionParameter, ScTypeHint, TupleField};
+
+/// Build the unsigned `submit_score` transaction; the player is the source and signs it
+pub async fn submit_score_xdr(config: &XdrConfig, player: &str, score: u64, cost_limits: &CostLimits) -> Result<String> {
+    info!("[LEADERBOARD] Building submit_score - player: {}, score: {}", truncate_address(player), score);
+
+    stellar_strkey::ed25519::PublicKey::from_string(player)
+        .map_err(|_| AppError::InvalidInput("Player must be a Stellar account address (G...)".to_string()))?;
+
+    let function = ContractFunction::SubmitScore { player: player.to_string(), score };
+    generate_hello_yew_xdr(config, player, &function, cost_limits).await
+}
+
+/// Read the `n` highest scores from the leaderboard contract via simulation
+///
+/// Goes through the contract manager, so reads share its pool, circuit breaker
+/// and cache. Scores change as submissions land, so results are cached per ledger.
+pub async fn top_scores(
+    manager: &ScalableContractManager,
+    contract_id: &str,
+    n: u32,
+    options: SimulationOptions,
+) -> Result<Vec<LeaderboardEntry>> {
+    info!("[LEADERBOARD] Reading top {} scores from {}", n, contract_id);
+
+    let function = ContractFunction::TopScores { n };
+    let response = manager
+        .call_contract_function(
+            contract_id,
+            function.name(),
+            vec![FunctionParameter::U32(n)],
+            None,
+            Some(options),
+            CallCacheMode::Ledger,
+        )
+        .await?;
+
+    if !response.success {
+        return Err(AppError::Contract(response.error.unwrap_or_else(|| "Unknown contract error".to_string())));
+    }
+
+    let Some(result_xdr) = response.result_xdr else {
+        debug!("[LEADERBOARD] {} returned no value", function.name());
+        return Ok(Vec::new());
+    };
+
+    let decoded = decode_result_xdr(&result_xdr, Some(&leaderboard_hint()))?;
+    let mut entries = leaderboard_entries(&decoded)?;
+    entries.truncate(n as usize);
+    Ok(entries)
+}
+
+/// `vec<(address, u64)>` decoded as `[{ "player": "G...", "score": "1200" }, ...]`
+fn leaderboard_hint() -> ScTypeHint {
+    ScTypeHint::Vec(Box::new(ScTypeHint::Tuple(vec![
+        TupleField { name: "player".to_string(), hint: None },
+        TupleField { name: "score".to_string(), hint: Some(ScTypeHint::Decimal(0)) },
+    ])))
+}
+
+/// Turn the decoded leaderboard into ranked entries, highest score first
+fn leaderboard_entries(decoded: &serde_json::Value) -> Result<Vec<LeaderboardEntry>> {
+    let malformed = || AppError::XdrDecoding(format!("Unexpected leaderboard value: {}", decoded));
+
+    let mut entries = decoded
+        .as_array()
+        .ok_or_else(malformed)?
+        .iter()
+        .map(|item| {
+            let player = item["player"].as_str().ok_or_else(malformed)?.to_string();
+            let score = item["score"].as_str().and_then(|s| s.parse().ok()).ok_or_else(malformed)?;
+            Ok(LeaderboardEntry { rank: 0, player, score })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Stable sort keeps the contract's tie order
+    entries.sort_by(|a, b| b.score.cmp(&a.score));
+    for (i, entry) in entries.iter_mut().enumerate() {
+        entry.rank = i as u32 + 1;
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAYER_A: &str = "GB43KVROR7TFJ6KAPCYRF2FJROTZAH4FHLTJLPWX4DRZCC5NASLGITR6";
+    const PLAYER_B: &str = "GDAT5HWTGIU4TSSZ4752OUC4SABDLTLZFRPZUJ3D6LKBNEPA7V2CIG54";
+
+    #[test]
+    fn test_entries_ranked_by_score() {
+        let decoded = serde_json::json!([
+            {"player": PLAYER_B, "score": "950"},
+            {"player": PLAYER_A, "score": "1200"},
+        ]);
+
+        let entries = leaderboard_entries(&decoded).unwrap();
+
+        assert_eq!(entries, vec![
+            LeaderboardEntry { rank: 1, player: PLAYER_A.to_string(), score: 1200 },
+            LeaderboardEntry { rank: 2, player: PLAYER_B.to_string(), score: 950 },
+        ]);
+    }
+
+    #[test]
+    fn test_malformed_leaderboard_rejected() {
+        assert!(leaderboard_entries(&serde_json::json!("not a list")).is_err());
+        assert!(leaderboard_entries(&serde_json::json!([{"player": PLAYER_A, "score": "-1"}])).is_err());
+        assert!(leaderboard_entries(&serde_json::json!([[PLAYER_A, "1200"]])).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_submit_score_rejects_contract_player() {
+        let result = submit_score_xdr(
+            &XdrConfig::default(),
+            "CCYOZJCOPG34LLQQ7N24YXBM7LL62R7ONMZ3G6WZAAYPB5OYKOMJRN63",
+            100,
+            &CostLimits::standard(),
+        ).await;
+
+        assert!(matches!(result, Err(AppError::InvalidInput(_))));
+    }
+}
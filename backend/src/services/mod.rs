@@ -1,7 +1,9 @@
+pub mod account;
 pub mod auth_service;
+pub mod leaderboard;
 pub mod stellar;
 pub mod soroban;
 
 pub use auth_service::AuthService;
-pub use stellar::{XdrConfig, generate_hello_yew_xdr, submit_signed_transaction};
+pub use stellar::{XdrConfig, generate_hello_yew_xdr, generate_hello_yew_transaction, estimate_hello_yew_fee, generate_payment_xdr, submit_signed_transaction, Signer, LocalSigner};
 pub use soroban::{ScalableContractManager, ContractMetrics, ContractInfo, HealthStatus};
\ No newline at end of file
@@ -0,0 +1,199 @@
+// Cost budget guard for Soroban simulations
+//
+// Simulation tells us how much CPU and fee a call would need before anything is
+// prepared or submitted. A runaway contract can report enormous requirements, so
+// callers check the simulation against a configurable budget and refuse to
+// proceed when it is exceeded.
+
+use serde::{Deserialize, Serialize};
+use soroban_client::xdr::{FeeBumpTransactionInnerTx, Limits, ReadXdr, TransactionEnvelope, TransactionExt};
+
+use super::simulation::SimulateTransactionResponse;
+use crate::error::{AppError, Result};
+
+/// Upper bounds on what a single simulated call may require
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CostLimits {
+    /// Maximum CPU instructions the call may budget
+    pub max_cpu_instructions: u64,
+    /// Maximum resource fee in stroops
+    pub max_resource_fee: u64,
+}
+
+impl CostLimits {
+    /// Default limits for regular users
+    pub const fn standard() -> Self {
+        Self {
+            max_cpu_instructions: 50_000_000,
+            max_resource_fee: 10_000_000,
+        }
+    }
+
+    /// Default limits for admins (network per-transaction maximums)
+    pub const fn admin() -> Self {
+        Self {
+            max_cpu_instructions: 100_000_000,
+            max_resource_fee: 100_000_000,
+        }
+    }
+
+    /// Check raw cost figures against these limits
+    pub fn check(&self, cpu_instructions: Option<u64>, resource_fee: Option<u64>) -> Result<()> {
+        if let Some(cpu) = cpu_instructions {
+            if cpu > self.max_cpu_instructions {
+                return Err(AppError::CostLimitExceeded(format!(
+                    "simulation requires {} CPU instructions, limit is {}",
+                    cpu, self.max_cpu_instructions
+                )));
+            }
+        }
+
+        if let Some(fee) = resource_fee {
+            if fee > self.max_resource_fee {
+                return Err(AppError::CostLimitExceeded(format!(
+                    "simulation requires a resource fee of {} stroops, limit is {}",
+                    fee, self.max_resource_fee
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check a simulation response against these limits
+    pub fn check_simulation(&self, simulation: &SimulateTransactionResponse) -> Result<()> {
+        self.check(simulation.get_cpu_instructions(), simulation.get_min_resource_fee())
+    }
+
+    /// Check a prepared transaction against these limits
+    ///
+    /// Preparing a transaction copies its simulation's budget into the envelope's
+    /// Soroban data, so this is the same check applied to XDR about to be returned
+    /// or submitted. Classic transactions carry no Soroban data and always pass.
+    pub fn check_envelope(&self, envelope: &TransactionEnvelope) -> Result<()> {
+        let ext = match envelope {
+            TransactionEnvelope::Tx(v1) => &v1.tx.ext,
+            TransactionEnvelope::TxFeeBump(fee_bump) => match &fee_bump.tx.inner_tx {
+                FeeBumpTransactionInnerTx::Tx(inner) => &inner.tx.ext,
+            },
+            TransactionEnvelope::TxV0(_) => return Ok(()),
+        };
+
+        match ext {
+            TransactionExt::V1(data) => self.check(
+                Some(u64::from(data.resources.instructions)),
+                u64::try_from(data.resource_fee).ok(),
+            ),
+            TransactionExt::V0 => Ok(()),
+        }
+    }
+
+    /// Decode a base64 transaction envelope and check it against these limits
+    pub fn check_envelope_xdr(&self, envelope_xdr: &str) -> Result<()> {
+        let envelope = TransactionEnvelope::from_xdr_base64(envelope_xdr, Limits::none())
+            .map_err(|e| AppError::InvalidInput(format!("Invalid transaction XDR: {:?}", e)))?;
+        self.check_envelope(&envelope)
+    }
+}
+
+impl Default for CostLimits {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_client::xdr::{
+        Memo, MuxedAccount, Preconditions, SequenceNumber, SorobanTransactionData, Transaction,
+        TransactionV1Envelope, Uint256, WriteXdr,
+    };
+
+    fn simulation_with_fee(fee: &str) -> SimulateTransactionResponse {
+        SimulateTransactionResponse {
+            latest_ledger: 12345,
+            min_resource_fee: Some(fee.to_string()),
+            error: None,
+            results: None,
+            transaction_data: None,
+            restore_preamble: None,
+            events: None,
+            state_changes: None,
+        }
+    }
+
+    #[test]
+    fn test_within_limits() {
+        let simulation = simulation_with_fee("5000");
+        assert!(CostLimits::standard().check_simulation(&simulation).is_ok());
+        assert!(CostLimits::standard().check(Some(1_000_000), Some(5000)).is_ok());
+    }
+
+    #[test]
+    fn test_high_cpu_rejected() {
+        let err = CostLimits::standard().check(Some(90_000_000), Some(5000)).unwrap_err();
+
+        assert_eq!(err.error_type(), "COST_LIMIT_EXCEEDED");
+        assert_eq!(err.status_code(), axum::http::StatusCode::BAD_REQUEST);
+        assert!(err.to_string().contains("90000000 CPU instructions"));
+
+        // Admins get a higher ceiling
+        assert!(CostLimits::admin().check(Some(90_000_000), Some(5000)).is_ok());
+    }
+
+    #[test]
+    fn test_high_fee_simulation_rejected() {
+        let simulation = simulation_with_fee("50000000");
+        let err = CostLimits::standard().check_simulation(&simulation).unwrap_err();
+
+        assert_eq!(err.error_type(), "COST_LIMIT_EXCEEDED");
+        assert!(err.to_string().contains("resource fee of 50000000 stroops"));
+        assert!(CostLimits::admin().check_simulation(&simulation).is_ok());
+    }
+
+    /// An unsigned envelope, prepared with `instructions` unless `None`
+    fn envelope(instructions: Option<u32>) -> String {
+        let ext = match instructions {
+            Some(instructions) => {
+                let mut bytes = Vec::new();
+                bytes.extend_from_slice(&0u32.to_be_bytes()); // ext v0
+                bytes.extend_from_slice(&0u32.to_be_bytes()); // read_only: empty
+                bytes.extend_from_slice(&0u32.to_be_bytes()); // read_write: empty
+                bytes.extend_from_slice(&instructions.to_be_bytes());
+                bytes.extend_from_slice(&0u32.to_be_bytes()); // read bytes
+                bytes.extend_from_slice(&0u32.to_be_bytes()); // write bytes
+                bytes.extend_from_slice(&5_000i64.to_be_bytes()); // resource fee
+                TransactionExt::V1(SorobanTransactionData::from_xdr(&bytes, Limits::none()).unwrap())
+            }
+            None => TransactionExt::V0,
+        };
+
+        TransactionEnvelope::Tx(TransactionV1Envelope {
+            tx: Transaction {
+                source_account: MuxedAccount::Ed25519(Uint256([1u8; 32])),
+                fee: 100,
+                seq_num: SequenceNumber(1),
+                cond: Preconditions::None,
+                memo: Memo::None,
+                operations: Default::default(),
+                ext,
+            },
+            signatures: Default::default(),
+        })
+        .to_xdr_base64(Limits::none())
+        .unwrap()
+    }
+
+    #[test]
+    fn test_prepared_envelope_checked() {
+        assert!(CostLimits::standard().check_envelope_xdr(&envelope(Some(1_000_000))).is_ok());
+        assert!(CostLimits::standard().check_envelope_xdr(&envelope(None)).is_ok());
+
+        let err = CostLimits::standard().check_envelope_xdr(&envelope(Some(90_000_000))).unwrap_err();
+        assert_eq!(err.error_type(), "COST_LIMIT_EXCEEDED");
+        assert!(CostLimits::admin().check_envelope_xdr(&envelope(Some(90_000_000))).is_ok());
+
+        assert!(matches!(CostLimits::standard().check_envelope_xdr("AAAA"), Err(AppError::InvalidInput(_))));
+    }
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
@@ -40,6 +41,14 @@ struct CircuitBreakerState {
     failure_count: u32,
     success_count: u32,
     last_failure_time: Option<Instant>,
+    last_error: Option<LastError>,
+}
+
+/// Most recent failure seen by a circuit breaker, kept for diagnosis
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LastError {
+    pub message: String,
+    pub at: DateTime<Utc>,
 }
 
 /// Circuit breaker for RPC failures - prevents cascading failures
@@ -59,6 +68,7 @@ impl CircuitBreaker {
                 failure_count: 0,
                 success_count: 0,
                 last_failure_time: None,
+                last_error: None,
             })),
         }
     }
@@ -67,6 +77,7 @@ impl CircuitBreaker {
     pub async fn call<F, T, E>(&self, f: F) -> Result<T, CircuitBreakerError<E>>
     where
         F: std::future::Future<Output = Result<T, E>>,
+        E: std::fmt::Display,
     {
         // Check if circuit is open
         {
@@ -91,7 +102,7 @@ impl CircuitBreaker {
                 Ok(result)
             }
             Err(error) => {
-                self.on_failure().await;
+                self.on_failure(error.to_string()).await;
                 Err(CircuitBreakerError::RequestFailed(error))
             }
         }
@@ -124,11 +135,12 @@ impl CircuitBreaker {
     }
 
     /// Record a failed call
-    async fn on_failure(&self) {
+    async fn on_failure(&self, message: String) {
         let mut state = self.state.write().await;
 
         state.failure_count += 1;
         state.last_failure_time = Some(Instant::now());
+        state.last_error = Some(LastError { message, at: Utc::now() });
         state.success_count = 0; // Reset success count
 
         if state.failure_count >= self.config.failure_threshold && state.state != CircuitState::Open {
@@ -158,6 +170,7 @@ impl CircuitBreaker {
             failure_count: state.failure_count,
             success_count: state.success_count,
             is_open: state.state == CircuitState::Open,
+            last_error: state.last_error.clone(),
         }
     }
 
@@ -169,6 +182,7 @@ impl CircuitBreaker {
         state.failure_count = 0;
         state.success_count = 0;
         state.last_failure_time = None;
+        state.last_error = None;
     }
 }
 
@@ -195,6 +209,8 @@ pub struct CircuitBreakerStats {
     pub failure_count: u32,
     pub success_count: u32,
     pub is_open: bool,
+    /// Why the breaker last recorded a failure (survives recovery until reset)
+    pub last_error: Option<LastError>,
 }
 
 // Manual Serialize impl for CircuitState
@@ -211,3 +227,43 @@ impl serde::Serialize for CircuitState {
         serializer.serialize_str(state_str)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_failing_call_populates_last_error() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig::default());
+        assert!(breaker.stats().await.last_error.is_none());
+
+        let result = breaker.call(async { Err::<(), _>("RPC request failed: connection refused") }).await;
+        assert!(result.is_err());
+
+        let last_error = breaker.stats().await.last_error.expect("last error recorded");
+        assert_eq!(last_error.message, "RPC request failed: connection refused");
+        assert!(Utc::now() - last_error.at < chrono::Duration::seconds(5));
+
+        // A later success doesn't hide why the breaker last failed
+        breaker.call(async { Ok::<_, String>(()) }).await.unwrap();
+        assert!(breaker.stats().await.last_error.is_some());
+
+        breaker.reset().await;
+        assert!(breaker.stats().await.last_error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_open_circuit_keeps_opening_error() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig { failure_threshold: 2, ..Default::default() });
+
+        for attempt in 1..=2 {
+            let _ = breaker.call(async move { Err::<(), _>(format!("failure {}", attempt)) }).await;
+        }
+        let rejected = breaker.call(async { Ok::<_, String>(()) }).await;
+
+        assert!(matches!(rejected, Err(CircuitBreakerError::CircuitOpen)));
+        let stats = breaker.stats().await;
+        assert!(stats.is_open);
+        assert_eq!(stats.last_error.unwrap().message, "failure 2");
+    }
+}
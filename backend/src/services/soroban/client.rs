@@ -1,21 +1,219 @@
 use soroban_client::{
     Server, Options,
-    transaction::{Account, TransactionBuilder, AccountBehavior, TransactionBuilderBehavior, TransactionBehavior},
+    error::Error as SorobanClientError,
+    transaction::{Account, Transaction, TransactionBuilder, AccountBehavior, TransactionBuilderBehavior, TransactionBehavior},
     contract::{Contracts, ContractBehavior},
     xdr::{Limits, WriteXdr, ReadXdr, TransactionEnvelope, ScVal, ScSymbol},
     address::{Address, AddressTrait},
 };
 use std::{cell::RefCell, rc::Rc};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
 use tracing::{info, debug, error, warn};
 use crate::types::ContractFunction;
-use crate::services::stellar::XdrConfig;
+use crate::services::stellar::{decode_operation, time_bounds, XdrConfig, DEFAULT_TX_TIMEOUT_SECS};
 use crate::utils::truncate_address;
-use shared::dto::soroban::{FunctionParameter, CallContractFunctionResponse, SimulationDetailsDto};
+use shared::amount::format_amount;
+use shared::dto::soroban::{FunctionParameter, CallContractFunctionResponse, SimulationDetailsDto, ScTypeHint, TupleField};
 
-use crate::error::{AppError, Result};
+use super::diagnostics::extract_error;
+use crate::error::{AppError, Result, RpcError};
 
 // ContractConfig removed - using XdrConfig from stellar.rs instead (deduplication)
 
+/// Monotonic JSON-RPC request id shared by all outgoing calls
+static RPC_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Server options for an RPC URL
+///
+/// Plain HTTP is only allowed for loopback URLs (standalone networks and local mock servers).
+pub fn rpc_options(rpc_url: &str) -> Options {
+    let loopback = ["http://localhost", "http://127.0.0.1", "http://[::1]"]
+        .iter()
+        .any(|prefix| rpc_url.starts_with(prefix));
+
+    Options {
+        allow_http: loopback,
+        ..Options::default()
+    }
+}
+
+/// Default maximum nesting of Vec/Map/Enum values (`MAX_PARAMETER_DEPTH` overrides)
+pub const DEFAULT_MAX_PARAMETER_DEPTH: usize = 16;
+
+/// Depth budget for the XDR reader itself on untrusted ScVals
+///
+/// Each nesting level costs the reader several frames, so this sits well
+/// above the parameter depth; `check_scval_depth` enforces the real limit.
+const SCVAL_READ_DEPTH: u32 = 512;
+
+/// Maximum nesting accepted when converting parameters and decoding values
+pub fn max_parameter_depth() -> usize {
+    static MAX_DEPTH: OnceLock<usize> = OnceLock::new();
+    *MAX_DEPTH.get_or_init(|| {
+        std::env::var("MAX_PARAMETER_DEPTH")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|depth| *depth > 0)
+            .unwrap_or(DEFAULT_MAX_PARAMETER_DEPTH)
+    })
+}
+
+/// XDR limits for decoding ScVals that come from clients or RPC responses
+pub fn scval_read_limits() -> Limits {
+    Limits::depth(SCVAL_READ_DEPTH)
+}
+
+fn nesting_too_deep() -> AppError {
+    AppError::InvalidInput(format!("parameter nesting too deep (max depth {})", max_parameter_depth()))
+}
+
+/// Reject client-supplied values nested deeper than `max_parameter_depth()`
+pub fn check_scval_depth(scval: &ScVal) -> Result<()> {
+    if exceeds_max_depth(scval) {
+        return Err(nesting_too_deep());
+    }
+    Ok(())
+}
+
+/// Reject RPC results nested deeper than `max_parameter_depth()`
+///
+/// The caller didn't send the value, so this is a decoding failure rather than invalid input.
+fn check_result_depth(scval: &ScVal) -> Result<()> {
+    if exceeds_max_depth(scval) {
+        return Err(AppError::XdrDecoding(format!(
+            "result nesting too deep (max depth {})",
+            max_parameter_depth()
+        )));
+    }
+    Ok(())
+}
+
+/// Whether a value nests deeper than `max_parameter_depth()`
+///
+/// Walks iteratively so the check itself can't overflow the stack.
+fn exceeds_max_depth(scval: &ScVal) -> bool {
+    let max_depth = max_parameter_depth();
+    let mut pending = vec![(scval, 1)];
+
+    while let Some((value, depth)) = pending.pop() {
+        if depth > max_depth {
+            return true;
+        }
+        match value {
+            ScVal::Vec(Some(items)) => pending.extend(items.0.iter().map(|item| (item, depth + 1))),
+            ScVal::Map(Some(map)) => {
+                for entry in map.0.iter() {
+                    pending.push((&entry.key, depth + 1));
+                    pending.push((&entry.val, depth + 1));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// Allocate the next JSON-RPC request id
+fn next_rpc_id() -> u64 {
+    RPC_REQUEST_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Build a JSON-RPC 2.0 request body with a fresh id
+fn build_rpc_request(method: &str, params: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": next_rpc_id(),
+        "method": method,
+        "params": params
+    })
+}
+
+/// Send several JSON-RPC calls in a single batch request
+///
+/// Responses may arrive in any order; they are matched back to their
+/// requests by id, so the returned vector lines up with `methods`.
+///
+/// # Arguments
+/// * `rpc_url` - RPC endpoint URL
+/// * `methods` - `(method, params)` pairs to send
+///
+/// # Returns
+/// One result per request: the `result` value, or an error if that call failed
+pub async fn rpc_batch(
+    rpc_url: &str,
+    methods: Vec<(String, serde_json::Value)>,
+) -> Result<Vec<Result<serde_json::Value>>> {
+    info!("[RPC] rpc_batch called - {} requests", methods.len());
+
+    if methods.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let requests: Vec<serde_json::Value> = methods
+        .into_iter()
+        .map(|(method, params)| build_rpc_request(&method, params))
+        .collect();
+    let ids: Vec<u64> = requests
+        .iter()
+        .filter_map(|r| r.get("id").and_then(|id| id.as_u64()))
+        .collect();
+
+    let client = reqwest::Client::new();
+    let response = client
+        .post(rpc_url)
+        .json(&requests)
+        .send()
+        .await
+        .map_err(|e| AppError::StellarRpc(format!("RPC request failed: {}", e)))?;
+
+    if !response.status().is_success() {
+        return Err(AppError::StellarRpc(format!(
+            "RPC returned error status: {}",
+            response.status()
+        )));
+    }
+
+    let responses: Vec<serde_json::Value> = response
+        .json()
+        .await
+        .map_err(|e| AppError::StellarRpc(format!("Failed to parse batch response: {}", e)))?;
+
+    debug!("[RPC] rpc_batch received {} responses", responses.len());
+
+    Ok(correlate_batch_responses(&ids, responses))
+}
+
+/// Match batch responses to request ids, preserving request order
+fn correlate_batch_responses(
+    ids: &[u64],
+    responses: Vec<serde_json::Value>,
+) -> Vec<Result<serde_json::Value>> {
+    let mut by_id: HashMap<u64, serde_json::Value> = responses
+        .into_iter()
+        .filter_map(|r| r.get("id").and_then(|id| id.as_u64()).map(|id| (id, r)))
+        .collect();
+
+    ids.iter()
+        .map(|id| {
+            let response = by_id
+                .remove(id)
+                .ok_or_else(|| AppError::StellarRpc(format!("No response for request id {}", id)))?;
+
+            if let Some(error) = response.get("error") {
+                return Err(RpcError::from_value(error).into());
+            }
+
+            response
+                .get("result")
+                .cloned()
+                .ok_or_else(|| AppError::StellarRpc("No result in RPC response".to_string()))
+        })
+        .collect()
+}
+
 pub async fn generate_leaderboard_xdr(
     config: &XdrConfig,
     source_account: &str,
@@ -35,10 +233,10 @@ pub async fn generate_leaderboard_xdr(
     info!("🌐 Using RPC: {}", config.rpc_url);
     info!("📡 Network: {}", config.network_passphrase);
 
-    let rpc = Server::new(&config.rpc_url, Options::default())
+    let rpc = Server::new(&config.rpc_url, rpc_options(&config.rpc_url))
         .map_err(|e| AppError::StellarRpc(format!("Failed to connect to Soroban RPC: {:?}", e)))?;
 
-    info!("Fetching account info for: {}", source_account);
+    info!("Fetching account info for: {}", truncate_address(source_account));
     let account_response = rpc.get_account(source_account).await
         .map_err(|e| AppError::Account(format!("Failed to get account info: {:?}", e)))?;
 
@@ -51,7 +249,7 @@ pub async fn generate_leaderboard_xdr(
     let mut tx_builder = TransactionBuilder::new(
         account_rc,
         &config.network_passphrase,
-        None
+        Some(time_bounds(config.tx_timeout_secs))
     );
 
     debug!("Setting fee: 1,000,000 stroops");
@@ -195,6 +393,71 @@ pub async fn submit_signed_transaction(
     Ok((tx_hash, contract_result))
 }
 
+/// Ledger range the RPC node currently retains, from `getHealth`
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcHealth {
+    pub status: String,
+    pub latest_ledger: u32,
+    pub oldest_ledger: u32,
+}
+
+/// Query the RPC node's health and retained ledger range
+///
+/// Event queries starting before `oldest_ledger` are rejected by the node,
+/// so callers use this to clamp their start ledger.
+pub async fn get_health(rpc_url: &str) -> Result<RpcHealth> {
+    debug!("[RPC] get_health called");
+    rpc_call(rpc_url, "getHealth", serde_json::json!({})).await
+}
+
+/// Latest closed ledger, from `getLatestLedger`
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatestLedger {
+    pub id: String,
+    pub protocol_version: u32,
+    pub sequence: u32,
+}
+
+/// Query the latest closed ledger sequence
+///
+/// A single cheap call, used to key caches that must not outlive a ledger.
+pub async fn get_latest_ledger(rpc_url: &str) -> Result<u32> {
+    debug!("[RPC] get_latest_ledger called");
+    let ledger: LatestLedger = rpc_call(rpc_url, "getLatestLedger", serde_json::json!({})).await?;
+    Ok(ledger.sequence)
+}
+
+/// Send a single JSON-RPC call and deserialize its `result`
+async fn rpc_call<T: serde::de::DeserializeOwned>(
+    rpc_url: &str,
+    method: &str,
+    params: serde_json::Value,
+) -> Result<T> {
+    let client = reqwest::Client::new();
+    let json_response: serde_json::Value = client
+        .post(rpc_url)
+        .json(&build_rpc_request(method, params))
+        .send()
+        .await
+        .map_err(|e| AppError::StellarRpc(format!("RPC request failed: {}", e)))?
+        .json()
+        .await
+        .map_err(|e| AppError::StellarRpc(format!("Failed to parse JSON response: {}", e)))?;
+
+    if let Some(error) = json_response.get("error") {
+        return Err(RpcError::from_value(error).into());
+    }
+
+    let result = json_response
+        .get("result")
+        .ok_or_else(|| AppError::StellarRpc("No result in RPC response".to_string()))?;
+
+    serde_json::from_value(result.clone())
+        .map_err(|e| AppError::StellarRpc(format!("Failed to parse {} response: {}", method, e)))
+}
+
 /// Query contract events from the Stellar RPC
 pub async fn get_events(
     config: &XdrConfig,
@@ -206,7 +469,7 @@ pub async fn get_events(
 
     config.validate()?;
 
-    let _rpc = Server::new(&config.rpc_url, Options::default())
+    let _rpc = Server::new(&config.rpc_url, rpc_options(&config.rpc_url))
         .map_err(|e| AppError::StellarRpc(format!("Failed to connect to RPC: {:?}", e)))?;
 
     // Convert pagination to start_ledger, end_ledger, cursor
@@ -241,12 +504,7 @@ pub async fn get_events(
     let client = reqwest::Client::new();
     let response = client
         .post(&config.rpc_url)
-        .json(&serde_json::json!({
-            "jsonrpc": "2.0",
-            "id": 1,
-            "method": "getEvents",
-            "params": params
-        }))
+        .json(&build_rpc_request("getEvents", params))
         .send()
         .await
         .map_err(|e| AppError::StellarRpc(format!("RPC request failed: {}", e)))?;
@@ -262,10 +520,7 @@ pub async fn get_events(
 
     // Check for error
     if let Some(error) = json_response.get("error") {
-        let error_msg = error.get("message")
-            .and_then(|m| m.as_str())
-            .unwrap_or("Unknown RPC error");
-        return Err(AppError::StellarRpc(format!("RPC error: {}", error_msg)));
+        return Err(RpcError::from_value(error).into());
     }
 
     // Extract result
@@ -341,12 +596,7 @@ pub async fn simulate_transaction(
     let client = reqwest::Client::new();
     let response = client
         .post(&config.rpc_url)
-        .json(&serde_json::json!({
-            "jsonrpc": "2.0",
-            "id": 1,
-            "method": "simulateTransaction",
-            "params": params
-        }))
+        .json(&build_rpc_request("simulateTransaction", params))
         .send()
         .await
         .map_err(|e| AppError::StellarRpc(format!("RPC request failed: {}", e)))?;
@@ -367,7 +617,7 @@ pub async fn simulate_transaction(
 
     // Check for JSON-RPC error
     if let Some(error) = json_response.get("error") {
-        return Err(AppError::StellarRpc(format!("RPC error: {}", error)));
+        return Err(RpcError::from_value(error).into());
     }
 
     // Extract result
@@ -395,6 +645,83 @@ pub async fn simulate_transaction(
     Ok(simulation_response)
 }
 
+/// Response from sendTransaction RPC call
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendTransactionResponse {
+    /// PENDING, DUPLICATE, TRY_AGAIN_LATER or ERROR
+    pub status: String,
+    /// Transaction hash (hex)
+    pub hash: String,
+    /// Latest ledger known to the RPC server
+    pub latest_ledger: u32,
+    /// (optional) TransactionResult XDR when status is ERROR
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_result_xdr: Option<String>,
+}
+
+impl SendTransactionResponse {
+    /// Whether the network accepted the transaction for processing
+    pub fn is_accepted(&self) -> bool {
+        self.status == "PENDING" || self.status == "DUPLICATE"
+    }
+}
+
+/// Submit a signed transaction envelope to the network
+///
+/// # Arguments
+/// * `config` - Contract configuration containing RPC URL
+/// * `signed_xdr` - Base64-encoded signed transaction envelope
+///
+/// # Returns
+/// The RPC server's acceptance status and the transaction hash
+pub async fn send_transaction(
+    config: &XdrConfig,
+    signed_xdr: &str,
+) -> Result<SendTransactionResponse> {
+    info!("[RPC] send_transaction called - XDR length: {}", signed_xdr.len());
+
+    let client = reqwest::Client::new();
+    let response = client
+        .post(&config.rpc_url)
+        .json(&build_rpc_request("sendTransaction", serde_json::json!({
+            "transaction": signed_xdr
+        })))
+        .send()
+        .await
+        .map_err(|e| AppError::StellarRpc(format!("RPC request failed: {}", e)))?;
+
+    if !response.status().is_success() {
+        return Err(AppError::StellarRpc(format!(
+            "RPC returned error status: {}",
+            response.status()
+        )));
+    }
+
+    let json_response: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| AppError::StellarRpc(format!("Failed to parse JSON response: {}", e)))?;
+
+    if let Some(error) = json_response.get("error") {
+        return Err(RpcError::from_value(error).into());
+    }
+
+    let result = json_response
+        .get("result")
+        .ok_or_else(|| AppError::StellarRpc("No result in RPC response".to_string()))?;
+
+    let send_response: SendTransactionResponse = serde_json::from_value(result.clone())
+        .map_err(|e| AppError::StellarRpc(format!("Failed to parse sendTransaction response: {}", e)))?;
+
+    info!(
+        "[RPC] ✅ send_transaction returned {} - hash: {}",
+        send_response.status, send_response.hash
+    );
+
+    Ok(send_response)
+}
+
 /// Query ledger entries from the Stellar RPC
 ///
 /// This function retrieves ledger entries (accounts, contract data, trustlines, etc.)
@@ -425,12 +752,7 @@ pub async fn get_ledger_entries(
     let client = reqwest::Client::new();
     let response = client
         .post(&config.rpc_url)
-        .json(&serde_json::json!({
-            "jsonrpc": "2.0",
-            "id": 1,
-            "method": "getLedgerEntries",
-            "params": params
-        }))
+        .json(&build_rpc_request("getLedgerEntries", params))
         .send()
         .await
         .map_err(|e| AppError::StellarRpc(format!("RPC request failed: {}", e)))?;
@@ -451,7 +773,7 @@ pub async fn get_ledger_entries(
 
     // Check for JSON-RPC error
     if let Some(error) = json_response.get("error") {
-        return Err(AppError::StellarRpc(format!("RPC error: {}", error)));
+        return Err(RpcError::from_value(error).into());
     }
 
     // Extract result
@@ -472,6 +794,41 @@ pub async fn get_ledger_entries(
     Ok(entries_response)
 }
 
+/// Query several groups of ledger entries in a single batched RPC request
+///
+/// # Arguments
+/// * `config` - Contract configuration containing RPC URL
+/// * `key_groups` - One vector of base64 XDR LedgerKeys per getLedgerEntries call
+///
+/// # Returns
+/// One `GetLedgerEntriesResponse` result per key group, in request order
+pub async fn get_ledger_entries_batch(
+    config: &XdrConfig,
+    key_groups: Vec<Vec<String>>,
+) -> Result<Vec<Result<crate::services::soroban::state::GetLedgerEntriesResponse>>> {
+    info!("[RPC] get_ledger_entries_batch called - {} groups", key_groups.len());
+
+    config.validate()?;
+
+    let methods = key_groups
+        .into_iter()
+        .map(|keys| ("getLedgerEntries".to_string(), serde_json::json!({ "keys": keys })))
+        .collect();
+
+    let results = rpc_batch(&config.rpc_url, methods).await?;
+
+    Ok(results
+        .into_iter()
+        .map(|result| {
+            result.and_then(|value| {
+                serde_json::from_value(value).map_err(|e| {
+                    AppError::StellarRpc(format!("Failed to parse ledger entries response: {}", e))
+                })
+            })
+        })
+        .collect())
+}
+
 /// Get contract storage data
 ///
 /// This function reads contract storage by constructing a ContractData LedgerKey
@@ -484,13 +841,13 @@ pub async fn get_ledger_entries(
 /// * `durability` - Storage durability (Temporary or Persistent)
 ///
 /// # Returns
-/// A `LedgerEntryResult` containing the contract data
+/// A `ContractDataRead` containing the contract data and the ledger it was read at
 pub async fn get_contract_data(
     config: &XdrConfig,
     contract_id: &str,
     key: &str,
     durability: crate::services::soroban::state::Durability,
-) -> Result<crate::services::soroban::state::LedgerEntryResult> {
+) -> Result<crate::services::soroban::state::ContractDataRead> {
     info!(
         "[RPC] get_contract_data called - contract: {}, durability: {:?}",
         contract_id, durability
@@ -498,9 +855,54 @@ pub async fn get_contract_data(
 
     config.validate()?;
 
+    let key_xdr = contract_data_ledger_key(contract_id, key, durability)?;
+
+    // Query ledger entries
+    let response = get_ledger_entries(config, vec![key_xdr]).await?;
+
+    // Extract first entry
+    if let Some(entry) = response.first_entry() {
+        info!("[RPC] ✅ get_contract_data successful - entry found");
+        Ok(crate::services::soroban::state::ContractDataRead {
+            entry: entry.clone(),
+            latest_ledger: response.latest_ledger,
+        })
+    } else {
+        Err(AppError::NotFound("Contract data not found".to_string()))
+    }
+}
+
+/// Decode the value held by a contract storage entry to JSON
+///
+/// # Returns
+/// `None` when the entry is not contract data
+pub fn decode_contract_data_value(
+    entry: &crate::services::soroban::state::LedgerEntryResult,
+) -> Result<Option<serde_json::Value>> {
+    use soroban_client::xdr::LedgerEntryData;
+
+    let data = LedgerEntryData::from_xdr_base64(&entry.xdr, scval_read_limits())
+        .map_err(|e| AppError::XdrDecoding(format!("Failed to parse ledger entry data: {:?}", e)))?;
+
+    match data {
+        LedgerEntryData::ContractData(data) => {
+            check_result_depth(&data.val)?;
+            Ok(Some(scval_to_json(&data.val)))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Build the base64 XDR ContractData LedgerKey for a storage key
+fn contract_data_ledger_key(
+    contract_id: &str,
+    key: &str,
+    durability: crate::services::soroban::state::Durability,
+) -> Result<String> {
     // Parse the storage key from base64 XDR
-    let sc_val = soroban_client::xdr::ScVal::from_xdr_base64(key, soroban_client::xdr::Limits::none())
+    let sc_val = soroban_client::xdr::ScVal::from_xdr_base64(key, scval_read_limits())
         .map_err(|e| AppError::Internal(format!("Failed to parse storage key: {}", e)))?;
+    check_scval_depth(&sc_val)?;
 
     // Convert contract ID to ScAddress
     let sc_address = Address::new(contract_id)
@@ -518,24 +920,164 @@ pub async fn get_contract_data(
     );
 
     // Convert to base64 XDR
-    let key_xdr = contract_key
+    contract_key
         .to_xdr_base64(soroban_client::xdr::Limits::none())
-        .map_err(|e| AppError::Internal(format!("Failed to encode ledger key: {}", e)))?;
+        .map_err(|e| AppError::Internal(format!("Failed to encode ledger key: {}", e)))
+}
 
-    // Query ledger entries
-    let response = get_ledger_entries(config, vec![key_xdr]).await?;
+/// Most keys a single `getLedgerEntries` call accepts
+pub const MAX_LEDGER_KEYS_PER_CALL: usize = 200;
 
-    // Extract first entry
-    if let Some(entry) = response.first_entry() {
-        info!("[RPC] ✅ get_contract_data successful - entry found");
-        Ok(entry.clone())
-    } else {
-        Err(AppError::NotFound("Contract data not found".to_string()))
+/// Read the TTL of several contract storage entries
+///
+/// Keys are split into `getLedgerEntries` calls of at most
+/// `MAX_LEDGER_KEYS_PER_CALL`, all sent in one batched request.
+///
+/// # Returns
+/// A `TtlReport` with entries sorted by remaining TTL, most at-risk first
+pub async fn get_ttl_report(
+    config: &XdrConfig,
+    contract_id: &str,
+    keys: Vec<(String, crate::services::soroban::state::Durability)>,
+) -> Result<crate::services::soroban::state::TtlReport> {
+    info!("[RPC] get_ttl_report called - contract: {}, {} keys", contract_id, keys.len());
+
+    config.validate()?;
+
+    let requested = keys
+        .into_iter()
+        .map(|(key, durability)| {
+            let ledger_key = contract_data_ledger_key(contract_id, &key, durability)?;
+            Ok((key, durability, ledger_key))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let key_groups = requested
+        .chunks(MAX_LEDGER_KEYS_PER_CALL)
+        .map(|chunk| chunk.iter().map(|(_, _, ledger_key)| ledger_key.clone()).collect())
+        .collect();
+    let response = get_ledger_entries_batch(config, key_groups)
+        .await?
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .reduce(crate::services::soroban::state::GetLedgerEntriesResponse::merge)
+        .ok_or_else(|| AppError::InvalidInput("At least one storage key is required".to_string()))?;
+
+    let report = crate::services::soroban::state::TtlReport::build(requested, &response);
+    info!(
+        "[RPC] ✅ get_ttl_report successful - {} of {} entries found",
+        report.entries.iter().filter(|e| e.found).count(),
+        report.entries.len()
+    );
+
+    Ok(report)
+}
+
+/// Fetch and decode a contract's interface spec
+///
+/// Reads the contract instance to find its Wasm hash, then loads the
+/// contract code and decodes the `contractspecv0` section.
+///
+/// # Returns
+/// `None` when the contract has no Wasm spec (e.g. Stellar Asset Contracts)
+pub async fn get_contract_spec(
+    config: &XdrConfig,
+    contract_id: &str,
+) -> Result<Option<crate::services::soroban::spec::ContractSpec>> {
+    use soroban_client::xdr::{LedgerEntryData, LedgerKey, LedgerKeyContractCode};
+
+    info!("[RPC] get_contract_spec called - contract: {}", contract_id);
+
+    let wasm_hash = match read_instance_wasm_hash(config, contract_id).await? {
+        Some(hash) => hash,
+        None => {
+            debug!("[RPC] Contract {} is a Stellar Asset Contract, no spec", contract_id);
+            return Ok(None);
+        }
+    };
+
+    let code_key = LedgerKey::ContractCode(LedgerKeyContractCode { hash: wasm_hash })
+        .to_xdr_base64(Limits::none())
+        .map_err(|e| AppError::XdrEncoding(format!("Failed to encode code key: {}", e)))?;
+
+    let response = get_ledger_entries(config, vec![code_key]).await?;
+    let code_entry = response.first_entry()
+        .ok_or_else(|| AppError::NotFound("Contract code not found".to_string()))?;
+
+    let spec = match code_entry.to_data().map_err(AppError::XdrDecoding)? {
+        LedgerEntryData::ContractCode(code) => {
+            crate::services::soroban::spec::ContractSpec::from_wasm(code.code.as_slice())?
+        }
+        _ => return Err(AppError::XdrDecoding("Unexpected ledger entry for contract code".to_string())),
+    };
+
+    info!("[RPC] ✅ get_contract_spec successful - {} functions", spec.functions.len());
+
+    Ok(Some(spec))
+}
+
+/// Get the hex-encoded Wasm hash a contract instance currently points to
+///
+/// # Returns
+/// `None` for Stellar Asset Contracts, which have no Wasm
+pub async fn get_contract_wasm_hash(config: &XdrConfig, contract_id: &str) -> Result<Option<String>> {
+    debug!("[RPC] get_contract_wasm_hash called - contract: {}", contract_id);
+
+    Ok(read_instance_wasm_hash(config, contract_id)
+        .await?
+        .map(|hash| hex::encode(hash.0)))
+}
+
+/// Read the contract instance entry and return its Wasm hash
+async fn read_instance_wasm_hash(
+    config: &XdrConfig,
+    contract_id: &str,
+) -> Result<Option<soroban_client::xdr::Hash>> {
+    use soroban_client::xdr::{ContractExecutable, LedgerEntryData};
+
+    let instance_key = ScVal::LedgerKeyContractInstance
+        .to_xdr_base64(Limits::none())
+        .map_err(|e| AppError::XdrEncoding(format!("Failed to encode instance key: {}", e)))?;
+
+    let instance = get_contract_data(
+        config,
+        contract_id,
+        &instance_key,
+        crate::services::soroban::state::Durability::Persistent,
+    ).await?;
+
+    match instance.entry.to_data().map_err(AppError::XdrDecoding)? {
+        LedgerEntryData::ContractData(data) => match data.val {
+            ScVal::ContractInstance(instance) => match instance.executable {
+                ContractExecutable::Wasm(hash) => Ok(Some(hash)),
+                ContractExecutable::StellarAsset => Ok(None),
+            },
+            _ => Err(AppError::XdrDecoding("Unexpected contract instance value".to_string())),
+        },
+        _ => Err(AppError::XdrDecoding("Unexpected ledger entry for contract instance".to_string())),
     }
 }
 
+/// Convert a call's parameters to ScVal, rejecting malformed or over-deep values
+///
+/// Done before any RPC traffic so a bad request is reported as invalid input
+/// rather than counted against the contract's circuit breaker.
+pub fn function_parameters_to_scvals(parameters: &[FunctionParameter]) -> Result<Vec<ScVal>> {
+    parameters.iter().map(function_parameter_to_scval).collect()
+}
+
 /// Convert FunctionParameter to ScVal for Soroban contract calls
 fn function_parameter_to_scval(param: &FunctionParameter) -> Result<ScVal> {
+    parameter_to_scval_at_depth(param, 1)
+}
+
+/// Recursive conversion; `depth` is 1 for a top-level parameter
+fn parameter_to_scval_at_depth(param: &FunctionParameter, depth: usize) -> Result<ScVal> {
+    if depth > max_parameter_depth() {
+        return Err(nesting_too_deep());
+    }
+
     match param {
         FunctionParameter::Symbol(s) => {
             let symbol_str: soroban_client::xdr::StringM<32> = s.as_bytes().to_vec().try_into()
@@ -574,7 +1116,7 @@ fn function_parameter_to_scval(param: &FunctionParameter) -> Result<ScVal> {
         }
         FunctionParameter::Vec(params) => {
             let scvals: Result<Vec<ScVal>> = params.iter()
-                .map(function_parameter_to_scval)
+                .map(|param| parameter_to_scval_at_depth(param, depth + 1))
                 .collect();
             Ok(ScVal::Vec(Some(
                 scvals?.try_into()
@@ -582,6 +1124,17 @@ fn function_parameter_to_scval(param: &FunctionParameter) -> Result<ScVal> {
             )))
         }
         FunctionParameter::Enum(variant_name, value) => {
+            // Option<T> uses the same encoding, so a mismatched payload would silently change the type
+            match (variant_name.as_str(), value) {
+                ("None", Some(_)) => {
+                    return Err(AppError::InvalidInput("Option None must not carry a value".to_string()));
+                }
+                ("Some", None) => {
+                    return Err(AppError::InvalidInput("Option Some must carry exactly one value".to_string()));
+                }
+                _ => {}
+            }
+
             // Convert enum variant name to symbol
             let variant_symbol: soroban_client::xdr::StringM<32> = variant_name.as_bytes().to_vec().try_into()
                 .map_err(|_| AppError::XdrEncoding(
@@ -592,7 +1145,7 @@ fn function_parameter_to_scval(param: &FunctionParameter) -> Result<ScVal> {
             let mut enum_vec = vec![ScVal::Symbol(ScSymbol::from(variant_symbol))];
 
             if let Some(val) = value {
-                enum_vec.push(function_parameter_to_scval(val)?);
+                enum_vec.push(parameter_to_scval_at_depth(val, depth + 1)?);
             }
 
             Ok(ScVal::Vec(Some(
@@ -604,7 +1157,7 @@ fn function_parameter_to_scval(param: &FunctionParameter) -> Result<ScVal> {
 }
 
 /// Parse ScVal result to JSON
-fn scval_to_json(scval: &ScVal) -> serde_json::Value {
+pub(crate) fn scval_to_json(scval: &ScVal) -> serde_json::Value {
     match scval {
         ScVal::Bool(b) => serde_json::Value::Bool(*b),
         ScVal::Void => serde_json::Value::Null,
@@ -639,6 +1192,17 @@ fn scval_to_json(scval: &ScVal) -> serde_json::Value {
             )
         }
         ScVal::Bytes(b) => serde_json::Value::String(hex::encode(b.0.as_slice())),
+        ScVal::Vec(Some(vec)) if is_pair_list(vec.0.as_slice()) => {
+            // Vec<(K, V)> lists (e.g. leaderboard entries) become [key, value] pairs
+            serde_json::Value::Array(
+                vec.0.iter().map(|item| match item {
+                    ScVal::Vec(Some(pair)) => serde_json::Value::Array(
+                        pair.0.iter().map(tuple_element_to_json).collect()
+                    ),
+                    _ => scval_to_json(item),
+                }).collect()
+            )
+        }
         ScVal::Vec(Some(vec)) => {
             serde_json::Value::Array(
                 vec.0.iter().map(scval_to_json).collect()
@@ -648,20 +1212,8 @@ fn scval_to_json(scval: &ScVal) -> serde_json::Value {
         ScVal::Map(Some(map)) => {
             let mut obj = serde_json::Map::new();
             for entry in map.0.iter() {
-                // Extract key as string (handle Symbol keys from oracle responses)
-                let key = match &entry.key {
-                    ScVal::Symbol(sym) => {
-                        String::from_utf8(sym.0.as_slice().to_vec())
-                            .unwrap_or_else(|_| format!("{:?}", entry.key))
-                    }
-                    ScVal::String(s) => {
-                        String::from_utf8(s.0.as_slice().to_vec())
-                            .unwrap_or_else(|_| format!("{:?}", entry.key))
-                    }
-                    _ => format!("{:?}", entry.key)
-                };
                 let value = scval_to_json(&entry.val);
-                obj.insert(key, value);
+                obj.insert(map_key(&entry.key), value);
             }
             serde_json::Value::Object(obj)
         }
@@ -673,6 +1225,228 @@ fn scval_to_json(scval: &ScVal) -> serde_json::Value {
     }
 }
 
+/// Extract a map key as string (handle Symbol keys from oracle responses)
+fn map_key(key: &ScVal) -> String {
+    match key {
+        ScVal::Symbol(sym) => {
+            String::from_utf8(sym.0.as_slice().to_vec())
+                .unwrap_or_else(|_| format!("{:?}", key))
+        }
+        ScVal::String(s) => {
+            String::from_utf8(s.0.as_slice().to_vec())
+                .unwrap_or_else(|_| format!("{:?}", key))
+        }
+        _ => format!("{:?}", key)
+    }
+}
+
+/// Whether a vector is a homogeneous list of 2-element tuples, e.g. `Vec<(Address, i128)>`
+fn is_pair_list(items: &[ScVal]) -> bool {
+    let Some(ScVal::Vec(Some(first))) = items.first() else {
+        return false;
+    };
+    if first.0.len() != 2 {
+        return false;
+    }
+
+    let same_type = |a: &ScVal, b: &ScVal| std::mem::discriminant(a) == std::mem::discriminant(b);
+    items.iter().all(|item| match item {
+        ScVal::Vec(Some(pair)) => {
+            pair.0.len() == 2
+                && same_type(&pair.0[0], &first.0[0])
+                && same_type(&pair.0[1], &first.0[1])
+        }
+        _ => false,
+    })
+}
+
+/// Tuple element to JSON; addresses are rendered as strkeys so list keys are usable
+fn tuple_element_to_json(scval: &ScVal) -> serde_json::Value {
+    match scval {
+        ScVal::Address(addr) => match Address::from_sc_address(addr) {
+            Ok(address) => serde_json::Value::String(address.to_string()),
+            Err(_) => scval_to_json(scval),
+        },
+        _ => scval_to_json(scval),
+    }
+}
+
+/// Parse ScVal result to JSON, guided by the caller's expected return type
+///
+/// Values that don't match the hint are decoded with the usual heuristics.
+pub fn scval_to_json_hinted(scval: &ScVal, hint: &ScTypeHint) -> serde_json::Value {
+    match (hint, scval) {
+        (ScTypeHint::AccountId, ScVal::Bytes(b)) if b.0.len() == 32 => {
+            let key: [u8; 32] = b.0.as_slice().try_into().unwrap_or([0; 32]);
+            serde_json::Value::String(stellar_strkey::ed25519::PublicKey(key).to_string())
+        }
+        (ScTypeHint::ContractId, ScVal::Bytes(b)) if b.0.len() == 32 => {
+            let hash: [u8; 32] = b.0.as_slice().try_into().unwrap_or([0; 32]);
+            serde_json::Value::String(stellar_strkey::Contract(hash).to_string())
+        }
+        (ScTypeHint::AccountId | ScTypeHint::ContractId, ScVal::Address(addr)) => {
+            match Address::from_sc_address(addr) {
+                Ok(address) => serde_json::Value::String(address.to_string()),
+                Err(_) => scval_to_json(scval),
+            }
+        }
+        (ScTypeHint::Bytes, ScVal::Bytes(b)) => serde_json::Value::String(hex::encode(b.0.as_slice())),
+        (ScTypeHint::Bytes, ScVal::String(s)) => serde_json::Value::String(hex::encode(s.0.as_slice())),
+        (ScTypeHint::String, ScVal::Bytes(b)) => match String::from_utf8(b.0.as_slice().to_vec()) {
+            Ok(text) => serde_json::Value::String(text),
+            Err(_) => scval_to_json(scval),
+        },
+        (ScTypeHint::Decimal(decimals), _) => match scval_integer(scval) {
+            Some(integer) => serde_json::Value::String(format_amount(integer, *decimals)),
+            None => scval_to_json(scval),
+        },
+        (ScTypeHint::Vec(inner), ScVal::Vec(Some(items))) => {
+            serde_json::Value::Array(
+                items.0.iter().map(|item| scval_to_json_hinted(item, inner)).collect()
+            )
+        }
+        (ScTypeHint::Map(inner), ScVal::Map(Some(map))) => {
+            let mut obj = serde_json::Map::new();
+            for entry in map.0.iter() {
+                obj.insert(map_key(&entry.key), scval_to_json_hinted(&entry.val, inner));
+            }
+            serde_json::Value::Object(obj)
+        }
+        (ScTypeHint::Tuple(fields), ScVal::Vec(Some(items))) if fields.len() == items.0.len() => {
+            let mut obj = serde_json::Map::new();
+            for (TupleField { name, hint }, item) in fields.iter().zip(items.0.iter()) {
+                let value = match hint {
+                    Some(hint) => scval_to_json_hinted(item, hint),
+                    None => tuple_element_to_json(item),
+                };
+                obj.insert(name.clone(), value);
+            }
+            serde_json::Value::Object(obj)
+        }
+        (ScTypeHint::Option(_), ScVal::Void) => serde_json::Value::Null,
+        (ScTypeHint::Option(inner), _) => scval_to_json_hinted(scval, inner),
+        _ => scval_to_json(scval),
+    }
+}
+
+/// Decode a base64 result XDR, using the return type hint when provided
+pub fn decode_result_xdr(result_xdr: &str, hint: Option<&ScTypeHint>) -> Result<serde_json::Value> {
+    let scval = ScVal::from_xdr_base64(result_xdr, scval_read_limits())
+        .map_err(|e| AppError::XdrEncoding(format!("Failed to decode result XDR: {:?}", e)))?;
+    check_result_depth(&scval)?;
+
+    Ok(match hint {
+        Some(hint) => scval_to_json_hinted(&scval, hint),
+        None => scval_to_json(&scval),
+    })
+}
+
+/// Integer ScVal widened to i128 (None for non-integers)
+///
+/// Token amounts are i128 (SEP-41); a u128 beyond `i128::MAX` is also None and
+/// falls back to the unscaled value.
+pub(crate) fn scval_integer(scval: &ScVal) -> Option<i128> {
+    match scval {
+        ScVal::U32(n) => Some((*n).into()),
+        ScVal::I32(n) => Some((*n).into()),
+        ScVal::U64(n) => Some((*n).into()),
+        ScVal::I64(n) => Some((*n).into()),
+        ScVal::U128(parts) => i128::try_from(((parts.hi as u128) << 64) | (parts.lo as u128)).ok(),
+        ScVal::I128(parts) => Some(((parts.hi as i128) << 64) | (parts.lo as i128)),
+        _ => None,
+    }
+}
+
+/// Source account for calls that don't name one
+const DEFAULT_CALL_SOURCE: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+
+/// Build the unprepared invoke transaction for a contract call; no network access
+fn build_call_transaction(
+    config: &XdrConfig,
+    account: Account,
+    function_name: &str,
+    scval_params: Vec<ScVal>,
+) -> Result<Transaction> {
+    let tx = {
+        let account_rc = Rc::new(RefCell::new(account));
+        let mut tx_builder = TransactionBuilder::new(
+            account_rc,
+            &config.network_passphrase,
+            None
+        );
+
+        tx_builder.fee(1000000u32);
+
+        // Create contract instance
+        let contract = Contracts::new(&config.contract_id)
+            .map_err(|e| AppError::Transaction(format!("Failed to create contract: {:?}", e)))?;
+
+        info!("[CONTRACT_CALL] Creating invoke operation for function: {}", function_name);
+        debug!("[CONTRACT_CALL] Invoking with {} ScVal arguments", scval_params.len());
+
+        // Create contract call
+        let invoke_operation = if scval_params.is_empty() {
+            contract.call(function_name, None)
+        } else {
+            contract.call(function_name, Some(scval_params))
+        };
+
+        tx_builder.add_operation(invoke_operation);
+        tx_builder.build()
+    };
+
+    Ok(tx)
+}
+
+/// Build a contract call's transaction without any RPC traffic ("explain" mode)
+///
+/// The source account isn't fetched, so the envelope carries a placeholder
+/// sequence number and no footprint or resource fee. It shows exactly what the
+/// parameters encode to, but is not meant to be signed.
+pub fn explain_contract_call(
+    contract_id: &str,
+    function_name: &str,
+    parameters: &[FunctionParameter],
+    source_account: Option<&str>,
+    rpc_url: &str,
+    network_passphrase: &str,
+) -> Result<CallContractFunctionResponse> {
+    info!("[CONTRACT_CALL] Explaining {} on contract {}", function_name, contract_id);
+
+    let config = XdrConfig {
+        contract_id: contract_id.to_string(),
+        network_passphrase: network_passphrase.to_string(),
+        rpc_url: rpc_url.to_string(),
+        tx_timeout_secs: DEFAULT_TX_TIMEOUT_SECS,
+    };
+    config.validate()?;
+
+    let source = source_account.unwrap_or(DEFAULT_CALL_SOURCE);
+    let account = Account::new(source, "0")
+        .map_err(|e| AppError::InvalidInput(format!("Invalid source account: {:?}", e)))?;
+
+    let arguments = function_parameters_to_scvals(parameters)?;
+    let envelope = build_call_transaction(&config, account, function_name, arguments)?
+        .to_envelope()
+        .map_err(|e| AppError::XdrEncoding(format!("Failed to create envelope: {:?}", e)))?;
+    let transaction_xdr = envelope.to_xdr_base64(Limits::none())
+        .map_err(|e| AppError::XdrEncoding(format!("Failed to encode XDR: {:?}", e)))?;
+    let decoded_operation = match &envelope {
+        TransactionEnvelope::Tx(v1) => decode_operation(&v1.tx),
+        _ => None,
+    };
+
+    Ok(CallContractFunctionResponse {
+        success: true,
+        result: None,
+        result_xdr: None,
+        simulation: None,
+        error: None,
+        transaction_xdr: Some(transaction_xdr),
+        decoded_operation,
+    })
+}
+
 /// Call a generic contract function (read-only via simulation)
 ///
 /// This function allows calling ANY Soroban contract function by:
@@ -683,8 +1457,9 @@ fn scval_to_json(scval: &ScVal) -> serde_json::Value {
 /// # Arguments
 /// * `contract_id` - Contract address (C... format)
 /// * `function_name` - Name of the function to call
-/// * `parameters` - Function parameters (will be converted to ScVal)
+/// * `arguments` - Function arguments, already converted with `function_parameters_to_scvals`
 /// * `source_account` - Optional source account (uses default if None)
+/// * `options` - Simulation options (auth mode, extra CPU budget)
 /// * `rpc_url` - RPC endpoint URL
 /// * `network_passphrase` - Network passphrase
 ///
@@ -697,32 +1472,35 @@ fn scval_to_json(scval: &ScVal) -> serde_json::Value {
 pub async fn call_contract_function(
     contract_id: &str,
     function_name: &str,
-    parameters: Vec<FunctionParameter>,
+    arguments: Vec<ScVal>,
     source_account: Option<&str>,
+    options: Option<crate::services::soroban::simulation::SimulationOptions>,
     rpc_url: &str,
     network_passphrase: &str,
 ) -> Result<CallContractFunctionResponse> {
     info!("[CONTRACT_CALL] Calling {} on contract {}", function_name, contract_id);
-    debug!("[CONTRACT_CALL] Parameters: {} params", parameters.len());
+    debug!("[CONTRACT_CALL] Parameters: {} params", arguments.len());
 
     // Use default testnet account if no source provided
-    let source = source_account.unwrap_or("GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF");
+    let source = source_account.unwrap_or(DEFAULT_CALL_SOURCE);
 
     // Create config for this contract
     let config = XdrConfig {
         contract_id: contract_id.to_string(),
         network_passphrase: network_passphrase.to_string(),
         rpc_url: rpc_url.to_string(),
+        tx_timeout_secs: DEFAULT_TX_TIMEOUT_SECS,
     };
 
     config.validate()?;
 
     // Connect to RPC
-    let rpc = Server::new(&config.rpc_url, Options::default())
+    let rpc = Server::new(&config.rpc_url, rpc_options(&config.rpc_url))
         .map_err(|e| AppError::StellarRpc(format!("Failed to connect to RPC: {:?}", e)))?;
 
     // Get account info
-    info!("[CONTRACT_CALL] Fetching account info for: {}", source);
+    info!("[CONTRACT_CALL] Fetching account info for: {}", truncate_address(source));
+    debug!("[CONTRACT_CALL] Full source account: {}", source);
     let account_response = rpc.get_account(source).await
         .map_err(|e| AppError::Account(format!("Failed to get account info: {:?}", e)))?;
 
@@ -730,44 +1508,17 @@ pub async fn call_contract_function(
         .map_err(|e| AppError::Account(format!("Failed to create account: {:?}", e)))?;
 
     // Build transaction
-    let tx = {
-        let account_rc = Rc::new(RefCell::new(account));
-        let mut tx_builder = TransactionBuilder::new(
-            account_rc,
-            &config.network_passphrase,
-            None
-        );
-
-        tx_builder.fee(1000000u32);
-
-        // Create contract instance
-        let contract = Contracts::new(&config.contract_id)
-            .map_err(|e| AppError::Transaction(format!("Failed to create contract: {:?}", e)))?;
-
-        // Convert parameters to ScVal
-        let scval_params: Result<Vec<ScVal>> = parameters.iter()
-            .map(function_parameter_to_scval)
-            .collect();
-        let scval_params = scval_params?;
-
-        info!("[CONTRACT_CALL] Creating invoke operation for function: {}", function_name);
-        debug!("[CONTRACT_CALL] Converted {} parameters to ScVal", scval_params.len());
-
-        // Create contract call
-        let invoke_operation = if scval_params.is_empty() {
-            contract.call(function_name, None)
-        } else {
-            contract.call(function_name, Some(scval_params))
-        };
-
-        tx_builder.add_operation(invoke_operation);
-        tx_builder.build()
-    };
+    let tx = build_call_transaction(&config, account, function_name, arguments)?;
 
     // Prepare transaction (adds footprint and resource fees)
     info!("[CONTRACT_CALL] Preparing transaction");
-    let prepared_tx = rpc.prepare_transaction(&tx).await
-        .map_err(|e| AppError::Transaction(format!("Failed to prepare transaction: {:?}", e)))?;
+    let prepared_tx = match rpc.prepare_transaction(&tx).await {
+        Ok(prepared_tx) => prepared_tx,
+        // Contract rejections and archived entries are reported by simulating the
+        // unprepared transaction below, with the contract's own reason or restore fee
+        Err(SorobanClientError::SimulationFailed(_)) | Err(SorobanClientError::RestorationRequired(..)) => tx,
+        Err(e) => return Err(AppError::Transaction(format!("Failed to prepare transaction: {:?}", e))),
+    };
 
     // Create envelope and encode to XDR
     let envelope = prepared_tx.to_envelope()
@@ -780,24 +1531,40 @@ pub async fn call_contract_function(
 
     // Simulate the transaction
     info!("[CONTRACT_CALL] Simulating transaction");
-    let simulation = simulate_transaction(&config, &tx_xdr, None).await?;
+    let simulation = simulate_transaction(&config, &tx_xdr, options).await?;
 
     // Check if simulation was successful
     if !simulation.is_success() {
-        let error_msg = simulation.error.clone()
+        let raw_error = simulation.error.clone()
             .unwrap_or_else(|| "Unknown simulation error".to_string());
+
+        // Prefer the contract's own reason from diagnostic events over the raw host error
+        let error_msg = match simulation.events.as_deref().and_then(extract_error) {
+            Some(diagnostic) => diagnostic.reason(),
+            None => raw_error,
+        };
         error!("[CONTRACT_CALL] ❌ Simulation failed: {}", error_msg);
         return Ok(CallContractFunctionResponse {
             success: false,
             result: None,
             result_xdr: None,
-            simulation: None,
+            simulation: Some(SimulationDetailsDto {
+                latest_ledger: simulation.latest_ledger,
+                min_resource_fee: simulation.min_resource_fee,
+                cpu_instructions: simulation.get_cpu_instructions(),
+                events: simulation.events,
+                requires_restore: simulation.needs_restoration(),
+                restore_fee_stroops: simulation.restoration_fee(),
+            }),
             error: Some(error_msg),
+            transaction_xdr: None,
+            decoded_operation: None,
         });
     }
 
     // Extract result from simulation
     if let Some((scval, _auth)) = simulation.to_result() {
+        check_result_depth(&scval)?;
         let result_json = scval_to_json(&scval);
         let result_xdr = scval.to_xdr_base64(Limits::none())
             .map_err(|e| AppError::XdrEncoding(format!("Failed to encode result: {:?}", e)))?;
@@ -812,10 +1579,14 @@ pub async fn call_contract_function(
             simulation: Some(SimulationDetailsDto {
                 latest_ledger: simulation.latest_ledger,
                 min_resource_fee: simulation.min_resource_fee,
-                cpu_instructions: None, // Not exposed in current simulation response
+                cpu_instructions: simulation.get_cpu_instructions(),
                 events: simulation.events,
+                requires_restore: simulation.needs_restoration(),
+                restore_fee_stroops: simulation.restoration_fee(),
             }),
             error: None,
+            transaction_xdr: None,
+            decoded_operation: None,
         })
     } else {
         warn!("[CONTRACT_CALL] ⚠️  Simulation succeeded but no result value");
@@ -826,10 +1597,228 @@ pub async fn call_contract_function(
             simulation: Some(SimulationDetailsDto {
                 latest_ledger: simulation.latest_ledger,
                 min_resource_fee: simulation.min_resource_fee,
-                cpu_instructions: None,
+                cpu_instructions: simulation.get_cpu_instructions(),
                 events: simulation.events,
+                requires_restore: simulation.needs_restoration(),
+                restore_fee_stroops: simulation.restoration_fee(),
             }),
             error: None,
+            transaction_xdr: None,
+            decoded_operation: None,
         })
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rpc_request_ids_increment() {
+        let first = build_rpc_request("getHealth", serde_json::json!({}));
+        let second = build_rpc_request("getHealth", serde_json::json!({}));
+
+        let first_id = first["id"].as_u64().unwrap();
+        let second_id = second["id"].as_u64().unwrap();
+        assert!(second_id > first_id);
+        assert_eq!(first["jsonrpc"], "2.0");
+        assert_eq!(first["method"], "getHealth");
+    }
+
+    #[test]
+    fn test_batch_responses_matched_by_id() {
+        let ids = vec![10, 11, 12];
+        // Server answers out of order, with one error
+        let responses = vec![
+            serde_json::json!({"jsonrpc": "2.0", "id": 12, "result": {"method": "third"}}),
+            serde_json::json!({"jsonrpc": "2.0", "id": 10, "result": {"method": "first"}}),
+            serde_json::json!({"jsonrpc": "2.0", "id": 11, "error": {"code": -32601, "message": "method not found"}}),
+        ];
+
+        let results = correlate_batch_responses(&ids, responses);
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap()["method"], "first");
+        assert!(results[1].as_ref().unwrap_err().to_string().contains("method not found"));
+        assert_eq!(results[2].as_ref().unwrap()["method"], "third");
+    }
+
+    #[test]
+    fn test_batch_missing_response() {
+        let ids = vec![1, 2];
+        let responses = vec![serde_json::json!({"jsonrpc": "2.0", "id": 1, "result": 42})];
+
+        let results = correlate_batch_responses(&ids, responses);
+
+        assert_eq!(results[0].as_ref().unwrap(), &serde_json::json!(42));
+        assert!(results[1].is_err());
+    }
+
+    #[test]
+    fn test_hinted_bytes_as_contract_strkey() {
+        let hash = [7u8; 32];
+        let scval = ScVal::Bytes(hash.to_vec().try_into().unwrap());
+
+        // Without a hint, bytes are hex
+        assert_eq!(scval_to_json(&scval), serde_json::json!(hex::encode(hash)));
+
+        let decoded = scval_to_json_hinted(&scval, &ScTypeHint::ContractId);
+        assert_eq!(decoded, serde_json::json!(stellar_strkey::Contract(hash).to_string()));
+        assert!(decoded.as_str().unwrap().starts_with('C'));
+    }
+
+    #[test]
+    fn test_hinted_decimal() {
+        use soroban_client::xdr::{Int128Parts, UInt128Parts};
+
+        let price = ScVal::I128(Int128Parts { hi: 0, lo: 123_456_789 });
+        assert_eq!(scval_to_json_hinted(&price, &ScTypeHint::Decimal(7)), serde_json::json!("12.3456789"));
+
+        let small = ScVal::U128(UInt128Parts { hi: 0, lo: 5 });
+        assert_eq!(scval_to_json_hinted(&small, &ScTypeHint::Decimal(3)), serde_json::json!("0.005"));
+
+        let negative = ScVal::I64(-1500);
+        assert_eq!(scval_to_json_hinted(&negative, &ScTypeHint::Decimal(2)), serde_json::json!("-15.00"));
+    }
+
+    #[test]
+    fn test_hinted_nested_and_fallback() {
+        let hint = ScTypeHint::Option(Box::new(ScTypeHint::Vec(Box::new(ScTypeHint::Decimal(1)))));
+
+        assert_eq!(scval_to_json_hinted(&ScVal::Void, &hint), serde_json::Value::Null);
+
+        let values = ScVal::Vec(Some(vec![ScVal::U32(15), ScVal::U32(250)].try_into().unwrap()));
+        assert_eq!(scval_to_json_hinted(&values, &hint), serde_json::json!(["1.5", "25.0"]));
+
+        // A hint that doesn't match the value falls back to the heuristics
+        let flag = ScVal::Bool(true);
+        assert_eq!(scval_to_json_hinted(&flag, &ScTypeHint::AccountId), serde_json::json!(true));
+    }
+
+    #[test]
+    fn test_leaderboard_pair_list() {
+        use soroban_client::xdr::Int128Parts;
+
+        let player_a = "CCYOZJCOPG34LLQQ7N24YXBM7LL62R7ONMZ3G6WZAAYPB5OYKOMJRN63";
+        let player_b = "CAVLP5DH2GJPZMVO7IJY4CVOD5MWEFTJFVPD2YY2FQXOQHRGHK4D6HLP";
+        let entry = |player: &str, score: u64| ScVal::Vec(Some(vec![
+            ScVal::Address(Address::new(player).unwrap().to_sc_address().unwrap()),
+            ScVal::I128(Int128Parts { hi: 0, lo: score }),
+        ].try_into().unwrap()));
+        let leaderboard = ScVal::Vec(Some(vec![entry(player_a, 1200), entry(player_b, 950)].try_into().unwrap()));
+
+        // Heuristic: [key, value] pairs with strkey addresses
+        assert_eq!(
+            scval_to_json(&leaderboard),
+            serde_json::json!([[player_a, "1200"], [player_b, "950"]])
+        );
+
+        // Schema: named-field objects
+        let hint = ScTypeHint::Vec(Box::new(ScTypeHint::Tuple(vec![
+            TupleField { name: "player".to_string(), hint: None },
+            TupleField { name: "score".to_string(), hint: Some(ScTypeHint::Decimal(0)) },
+        ])));
+        assert_eq!(
+            scval_to_json_hinted(&leaderboard, &hint),
+            serde_json::json!([
+                {"player": player_a, "score": "1200"},
+                {"player": player_b, "score": "950"},
+            ])
+        );
+    }
+
+    #[test]
+    fn test_mixed_tuples_are_not_pair_list() {
+        let mixed = vec![
+            ScVal::Vec(Some(vec![ScVal::U32(1), ScVal::Bool(true)].try_into().unwrap())),
+            ScVal::Vec(Some(vec![ScVal::U32(2), ScVal::U32(3)].try_into().unwrap())),
+        ];
+        assert!(!is_pair_list(&mixed));
+        assert!(!is_pair_list(&[]));
+    }
+
+    #[test]
+    fn test_option_parameters() {
+        let none = FunctionParameter::Enum("None".to_string(), None);
+        let some = FunctionParameter::Enum("Some".to_string(), Some(Box::new(FunctionParameter::U32(7))));
+
+        assert_eq!(
+            function_parameter_to_scval(&none).unwrap(),
+            ScVal::Vec(Some(vec![ScVal::Symbol(ScSymbol("None".try_into().unwrap()))].try_into().unwrap()))
+        );
+        assert_eq!(
+            function_parameter_to_scval(&some).unwrap(),
+            ScVal::Vec(Some(vec![ScVal::Symbol(ScSymbol("Some".try_into().unwrap())), ScVal::U32(7)].try_into().unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_malformed_option_parameters_rejected() {
+        let none_with_value = FunctionParameter::Enum("None".to_string(), Some(Box::new(FunctionParameter::U32(7))));
+        let some_without_value = FunctionParameter::Enum("Some".to_string(), None);
+
+        assert!(matches!(function_parameter_to_scval(&none_with_value), Err(AppError::InvalidInput(_))));
+        assert!(matches!(function_parameter_to_scval(&some_without_value), Err(AppError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_protocol_presets_convert_to_scval() {
+        use shared::dto::presets::{parameter_presets, BLEND_POOL_ID, REFLECTOR_ORACLE_ID};
+
+        let presets = parameter_presets();
+        assert!(presets.keys().any(|(id, _)| *id == REFLECTOR_ORACLE_ID));
+        assert!(presets.keys().any(|(id, _)| *id == BLEND_POOL_ID));
+
+        for ((contract_id, function_name), preset) in &presets {
+            for param in &preset.parameters {
+                assert!(
+                    function_parameter_to_scval(param).is_ok(),
+                    "{}::{} preset has an invalid parameter: {:?}", contract_id, function_name, param
+                );
+            }
+        }
+    }
+
+    /// `Vec([Vec([... U32(0) ...])])` with `levels` Vec wrappers
+    fn nested_vec_parameter(levels: usize) -> FunctionParameter {
+        (0..levels).fold(FunctionParameter::U32(0), |inner, _| FunctionParameter::Vec(vec![inner]))
+    }
+
+    #[test]
+    fn test_over_deep_parameter_rejected() {
+        let max_depth = max_parameter_depth();
+
+        // Max depth includes the innermost scalar
+        assert!(function_parameter_to_scval(&nested_vec_parameter(max_depth - 1)).is_ok());
+
+        match function_parameter_to_scval(&nested_vec_parameter(max_depth)) {
+            Err(AppError::InvalidInput(message)) => assert!(message.contains("parameter nesting too deep")),
+            other => panic!("Expected InvalidInput, got {:?}", other),
+        }
+
+        // Far beyond the limit: rejected cleanly instead of recursing
+        assert!(matches!(
+            function_parameter_to_scval(&nested_vec_parameter(1_000)),
+            Err(AppError::InvalidInput(_))
+        ));
+
+        let deep_enum = (0..max_depth).fold(FunctionParameter::U32(0), |inner, _| {
+            FunctionParameter::Enum("Some".to_string(), Some(Box::new(inner)))
+        });
+        assert!(matches!(function_parameter_to_scval(&deep_enum), Err(AppError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_over_deep_result_rejected() {
+        let nested = |levels: usize| {
+            (0..levels).fold(ScVal::U32(0), |inner, _| ScVal::Vec(Some(vec![inner].try_into().unwrap())))
+        };
+        let max_depth = max_parameter_depth();
+
+        let ok_xdr = nested(max_depth - 1).to_xdr_base64(Limits::none()).unwrap();
+        assert!(decode_result_xdr(&ok_xdr, None).is_ok());
+
+        let deep_xdr = nested(max_depth).to_xdr_base64(Limits::none()).unwrap();
+        assert!(matches!(decode_result_xdr(&deep_xdr, None), Err(AppError::XdrDecoding(_))));
+    }
+}
@@ -0,0 +1,143 @@
+// Diagnostic event decoding for failed Soroban simulations
+//
+// When a contract call fails, simulation returns diagnostic events alongside the
+// error. The interesting ones carry an `error` topic with the ScError that ended
+// execution and a message describing why. This module turns those into a
+// human-readable reason such as:
+//   "contract error Error(Contract, #3): balance is not sufficient to spend"
+
+use soroban_client::xdr::{ContractEventBody, DiagnosticEvent, Limits, ReadXdr, ScError, ScVal};
+
+/// Error extracted from a diagnostic event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticError {
+    /// Formatted error code, e.g. `Error(Contract, #3)`
+    pub code: String,
+    /// Whether the error was raised by the contract itself (vs. the host)
+    pub is_contract_error: bool,
+    /// Message attached to the event, if any
+    pub message: Option<String>,
+}
+
+impl DiagnosticError {
+    /// Human-readable reason for API responses
+    pub fn reason(&self) -> String {
+        let prefix = if self.is_contract_error { "contract error" } else { "host error" };
+        match &self.message {
+            Some(message) => format!("{} {}: {}", prefix, self.code, message),
+            None => format!("{} {}", prefix, self.code),
+        }
+    }
+}
+
+/// Decode a base64 XDR diagnostic event
+pub fn decode_diagnostic_event(event_xdr: &str) -> Option<DiagnosticEvent> {
+    DiagnosticEvent::from_xdr_base64(event_xdr, Limits::none()).ok()
+}
+
+/// Extract the error carried by a single diagnostic event, if it is an error event
+pub fn diagnostic_error(event: &DiagnosticEvent) -> Option<DiagnosticError> {
+    let ContractEventBody::V0(body) = &event.event.body;
+    let mut topics = body.topics.iter();
+
+    match topics.next() {
+        Some(ScVal::Symbol(symbol)) if symbol.0.as_slice() == b"error" => {}
+        _ => return None,
+    }
+
+    let (code, is_contract_error) = match topics.next() {
+        Some(ScVal::Error(ScError::Contract(code))) => (format!("Error(Contract, #{})", code), true),
+        Some(ScVal::Error(error)) => (format!("{:?}", error), false),
+        _ => return None,
+    };
+
+    Some(DiagnosticError {
+        code,
+        is_contract_error,
+        message: describe_data(&body.data),
+    })
+}
+
+/// Find the most relevant error in a list of base64 diagnostic events
+///
+/// Contract-raised errors win over host errors, since they carry the
+/// application-level reason (e.g. insufficient allowance).
+pub fn extract_error(events: &[String]) -> Option<DiagnosticError> {
+    let errors: Vec<DiagnosticError> = events
+        .iter()
+        .filter_map(|xdr| decode_diagnostic_event(xdr))
+        .filter_map(|event| diagnostic_error(&event))
+        .collect();
+
+    errors
+        .iter()
+        .find(|e| e.is_contract_error)
+        .or_else(|| errors.first())
+        .cloned()
+}
+
+/// Render event data as text: strings directly, vectors as "message [args]"
+fn describe_data(data: &ScVal) -> Option<String> {
+    match data {
+        ScVal::Void => None,
+        ScVal::String(s) => Some(String::from_utf8_lossy(s.0.as_slice()).to_string()),
+        ScVal::Symbol(s) => Some(String::from_utf8_lossy(s.0.as_slice()).to_string()),
+        ScVal::Vec(Some(items)) => {
+            let mut parts = items.0.iter().filter_map(describe_data);
+            let message = parts.next()?;
+            let args: Vec<String> = parts.collect();
+            if args.is_empty() {
+                Some(message)
+            } else {
+                Some(format!("{} [{}]", message, args.join(", ")))
+            }
+        }
+        other => Some(format!("{:?}", other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Diagnostic event: topics ["error", Error(Contract, #3)],
+    // data "balance is not sufficient to spend"
+    const CONTRACT_ERROR_EVENT: &str = "AAAAAAAAAAAAAAABAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACAAAAAAAAAAIAAAAPAAAABWVycm9yAAAAAAAAAgAAAAAAAAADAAAADgAAACJiYWxhbmNlIGlzIG5vdCBzdWZmaWNpZW50IHRvIHNwZW5kAAA=";
+
+    // Diagnostic event: topics ["fn_call"], data Void
+    const FN_CALL_EVENT: &str = "AAAAAQAAAAAAAAAAAAAAAgAAAAAAAAABAAAADwAAAAdmbl9jYWxsAAAAAAE=";
+
+    #[test]
+    fn test_decode_contract_error_event() {
+        let event = decode_diagnostic_event(CONTRACT_ERROR_EVENT).expect("event should decode");
+        let error = diagnostic_error(&event).expect("should be an error event");
+
+        assert!(error.is_contract_error);
+        assert_eq!(error.code, "Error(Contract, #3)");
+        assert_eq!(error.message.as_deref(), Some("balance is not sufficient to spend"));
+        assert_eq!(
+            error.reason(),
+            "contract error Error(Contract, #3): balance is not sufficient to spend"
+        );
+    }
+
+    #[test]
+    fn test_non_error_event_ignored() {
+        let event = decode_diagnostic_event(FN_CALL_EVENT).expect("event should decode");
+        assert!(diagnostic_error(&event).is_none());
+    }
+
+    #[test]
+    fn test_extract_error_from_event_list() {
+        let events = vec![
+            FN_CALL_EVENT.to_string(),
+            "not-base64".to_string(),
+            CONTRACT_ERROR_EVENT.to_string(),
+        ];
+
+        let error = extract_error(&events).expect("should find the contract error");
+        assert_eq!(error.code, "Error(Contract, #3)");
+
+        assert!(extract_error(&[FN_CALL_EVENT.to_string()]).is_none());
+    }
+}
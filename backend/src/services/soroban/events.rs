@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use soroban_client::address::{Address, AddressTrait};
 use soroban_client::xdr::{ScVal, Limits, WriteXdr, ReadXdr};
 use tracing::debug;
 
@@ -213,6 +214,93 @@ impl EventResponse {
     }
 }
 
+/// Price update emitted by a Reflector oracle
+///
+/// Reflector publishes updates with topics `["REFLECTOR", "price", asset]` and a
+/// value of either `[price, timestamp]` or `{ price, timestamp }`. Prices are
+/// i128 values with the oracle's decimals (14 for Reflector), kept as strings
+/// to avoid JSON precision loss.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReflectorPriceEvent {
+    /// Asset code (e.g. "BTC") or contract address for Stellar assets
+    pub asset: String,
+    /// Raw price value
+    pub price: String,
+    /// Unix timestamp (seconds) of the price update
+    pub timestamp: u64,
+}
+
+impl ReflectorPriceEvent {
+    /// Decode a price update from an RPC event, if it has the oracle's shape
+    pub fn from_event(event: &EventResponse) -> Option<Self> {
+        let topics: Vec<ScVal> = event.parse_all_topics().into_iter().collect::<Option<_>>()?;
+        let value = event.parse_value()?;
+        Self::from_parts(&topics, &value)
+    }
+
+    /// Decode a price update from already-parsed topics and value
+    pub fn from_parts(topics: &[ScVal], value: &ScVal) -> Option<Self> {
+        match topics {
+            [ScVal::Symbol(name), ScVal::Symbol(kind), asset, ..]
+                if name.0.as_slice().eq_ignore_ascii_case(b"REFLECTOR") && kind.0.as_slice() == b"price" =>
+            {
+                let (price, timestamp) = match value {
+                    ScVal::Vec(Some(items)) => match items.0.as_slice() {
+                        [price, timestamp, ..] => (price, timestamp),
+                        _ => return None,
+                    },
+                    ScVal::Map(Some(map)) => {
+                        let field = |key: &[u8]| {
+                            map.0.iter().find_map(|entry| match &entry.key {
+                                ScVal::Symbol(sym) if sym.0.as_slice() == key => Some(&entry.val),
+                                _ => None,
+                            })
+                        };
+                        (field(b"price")?, field(b"timestamp")?)
+                    }
+                    _ => return None,
+                };
+
+                Some(Self {
+                    asset: decode_asset(asset)?,
+                    price: decode_i128(price)?.to_string(),
+                    timestamp: match timestamp {
+                        ScVal::U64(ts) => *ts,
+                        ScVal::Timepoint(ts) => ts.0,
+                        _ => return None,
+                    },
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Decode a Reflector asset: `Other(Symbol)`, `Stellar(Address)`, or a bare symbol/address
+fn decode_asset(asset: &ScVal) -> Option<String> {
+    match asset {
+        ScVal::Symbol(sym) => String::from_utf8(sym.0.as_slice().to_vec()).ok(),
+        ScVal::Address(addr) => Some(
+            Address::from_sc_address(addr)
+                .map(|a| a.to_string())
+                .unwrap_or_else(|_| format!("{:?}", addr)),
+        ),
+        ScVal::Vec(Some(items)) => match items.0.as_slice() {
+            [ScVal::Symbol(_tag), inner] => decode_asset(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn decode_i128(value: &ScVal) -> Option<i128> {
+    match value {
+        ScVal::I128(parts) => Some(((parts.hi as i128) << 64) | (parts.lo as i128)),
+        ScVal::I64(n) => Some(*n as i128),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,4 +333,65 @@ mod tests {
         assert_eq!(Topic::Any.to_xdr_string(), "*");
         assert_eq!(Topic::Greedy.to_xdr_string(), "**");
     }
+
+    fn symbol(s: &str) -> ScVal {
+        ScVal::Symbol(soroban_client::xdr::ScSymbol(s.try_into().unwrap()))
+    }
+
+    fn sample_oracle_event(value: ScVal) -> EventResponse {
+        let topics = vec![
+            symbol("REFLECTOR"),
+            symbol("price"),
+            ScVal::Vec(Some(soroban_client::xdr::ScVec(
+                vec![symbol("Other"), symbol("BTC")].try_into().unwrap(),
+            ))),
+        ];
+
+        EventResponse {
+            event_type: "contract".to_string(),
+            ledger: 1000,
+            ledger_closed_at: "2025-01-01T00:00:00Z".to_string(),
+            contract_id: "CCYOZJCOPG34LLQQ7N24YXBM7LL62R7ONMZ3G6WZAAYPB5OYKOMJRN63".to_string(),
+            id: "0000004294967296-0000000001".to_string(),
+            paging_token: "0000004294967296-0000000001".to_string(),
+            topic: topics.iter().map(|t| t.to_xdr_base64(Limits::none()).unwrap()).collect(),
+            value: value.to_xdr_base64(Limits::none()).unwrap(),
+            in_successful_contract_call: true,
+            transaction_hash: None,
+        }
+    }
+
+    #[test]
+    fn test_decode_reflector_price_event() {
+        // 97,000.50 USD with 14 decimals
+        let price = 9_700_050_000_000_000_000i128;
+        let value = ScVal::Vec(Some(soroban_client::xdr::ScVec(
+            vec![
+                ScVal::I128(soroban_client::xdr::Int128Parts {
+                    hi: (price >> 64) as i64,
+                    lo: price as u64,
+                }),
+                ScVal::U64(1_735_689_600),
+            ]
+            .try_into()
+            .unwrap(),
+        )));
+
+        let event = sample_oracle_event(value);
+        let decoded = ReflectorPriceEvent::from_event(&event).expect("should decode price event");
+
+        assert_eq!(decoded.asset, "BTC");
+        assert_eq!(decoded.price, "9700050000000000000");
+        assert_eq!(decoded.timestamp, 1_735_689_600);
+    }
+
+    #[test]
+    fn test_non_oracle_event_not_decoded() {
+        let mut event = sample_oracle_event(ScVal::U32(1));
+        assert!(ReflectorPriceEvent::from_event(&event).is_none());
+
+        event.topic = vec![symbol("transfer").to_xdr_base64(Limits::none()).unwrap()];
+        event.value = ScVal::U64(1).to_xdr_base64(Limits::none()).unwrap();
+        assert!(ReflectorPriceEvent::from_event(&event).is_none());
+    }
 }
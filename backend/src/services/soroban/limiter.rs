@@ -0,0 +1,172 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::sync::{Semaphore, SemaphorePermit};
+use tracing::warn;
+use crate::error::{AppError, Result};
+
+/// Process-wide limiter shared by every `simulateTransaction` call
+static SIMULATION_LIMITER: OnceLock<SimulationLimiter> = OnceLock::new();
+
+/// Concurrency limits for `simulateTransaction`
+#[derive(Debug, Clone)]
+pub struct SimulationLimiterConfig {
+    /// Simulations allowed in flight at once across all contracts
+    pub max_concurrent: usize,
+    /// How long a call waits for a permit before failing with 429
+    pub acquire_timeout: Duration,
+}
+
+impl Default for SimulationLimiterConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent: 16,
+            acquire_timeout: Duration::from_millis(250),
+        }
+    }
+}
+
+impl SimulationLimiterConfig {
+    /// Default config overridden by `MAX_CONCURRENT_SIMULATIONS` and `SIMULATION_PERMIT_WAIT_MS`
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        Self {
+            max_concurrent: std::env::var("MAX_CONCURRENT_SIMULATIONS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(defaults.max_concurrent)
+                .max(1),
+            acquire_timeout: std::env::var("SIMULATION_PERMIT_WAIT_MS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.acquire_timeout),
+        }
+    }
+}
+
+/// Bounds concurrent simulations so a burst can't overwhelm the upstream RPC
+///
+/// Callers wait briefly for a permit, then fail fast with
+/// `AppError::TooManyRequests` rather than queueing indefinitely.
+#[derive(Debug)]
+pub struct SimulationLimiter {
+    semaphore: Semaphore,
+    config: SimulationLimiterConfig,
+    rejected: AtomicU64,
+}
+
+impl SimulationLimiter {
+    pub fn new(config: SimulationLimiterConfig) -> Self {
+        Self {
+            semaphore: Semaphore::new(config.max_concurrent),
+            config,
+            rejected: AtomicU64::new(0),
+        }
+    }
+
+    /// The shared limiter, configured from the environment on first use
+    pub fn global() -> &'static SimulationLimiter {
+        SIMULATION_LIMITER.get_or_init(|| Self::new(SimulationLimiterConfig::from_env()))
+    }
+
+    /// Wait up to `acquire_timeout` for a permit; the simulation holds it until dropped
+    pub async fn acquire(&self) -> Result<SemaphorePermit<'_>> {
+        match tokio::time::timeout(self.config.acquire_timeout, self.semaphore.acquire()).await {
+            Ok(Ok(permit)) => Ok(permit),
+            Ok(Err(_)) => Err(AppError::Internal("Simulation limiter closed".to_string())),
+            Err(_) => {
+                self.rejected.fetch_add(1, Ordering::Relaxed);
+                warn!(
+                    "[LIMITER] ⚠️  Rejecting simulation: {} already in flight",
+                    self.in_flight()
+                );
+                Err(AppError::TooManyRequests(format!(
+                    "Too many concurrent simulations (limit {}), retry shortly",
+                    self.config.max_concurrent
+                )))
+            }
+        }
+    }
+
+    /// Simulations currently holding a permit
+    pub fn in_flight(&self) -> usize {
+        self.config.max_concurrent - self.semaphore.available_permits()
+    }
+
+    pub fn max_concurrent(&self) -> usize {
+        self.config.max_concurrent
+    }
+
+    /// Simulations turned away because no permit freed up in time
+    pub fn rejected(&self) -> u64 {
+        self.rejected.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicUsize;
+
+    fn limiter(max_concurrent: usize, wait_ms: u64) -> Arc<SimulationLimiter> {
+        Arc::new(SimulationLimiter::new(SimulationLimiterConfig {
+            max_concurrent,
+            acquire_timeout: Duration::from_millis(wait_ms),
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_limit_enforced_under_concurrency() {
+        let limiter = limiter(3, 1000);
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let tasks: Vec<_> = (0..12)
+            .map(|_| {
+                let limiter = limiter.clone();
+                let running = running.clone();
+                let peak = peak.clone();
+                tokio::spawn(async move {
+                    let _permit = limiter.acquire().await.unwrap();
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+        assert_eq!(limiter.in_flight(), 0);
+        assert_eq!(limiter.rejected(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_fails_fast_with_429_when_saturated() {
+        let limiter = limiter(2, 10);
+        let _first = limiter.acquire().await.unwrap();
+        let _second = limiter.acquire().await.unwrap();
+        assert_eq!(limiter.in_flight(), 2);
+
+        let error = limiter.acquire().await.unwrap_err();
+
+        assert_eq!(error.status_code(), axum::http::StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(limiter.rejected(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_permit_released_on_drop() {
+        let limiter = limiter(1, 10);
+
+        drop(limiter.acquire().await.unwrap());
+
+        assert!(limiter.acquire().await.is_ok());
+    }
+}
@@ -1,22 +1,37 @@
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 use tracing::{info, warn, error};
 use serde::{Deserialize, Serialize};
 
 use super::{
-    registry::{ContractRegistry, ContractMetadata, create_default_registry},
-    queue::{ContractQueue, ContractOperation, OperationPriority, QueueResult},
+    registry::{ContractRegistry, ContractMetadata, ContractHandle, create_default_registry},
+    spec::ContractSpec,
+    budget::CostLimits,
+    queue::{ContractQueue, ContractOperation, OperationPriority, PendingOperation, QueueResult},
     pool::PoolConfig,
     circuit_breaker::CircuitBreakerConfig,
+    limiter::SimulationLimiter,
+    subscriptions::SubscriptionCursors,
+    upgrades::{WasmHashTracker, upgrade_check_interval_from_env},
+    warmup::{WarmupCall, WarmupReport},
 };
 use crate::error::{AppError, Result};
 use crate::types::ContractFunction;
+use shared::dto::soroban::CallCacheMode;
+
+/// How long the latest ledger sequence is reused for ledger-keyed call caching
+pub const LATEST_LEDGER_CACHE_TTL: Duration = Duration::from_secs(1);
 
 /// High-level contract manager that orchestrates all scalability components
 pub struct ScalableContractManager {
     registry: Arc<ContractRegistry>,
     queue: Arc<ContractQueue>,
-    metrics: Arc<tokio::sync::RwLock<ContractMetrics>>,
+    metrics: Arc<AtomicContractMetrics>,
+    subscriptions: Arc<SubscriptionCursors>,
+    upgrades: Arc<WasmHashTracker>,
+    /// Background upgrade checks, aborted when the manager is dropped
+    upgrade_monitor: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl ScalableContractManager {
@@ -29,30 +44,55 @@ impl ScalableContractManager {
             .await
             .map_err(|e| AppError::Config(format!("Failed to create registry: {}", e)))?;
 
-        // Create async queue for operations
-        let queue = Arc::new(ContractQueue::new());
+        Ok(Self::with_registry(registry))
+    }
+
+    /// Create a manager around an existing registry (e.g. contracts pointing at a local RPC)
+    pub fn with_registry(registry: Arc<ContractRegistry>) -> Self {
+        Self::with_registry_and_queue(registry, ContractQueue::new())
+    }
+
+    /// Create a manager around an existing registry and submission queue
+    ///
+    /// Upgrade checks run every `UPGRADE_CHECK_INTERVAL_SECS` (0 disables them).
+    pub fn with_registry_and_queue(registry: Arc<ContractRegistry>, queue: ContractQueue) -> Self {
+        let queue = Arc::new(queue);
 
         // Initialize metrics
-        let metrics = Arc::new(tokio::sync::RwLock::new(ContractMetrics::default()));
+        let metrics = Arc::new(AtomicContractMetrics::default());
+
+        // Event subscription cursors (resume after reconnect)
+        let subscriptions = Arc::new(SubscriptionCursors::default());
 
         // Start background tasks
-        Self::start_background_tasks(queue.clone(), metrics.clone());
+        Self::start_background_tasks(queue.clone(), metrics.clone(), subscriptions.clone());
+
+        // Watch on-chain Wasm hashes for contract upgrades
+        let upgrades = Arc::new(WasmHashTracker::new());
+        let upgrade_monitor = upgrade_check_interval_from_env()
+            .map(|period| Self::start_upgrade_monitor(registry.clone(), upgrades.clone(), period));
 
         info!("✅ Scalable Contract Manager initialized successfully");
 
-        Ok(Self {
+        Self {
             registry,
             queue,
             metrics,
-        })
+            subscriptions,
+            upgrades,
+            upgrade_monitor,
+        }
     }
 
     /// Generate XDR for contract function with all scalability features
+    ///
+    /// The transaction expires `tx_timeout_secs` after it is built.
     pub async fn generate_xdr(
         &self,
         contract_id: &str,
         source_account: &str,
         function: &ContractFunction,
+        tx_timeout_secs: u64,
     ) -> Result<String> {
         // Get contract handle from registry
         let handle = self
@@ -65,12 +105,12 @@ impl ScalableContractManager {
         let cache_key = format!("xdr:{}:{}:{}", contract_id, source_account, function.name());
         if let Some(cached_xdr) = handle.cache.get(&cache_key).await {
             info!("✅ XDR retrieved from cache");
-            self.record_cache_hit().await;
+            self.record_cache_hit();
             return String::from_utf8(cached_xdr)
                 .map_err(|e| AppError::XdrEncoding(format!("Invalid cached XDR: {}", e)));
         }
 
-        self.record_cache_miss().await;
+        self.record_cache_miss();
 
         // Get RPC connection from pool (validates connection is available)
         let _connection = handle
@@ -87,14 +127,14 @@ impl ScalableContractManager {
                         contract_id: handle.metadata.contract_id.clone(),
                         network_passphrase: handle.metadata.network_passphrase.clone(),
                         rpc_url: handle.metadata.rpc_url.clone(),
+                        tx_timeout_secs,
                     },
                     source_account,
                     function,
                 )
                 .await
             })
-            .await
-            .map_err(AppError::StellarRpc)?;
+            .await?;
 
         // Cache the result (1 minute TTL for XDR)
         handle
@@ -102,11 +142,13 @@ impl ScalableContractManager {
             .set(cache_key, xdr_result.clone().into_bytes(), Some(Duration::from_secs(60)))
             .await;
 
-        self.record_xdr_generated().await;
+        self.record_xdr_generated();
         Ok(xdr_result)
     }
 
     /// Submit signed transaction via async queue with retry logic
+    ///
+    /// Transactions whose prepared budget exceeds `cost_limits` are refused before queueing.
     pub async fn submit_transaction(
         &self,
         contract_id: &str,
@@ -114,7 +156,16 @@ impl ScalableContractManager {
         function: ContractFunction,
         signed_xdr: String,
         priority: Option<OperationPriority>,
+        cost_limits: &CostLimits,
     ) -> Result<String> {
+        let handle = self.registry.get(contract_id).await
+            .ok_or_else(|| AppError::Config(format!("Contract not found: {}", contract_id)))?;
+
+        cost_limits.check_envelope_xdr(&signed_xdr)?;
+
+        // The hash is fixed by the signed transaction and network, before submission
+        let tx_hash = crate::services::stellar::envelope_hash(&signed_xdr, &handle.metadata.network_passphrase)?;
+
         // Create operation
         let operation = ContractOperation::new(
             contract_id.to_string(),
@@ -122,6 +173,7 @@ impl ScalableContractManager {
             source_account,
             Some(signed_xdr),
         )
+        .with_tx_hash(tx_hash)
         .with_priority(priority.unwrap_or(OperationPriority::Normal))
         .with_max_retries(3);
 
@@ -132,7 +184,7 @@ impl ScalableContractManager {
             .await
             .map_err(AppError::Transaction)?;
 
-        self.record_transaction_submitted().await;
+        self.record_transaction_submitted();
         Ok(operation_id)
     }
 
@@ -141,6 +193,16 @@ impl ScalableContractManager {
         self.queue.next_result().await
     }
 
+    /// Operations waiting in the submission queue
+    pub fn pending_operations(&self) -> Vec<PendingOperation> {
+        self.queue.pending()
+    }
+
+    /// Cancel every operation still waiting in the submission queue
+    pub fn flush_queue(&self) -> usize {
+        self.queue.flush()
+    }
+
     /// Register a new contract dynamically
     pub async fn register_contract(&self, metadata: ContractMetadata) -> Result<()> {
         self.registry
@@ -156,7 +218,14 @@ impl ScalableContractManager {
 
     /// Get comprehensive system metrics
     pub async fn get_metrics(&self) -> ContractMetrics {
-        self.metrics.read().await.clone()
+        let limiter = SimulationLimiter::global();
+
+        ContractMetrics {
+            simulations_in_flight: limiter.in_flight() as u64,
+            max_concurrent_simulations: limiter.max_concurrent() as u64,
+            simulations_rejected: limiter.rejected(),
+            ..self.metrics.snapshot()
+        }
     }
 
     /// Get detailed contract information
@@ -172,12 +241,158 @@ impl ScalableContractManager {
             pool_stats: handle.rpc_pool.stats().await,
             circuit_breaker_stats: handle.circuit_breaker.stats().await,
             cache_stats: handle.cache.stats().await,
+            wasm_hash: self.upgrades.wasm_hash(contract_id).await,
+            upgraded: self.upgrades.is_upgraded(contract_id).await,
+        })
+    }
+
+    /// Compare every enabled contract's on-chain Wasm hash with the last seen value
+    pub async fn check_for_upgrades(&self) {
+        Self::check_upgrades(&self.registry, &self.upgrades).await;
+    }
+
+    async fn check_upgrades(registry: &ContractRegistry, upgrades: &WasmHashTracker) {
+        for metadata in registry.list_all().await {
+            if !metadata.enabled {
+                continue;
+            }
+            let Some(handle) = registry.get(&metadata.contract_id).await else {
+                continue;
+            };
+
+            match Self::wasm_hash(&handle).await {
+                Ok(Some(hash)) => {
+                    upgrades.observe(&metadata.contract_id, &hash).await;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    warn!("[MANAGER] ⚠️ Could not read Wasm hash for {}: {}", metadata.contract_id, e);
+                }
+            }
+        }
+    }
+
+    /// Wasm hash the contract instance points to, read through the pool and circuit breaker
+    async fn wasm_hash(handle: &ContractHandle) -> Result<Option<String>> {
+        let config = crate::services::stellar::XdrConfig {
+            contract_id: handle.metadata.contract_id.clone(),
+            network_passphrase: handle.metadata.network_passphrase.clone(),
+            rpc_url: handle.metadata.rpc_url.clone(),
+            tx_timeout_secs: crate::services::stellar::DEFAULT_TX_TIMEOUT_SECS,
+        };
+
+        // Get RPC connection from pool
+        let _connection = handle
+            .get_rpc_connection()
+            .await
+            .map_err(AppError::StellarRpc)?;
+
+        // Execute with circuit breaker protection
+        handle
+            .call_with_protection(super::client::get_contract_wasm_hash(&config, &handle.metadata.contract_id))
+            .await
+    }
+
+    /// Periodically check registered contracts for on-chain upgrades
+    ///
+    /// The first check runs one `period` after startup and records the baseline hashes.
+    fn start_upgrade_monitor(
+        registry: Arc<ContractRegistry>,
+        upgrades: Arc<WasmHashTracker>,
+        period: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            info!("🔍 Starting contract upgrade monitor (every {:?})", period);
+            let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+            loop {
+                interval.tick().await;
+                Self::check_upgrades(&registry, &upgrades).await;
+            }
         })
     }
 
+    /// Cursor store for event subscriptions
+    pub fn subscriptions(&self) -> &SubscriptionCursors {
+        &self.subscriptions
+    }
+
+    /// Whether a registered contract is a known price oracle
+    pub async fn is_price_oracle(&self, contract_id: &str) -> bool {
+        self.registry
+            .get(contract_id)
+            .await
+            .map(|handle| handle.metadata.is_price_oracle())
+            .unwrap_or(false)
+    }
+
+    /// Oldest ledger the contract's RPC node still retains
+    ///
+    /// Cached for a few seconds, since it only moves as the node prunes history.
+    pub async fn oldest_ledger(&self, contract_id: &str) -> Result<u32> {
+        let handle = self
+            .registry
+            .get(contract_id)
+            .await
+            .ok_or_else(|| AppError::Config(format!("Contract not found: {}", contract_id)))?;
+
+        let cache_key = "oldest_ledger".to_string();
+        if let Some(cached) = handle.cache.get(&cache_key).await {
+            if let Ok(oldest) = serde_json::from_slice::<u32>(&cached) {
+                return Ok(oldest);
+            }
+        }
+
+        // Get RPC connection from pool
+        let _connection = handle
+            .get_rpc_connection()
+            .await
+            .map_err(AppError::StellarRpc)?;
+
+        // Execute with circuit breaker protection
+        let health = handle
+            .call_with_protection(super::client::get_health(&handle.metadata.rpc_url))
+            .await?;
+
+        if let Ok(cached_bytes) = serde_json::to_vec(&health.oldest_ledger) {
+            handle.cache.set(cache_key, cached_bytes, Some(Duration::from_secs(5))).await;
+        }
+
+        Ok(health.oldest_ledger)
+    }
+
+    /// Latest closed ledger on the contract's RPC node
+    ///
+    /// Cached for `LATEST_LEDGER_CACHE_TTL`, well under a ledger close, so a burst of
+    /// ledger-keyed calls shares one lookup.
+    async fn latest_ledger(handle: &ContractHandle) -> Result<u32> {
+        let cache_key = "latest_ledger".to_string();
+        if let Some(cached) = handle.cache.get(&cache_key).await {
+            if let Ok(sequence) = serde_json::from_slice::<u32>(&cached) {
+                return Ok(sequence);
+            }
+        }
+
+        // Get RPC connection from pool
+        let _connection = handle
+            .get_rpc_connection()
+            .await
+            .map_err(AppError::StellarRpc)?;
+
+        // Execute with circuit breaker protection
+        let sequence = handle
+            .call_with_protection(super::client::get_latest_ledger(&handle.metadata.rpc_url))
+            .await?;
+
+        if let Ok(cached_bytes) = serde_json::to_vec(&sequence) {
+            handle.cache.set(cache_key, cached_bytes, Some(LATEST_LEDGER_CACHE_TTL)).await;
+        }
+
+        Ok(sequence)
+    }
+
     /// Health check for the contract manager
     pub async fn health_check(&self) -> HealthStatus {
-        let metrics = self.metrics.read().await.clone();
+        let metrics = self.metrics.snapshot();
         let registry_stats = self.registry.stats().await;
 
         HealthStatus {
@@ -211,7 +426,7 @@ impl ScalableContractManager {
         let cache_key = format!("events:{}:{:?}:{:?}", contract_id, pagination, limit);
         if let Some(cached_response) = handle.cache.get(&cache_key).await {
             info!("[MANAGER] ✅ Events retrieved from cache");
-            self.record_cache_hit().await;
+            self.record_cache_hit();
 
             // Try to deserialize cached response
             if let Ok(events_response) = serde_json::from_slice::<super::events::GetEventsResponse>(&cached_response) {
@@ -219,7 +434,7 @@ impl ScalableContractManager {
             }
         }
 
-        self.record_cache_miss().await;
+        self.record_cache_miss();
 
         // Get RPC connection from pool
         let _connection = handle
@@ -236,6 +451,7 @@ impl ScalableContractManager {
                         contract_id: handle.metadata.contract_id.clone(),
                         network_passphrase: handle.metadata.network_passphrase.clone(),
                         rpc_url: handle.metadata.rpc_url.clone(),
+                        tx_timeout_secs: crate::services::stellar::DEFAULT_TX_TIMEOUT_SECS,
                     },
                     pagination,
                     filters,
@@ -243,8 +459,7 @@ impl ScalableContractManager {
                 )
                 .await
             })
-            .await
-            .map_err(AppError::StellarRpc)?;
+            .await?;
 
         // Cache the result (30 seconds TTL for events - they change frequently)
         if let Ok(cached_bytes) = serde_json::to_vec(&events_result) {
@@ -255,9 +470,7 @@ impl ScalableContractManager {
         }
 
         // Update metrics
-        let mut metrics = self.metrics.write().await;
-        metrics.total_operations += 1;
-        metrics.successful_operations += 1;
+        self.metrics.record_operation(true);
 
         info!("[MANAGER] ✅ query_events successful - {} events returned", events_result.event_count());
 
@@ -291,17 +504,23 @@ impl ScalableContractManager {
         let handle = self.registry.get(contract_id).await
             .ok_or_else(|| AppError::Config(format!("Contract not found: {}", contract_id)))?;
 
-        // Check cache first (60-second TTL for simulations)
-        let cache_key = format!("sim:{}:{}", contract_id, transaction_xdr);
+        // Check cache first (60-second TTL for simulations); the auth mode
+        // changes the result, so it is part of the key
+        let auth_mode = options.as_ref().and_then(|opt| opt.auth_mode);
+        let cache_key = format!("sim:{}:{:?}:{}", contract_id, auth_mode, transaction_xdr);
         if let Some(cached_response) = handle.cache.get(&cache_key).await {
-            self.record_cache_hit().await;
+            self.record_cache_hit();
             if let Ok(sim_response) = serde_json::from_slice(&cached_response) {
                 info!("[MANAGER] ✅ Using cached simulation result");
                 return Ok(sim_response);
             }
         }
 
-        self.record_cache_miss().await;
+        self.record_cache_miss();
+
+        // Bound concurrent simulations; acquired outside the circuit breaker so
+        // a 429 here never counts as an upstream failure
+        let _simulation_permit = SimulationLimiter::global().acquire().await?;
 
         // Get RPC connection from pool
         let _connection = handle.get_rpc_connection().await
@@ -312,6 +531,7 @@ impl ScalableContractManager {
             contract_id: handle.metadata.contract_id.clone(),
             rpc_url: handle.metadata.rpc_url.clone(),
             network_passphrase: handle.metadata.network_passphrase.clone(),
+            tx_timeout_secs: crate::services::stellar::DEFAULT_TX_TIMEOUT_SECS,
         };
 
         // Execute with circuit breaker protection
@@ -319,8 +539,7 @@ impl ScalableContractManager {
             .call_with_protection(async {
                 super::client::simulate_transaction(&config, transaction_xdr, options).await
             })
-            .await
-            .map_err(|e| AppError::Internal(format!("Circuit breaker error: {}", e)))?;
+            .await?;
 
         // Cache result (60 seconds TTL - longer than events since simulations are more expensive)
         if let Ok(cached_bytes) = serde_json::to_vec(&simulation_result) {
@@ -332,9 +551,7 @@ impl ScalableContractManager {
         }
 
         // Update metrics
-        let mut metrics = self.metrics.write().await;
-        metrics.total_operations += 1;
-        metrics.successful_operations += 1;
+        self.metrics.record_operation(true);
 
         if simulation_result.is_success() {
             info!(
@@ -365,13 +582,13 @@ impl ScalableContractManager {
     /// * `durability` - Storage durability (Temporary or Persistent)
     ///
     /// # Returns
-    /// A `LedgerEntryResult` containing the storage data and TTL information
+    /// A `ContractDataRead` containing the storage data, TTL and the ledger it was read at
     pub async fn get_contract_data(
         &self,
         contract_id: &str,
         key: &str,
         durability: super::state::Durability,
-    ) -> Result<super::state::LedgerEntryResult> {
+    ) -> Result<super::state::ContractDataRead> {
         info!(
             "[MANAGER] get_contract_data called for contract: {}, durability: {:?}",
             contract_id, durability
@@ -384,14 +601,14 @@ impl ScalableContractManager {
         // Check cache first (5-minute TTL for contract state)
         let cache_key = format!("state:{}:{}:{:?}", contract_id, key, durability);
         if let Some(cached_response) = handle.cache.get(&cache_key).await {
-            self.record_cache_hit().await;
+            self.record_cache_hit();
             if let Ok(entry) = serde_json::from_slice(&cached_response) {
                 info!("[MANAGER] ✅ Using cached contract data");
                 return Ok(entry);
             }
         }
 
-        self.record_cache_miss().await;
+        self.record_cache_miss();
 
         // Get RPC connection from pool
         let _connection = handle.get_rpc_connection().await
@@ -402,6 +619,7 @@ impl ScalableContractManager {
             contract_id: handle.metadata.contract_id.clone(),
             rpc_url: handle.metadata.rpc_url.clone(),
             network_passphrase: handle.metadata.network_passphrase.clone(),
+            tx_timeout_secs: crate::services::stellar::DEFAULT_TX_TIMEOUT_SECS,
         };
 
         // Execute with circuit breaker protection
@@ -409,8 +627,7 @@ impl ScalableContractManager {
             .call_with_protection(async {
                 super::client::get_contract_data(&config, contract_id, key, durability).await
             })
-            .await
-            .map_err(|e| AppError::Internal(format!("Circuit breaker error: {}", e)))?;
+            .await?;
 
         // Cache result (5 minutes TTL - contract state changes less frequently)
         if let Ok(cached_bytes) = serde_json::to_vec(&data_result) {
@@ -422,15 +639,70 @@ impl ScalableContractManager {
         }
 
         // Update metrics
-        let mut metrics = self.metrics.write().await;
-        metrics.total_operations += 1;
-        metrics.successful_operations += 1;
+        self.metrics.record_operation(true);
 
         info!("[MANAGER] ✅ get_contract_data successful");
 
         Ok(data_result)
     }
 
+    /// Read the TTL of several storage entries of a contract
+    ///
+    /// Not cached: expiry monitoring needs live TTLs.
+    pub async fn get_ttl_report(
+        &self,
+        contract_id: &str,
+        keys: Vec<(String, super::state::Durability)>,
+    ) -> Result<super::state::TtlReport> {
+        info!("[MANAGER] get_ttl_report called for contract: {}, {} keys", contract_id, keys.len());
+
+        let handle = self.registry.get(contract_id).await
+            .ok_or_else(|| AppError::Config(format!("Contract not found: {}", contract_id)))?;
+
+        let _connection = handle.get_rpc_connection().await
+            .map_err(|e| AppError::Internal(format!("Failed to get RPC connection: {}", e)))?;
+
+        let config = crate::services::stellar::XdrConfig {
+            contract_id: handle.metadata.contract_id.clone(),
+            rpc_url: handle.metadata.rpc_url.clone(),
+            network_passphrase: handle.metadata.network_passphrase.clone(),
+            tx_timeout_secs: crate::services::stellar::DEFAULT_TX_TIMEOUT_SECS,
+        };
+
+        let report = handle
+            .call_with_protection(async {
+                super::client::get_ttl_report(&config, contract_id, keys).await
+            })
+            .await?;
+
+        self.metrics.record_operation(true);
+
+        info!("[MANAGER] ✅ get_ttl_report successful");
+
+        Ok(report)
+    }
+
+    /// Build a contract call's transaction without simulating it or touching the RPC
+    pub async fn explain_contract_call(
+        &self,
+        contract_id: &str,
+        function_name: &str,
+        parameters: &[shared::dto::soroban::FunctionParameter],
+        source_account: Option<&str>,
+    ) -> Result<shared::dto::soroban::CallContractFunctionResponse> {
+        let handle = self.registry.get(contract_id).await
+            .ok_or_else(|| AppError::Config(format!("Contract not found: {}", contract_id)))?;
+
+        super::client::explain_contract_call(
+            contract_id,
+            function_name,
+            parameters,
+            source_account,
+            &handle.metadata.rpc_url,
+            &handle.metadata.network_passphrase,
+        )
+    }
+
     /// Call a generic contract function (read-only via simulation)
     ///
     /// This method allows calling ANY Soroban contract function by:
@@ -439,7 +711,7 @@ impl ScalableContractManager {
     /// - Parsing the result from XDR to JSON
     ///
     /// Uses scalability features:
-    /// - Cache for repeated function calls (60-second TTL)
+    /// - Cache for repeated function calls (60-second TTL, or until the next ledger)
     /// - Circuit breaker for fault tolerance
     /// - Connection pool for scalability
     /// - Metrics tracking
@@ -449,6 +721,8 @@ impl ScalableContractManager {
     /// * `function_name` - Name of the function to invoke
     /// * `parameters` - Function parameters (will be converted to ScVal)
     /// * `source_account` - Optional source account (uses default if None)
+    /// * `options` - Simulation options (see `AppConfig::read_simulation_options`)
+    /// * `cache_mode` - `Ledger` keys the cache by the latest ledger so a new ledger invalidates it
     ///
     /// # Returns
     /// A `CallContractFunctionResponse` containing the parsed result and simulation details
@@ -458,6 +732,8 @@ impl ScalableContractManager {
         function_name: &str,
         parameters: Vec<shared::dto::soroban::FunctionParameter>,
         source_account: Option<&str>,
+        options: Option<super::simulation::SimulationOptions>,
+        cache_mode: CallCacheMode,
     ) -> Result<shared::dto::soroban::CallContractFunctionResponse> {
         info!(
             "[MANAGER] call_contract_function - contract: {}, function: {}",
@@ -469,19 +745,43 @@ impl ScalableContractManager {
             .ok_or_else(|| AppError::Config(format!("Contract not found: {}", contract_id)))?;
 
         // Build cache key from function name and parameters
-        let params_hash = format!("{:?}", parameters);
-        let cache_key = format!("func:{}:{}:{}", contract_id, function_name, params_hash);
+        let base_key = Self::function_cache_key(contract_id, function_name, &parameters);
+
+        // Ledger mode ties the entry to the latest ledger, so the next close misses the cache
+        let (cache_key, cache_ttl) = match cache_mode {
+            CallCacheMode::Ttl => (Some(base_key), Duration::from_secs(60)),
+            CallCacheMode::Ledger => match Self::latest_ledger(&handle).await {
+                Ok(ledger) => (Some(format!("{}@{}", base_key, ledger)), Duration::from_secs(10)),
+                Err(e) => {
+                    warn!("[MANAGER] ⚠️ Could not read latest ledger, bypassing cache: {}", e);
+                    (None, Duration::ZERO)
+                }
+            },
+        };
 
-        // Check cache first (60-second TTL for function calls)
-        if let Some(cached_response) = handle.cache.get(&cache_key).await {
-            self.record_cache_hit().await;
-            if let Ok(func_response) = serde_json::from_slice(&cached_response) {
-                info!("[MANAGER] ✅ Using cached function call result");
-                return Ok(func_response);
+        // Check cache first
+        if let Some(cache_key) = &cache_key {
+            if let Some(cached_response) = handle.cache.get(cache_key).await {
+                self.record_cache_hit();
+                if let Ok(func_response) = serde_json::from_slice(&cached_response) {
+                    info!("[MANAGER] ✅ Using cached function call result");
+                    return Ok(func_response);
+                }
             }
         }
 
-        self.record_cache_miss().await;
+        self.record_cache_miss();
+
+        // Reject unknown functions up front when the contract spec is available
+        if let Some(spec) = self.get_contract_spec(&handle).await {
+            spec.validate_call(function_name, parameters.len())?;
+        }
+
+        // Malformed parameters are the caller's error, so convert them outside the circuit breaker
+        let arguments = super::client::function_parameters_to_scvals(&parameters)?;
+
+        // Calls are answered by simulation, so they share the simulation limit
+        let _simulation_permit = SimulationLimiter::global().acquire().await?;
 
         // Get RPC connection from pool
         let _connection = handle.get_rpc_connection().await
@@ -493,34 +793,24 @@ impl ScalableContractManager {
                 super::client::call_contract_function(
                     contract_id,
                     function_name,
-                    parameters,
+                    arguments,
                     source_account,
+                    options,
                     &handle.metadata.rpc_url,
                     &handle.metadata.network_passphrase,
                 ).await
             })
-            .await
-            .map_err(|e| AppError::Internal(format!("Circuit breaker error: {}", e)))?;
+            .await?;
 
-        // Cache successful results (60 seconds TTL)
-        if func_result.success {
+        // Cache successful results
+        if let (true, Some(cache_key)) = (func_result.success, cache_key) {
             if let Ok(cached_bytes) = serde_json::to_vec(&func_result) {
-                handle.cache.set(
-                    cache_key,
-                    cached_bytes,
-                    Some(std::time::Duration::from_secs(60))
-                ).await;
+                handle.cache.set(cache_key, cached_bytes, Some(cache_ttl)).await;
             }
         }
 
         // Update metrics
-        let mut metrics = self.metrics.write().await;
-        metrics.total_operations += 1;
-        if func_result.success {
-            metrics.successful_operations += 1;
-        } else {
-            metrics.failed_operations += 1;
-        }
+        self.metrics.record_operation(func_result.success);
 
         if func_result.success {
             info!("[MANAGER] ✅ call_contract_function successful");
@@ -531,34 +821,142 @@ impl ScalableContractManager {
         Ok(func_result)
     }
 
+    /// Pre-populate the cache by executing each warmup call once
+    pub async fn warm_cache(&self, calls: &[WarmupCall], options: &super::simulation::SimulationOptions) -> WarmupReport {
+        info!("[MANAGER] 🔥 Warming cache with {} calls", calls.len());
+        let mut report = WarmupReport::default();
+
+        for call in calls {
+            match self
+                .call_contract_function(&call.contract_id, &call.function_name, call.parameters.clone(), None, Some(options.clone()), CallCacheMode::Ttl)
+                .await
+            {
+                Ok(response) if response.success => {
+                    info!("[MANAGER] 🔥 Warmed {}::{}", call.contract_id, call.function_name);
+                    report.warmed += 1;
+                }
+                Ok(response) => {
+                    warn!("[MANAGER] ⚠️ Warmup call {}::{} failed: {:?}", call.contract_id, call.function_name, response.error);
+                    report.failed += 1;
+                }
+                Err(e) => {
+                    warn!("[MANAGER] ⚠️ Warmup call {}::{} failed: {}", call.contract_id, call.function_name, e);
+                    report.failed += 1;
+                }
+            }
+        }
+
+        info!("[MANAGER] ✅ Cache warmup complete - {} warmed, {} failed", report.warmed, report.failed);
+        report
+    }
+
+    /// Whether a function call result is currently cached
+    pub async fn is_function_cached(
+        &self,
+        contract_id: &str,
+        function_name: &str,
+        parameters: &[shared::dto::soroban::FunctionParameter],
+    ) -> bool {
+        match self.registry.get(contract_id).await {
+            Some(handle) => handle
+                .cache
+                .get(&Self::function_cache_key(contract_id, function_name, parameters))
+                .await
+                .is_some(),
+            None => false,
+        }
+    }
+
+    fn function_cache_key(
+        contract_id: &str,
+        function_name: &str,
+        parameters: &[shared::dto::soroban::FunctionParameter],
+    ) -> String {
+        format!("func:{}:{}:{:?}", contract_id, function_name, parameters)
+    }
+
+    /// Load the contract spec, caching it for 5 minutes
+    ///
+    /// Returns `None` when the spec cannot be read so callers can fall back
+    /// to plain simulation. Contracts without a spec are cached as such;
+    /// failed reads (RPC errors, an open breaker) are not, so the next call retries.
+    async fn get_contract_spec(&self, handle: &ContractHandle) -> Option<ContractSpec> {
+        let cache_key = format!("spec:{}", handle.metadata.contract_id);
+
+        if let Some(cached_spec) = handle.cache.get(&cache_key).await {
+            if let Ok(spec) = serde_json::from_slice::<Option<ContractSpec>>(&cached_spec) {
+                return spec;
+            }
+        }
+
+        let config = crate::services::stellar::XdrConfig {
+            contract_id: handle.metadata.contract_id.clone(),
+            network_passphrase: handle.metadata.network_passphrase.clone(),
+            rpc_url: handle.metadata.rpc_url.clone(),
+            tx_timeout_secs: crate::services::stellar::DEFAULT_TX_TIMEOUT_SECS,
+        };
+
+        // Read through the pool and circuit breaker like every other RPC call
+        let spec = match handle.get_rpc_connection().await {
+            Ok(_connection) => {
+                handle
+                    .call_with_protection(super::client::get_contract_spec(&config, &handle.metadata.contract_id))
+                    .await
+            }
+            Err(e) => Err(AppError::StellarRpc(e)),
+        };
+
+        match spec {
+            Ok(spec) => {
+                let spec = spec.filter(|spec| !spec.functions.is_empty());
+                if let Ok(cached_bytes) = serde_json::to_vec(&spec) {
+                    handle.cache.set(
+                        cache_key,
+                        cached_bytes,
+                        Some(Duration::from_secs(300))
+                    ).await;
+                }
+                spec
+            }
+            Err(e) => {
+                warn!("[MANAGER] ⚠️ Contract spec unavailable, falling back to simulation: {}", e);
+                None
+            }
+        }
+    }
+
     // Internal metric recording methods
-    async fn record_cache_hit(&self) {
-        let mut metrics = self.metrics.write().await;
-        metrics.cache_hits += 1;
+    fn record_cache_hit(&self) {
+        self.metrics.record_cache_hit();
     }
 
-    async fn record_cache_miss(&self) {
-        let mut metrics = self.metrics.write().await;
-        metrics.cache_misses += 1;
+    fn record_cache_miss(&self) {
+        self.metrics.record_cache_miss();
     }
 
-    async fn record_xdr_generated(&self) {
-        let mut metrics = self.metrics.write().await;
-        metrics.xdr_generated += 1;
-        metrics.total_operations += 1;
+    fn record_xdr_generated(&self) {
+        self.metrics.record_xdr_generated();
     }
 
-    async fn record_transaction_submitted(&self) {
-        let mut metrics = self.metrics.write().await;
-        metrics.transactions_submitted += 1;
-        metrics.total_operations += 1;
+    fn record_transaction_submitted(&self) {
+        self.metrics.record_transaction_submitted();
     }
 
     /// Start background tasks for queue processing, cache cleanup, etc.
     fn start_background_tasks(
         queue: Arc<ContractQueue>,
-        metrics: Arc<tokio::sync::RwLock<ContractMetrics>>,
+        metrics: Arc<AtomicContractMetrics>,
+        subscriptions: Arc<SubscriptionCursors>,
     ) {
+        // Expired subscription cursor cleanup
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(600));
+            loop {
+                interval.tick().await;
+                subscriptions.cleanup_expired().await;
+            }
+        });
+
         // Queue result processor
         tokio::spawn(async move {
             info!("🔄 Starting queue result processor");
@@ -567,18 +965,15 @@ impl ScalableContractManager {
                 match result {
                     QueueResult::Success { operation_id, result } => {
                         info!("✅ Operation {} succeeded: {}", operation_id, result);
-                        let mut m = metrics.write().await;
-                        m.successful_operations += 1;
+                        metrics.record_queue_success();
                     }
                     QueueResult::Retry { operation_id, attempt } => {
                         warn!("🔄 Operation {} retry attempt {}", operation_id, attempt);
-                        let mut m = metrics.write().await;
-                        m.retried_operations += 1;
+                        metrics.record_queue_retry();
                     }
                     QueueResult::Failed { operation_id, error } => {
                         error!("❌ Operation {} failed: {}", operation_id, error);
-                        let mut m = metrics.write().await;
-                        m.failed_operations += 1;
+                        metrics.record_queue_failure();
                     }
                 }
             }
@@ -586,6 +981,14 @@ impl ScalableContractManager {
     }
 }
 
+impl Drop for ScalableContractManager {
+    fn drop(&mut self) {
+        if let Some(monitor) = &self.upgrade_monitor {
+            monitor.abort();
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ContractMetrics {
     pub total_operations: u64,
@@ -596,6 +999,11 @@ pub struct ContractMetrics {
     pub cache_misses: u64,
     pub xdr_generated: u64,
     pub transactions_submitted: u64,
+    /// Simulations currently holding a limiter permit
+    pub simulations_in_flight: u64,
+    pub max_concurrent_simulations: u64,
+    /// Simulations rejected with 429 because the limit was reached
+    pub simulations_rejected: u64,
 }
 
 impl ContractMetrics {
@@ -615,12 +1023,89 @@ impl ContractMetrics {
     }
 }
 
+/// Lock-free counters behind `ContractMetrics`
+///
+/// Operations bump these concurrently without contending on a lock;
+/// `snapshot()` reads them into the serializable struct.
+#[derive(Debug, Default)]
+pub struct AtomicContractMetrics {
+    total_operations: AtomicU64,
+    successful_operations: AtomicU64,
+    failed_operations: AtomicU64,
+    retried_operations: AtomicU64,
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+    xdr_generated: AtomicU64,
+    transactions_submitted: AtomicU64,
+}
+
+impl AtomicContractMetrics {
+    /// Count a completed operation as successful or failed
+    pub fn record_operation(&self, success: bool) {
+        self.total_operations.fetch_add(1, Ordering::Relaxed);
+        if success {
+            self.successful_operations.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.failed_operations.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn record_queue_success(&self) {
+        self.successful_operations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_queue_retry(&self) {
+        self.retried_operations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_queue_failure(&self) {
+        self.failed_operations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_cache_hit(&self) {
+        self.cache_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_cache_miss(&self) {
+        self.cache_misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_xdr_generated(&self) {
+        self.xdr_generated.fetch_add(1, Ordering::Relaxed);
+        self.total_operations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_transaction_submitted(&self) {
+        self.transactions_submitted.fetch_add(1, Ordering::Relaxed);
+        self.total_operations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Read the current counters
+    pub fn snapshot(&self) -> ContractMetrics {
+        ContractMetrics {
+            total_operations: self.total_operations.load(Ordering::Relaxed),
+            successful_operations: self.successful_operations.load(Ordering::Relaxed),
+            failed_operations: self.failed_operations.load(Ordering::Relaxed),
+            retried_operations: self.retried_operations.load(Ordering::Relaxed),
+            cache_hits: self.cache_hits.load(Ordering::Relaxed),
+            cache_misses: self.cache_misses.load(Ordering::Relaxed),
+            xdr_generated: self.xdr_generated.load(Ordering::Relaxed),
+            transactions_submitted: self.transactions_submitted.load(Ordering::Relaxed),
+            ..ContractMetrics::default()
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct ContractInfo {
     pub metadata: ContractMetadata,
     pub pool_stats: super::pool::PoolStats,
     pub circuit_breaker_stats: super::circuit_breaker::CircuitBreakerStats,
     pub cache_stats: super::cache::CacheStats,
+    /// Wasm hash the contract instance currently points to (once observed)
+    pub wasm_hash: Option<String>,
+    /// Whether the Wasm hash changed since the service started tracking it
+    pub upgraded: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -663,6 +1148,9 @@ impl From<ContractMetrics> for shared::dto::soroban::ContractMetrics {
             cache_misses: m.cache_misses,
             xdr_generated: m.xdr_generated,
             transactions_submitted: m.transactions_submitted,
+            simulations_in_flight: m.simulations_in_flight,
+            max_concurrent_simulations: m.max_concurrent_simulations,
+            simulations_rejected: m.simulations_rejected,
         }
     }
 }
@@ -687,6 +1175,8 @@ impl From<ContractInfo> for shared::dto::soroban::ContractInfo {
             pool_stats: i.pool_stats.into(),
             circuit_breaker_stats: i.circuit_breaker_stats.into(),
             cache_stats: i.cache_stats.into(),
+            wasm_hash: i.wasm_hash,
+            upgraded: i.upgraded,
         }
     }
 }
@@ -696,7 +1186,7 @@ impl From<ContractMetadata> for shared::dto::soroban::ContractMetadata {
         Self {
             contract_id: m.contract_id,
             name: m.name,
-            network: m.network.into(),
+            network: m.network,
             network_passphrase: m.network_passphrase,
             rpc_url: m.rpc_url,
             description: m.description,
@@ -706,23 +1196,16 @@ impl From<ContractMetadata> for shared::dto::soroban::ContractMetadata {
     }
 }
 
-impl From<super::registry::NetworkType> for shared::dto::soroban::NetworkType {
-    fn from(n: super::registry::NetworkType) -> Self {
-        match n {
-            super::registry::NetworkType::Testnet => Self::Testnet,
-            super::registry::NetworkType::Mainnet => Self::Mainnet,
-            super::registry::NetworkType::Futurenet => Self::Futurenet,
-            super::registry::NetworkType::Standalone => Self::Standalone,
-        }
-    }
-}
-
 impl From<super::pool::PoolStats> for shared::dto::soroban::PoolStats {
     fn from(p: super::pool::PoolStats) -> Self {
         Self {
             total_connections: p.total_connections,
             max_connections: p.max_connections,
             available: p.available,
+            rpc_requests_per_second: p.rpc_requests_per_second,
+            max_requests_per_second: p.max_requests_per_second,
+            peak_utilization: p.peak_utilization,
+            saturation_alerts: p.saturation_alerts,
         }
     }
 }
@@ -734,6 +1217,10 @@ impl From<super::circuit_breaker::CircuitBreakerStats> for shared::dto::soroban:
             failure_count: c.failure_count,
             success_count: c.success_count,
             is_open: c.is_open,
+            last_error: c.last_error.map(|e| shared::dto::soroban::LastError {
+                message: e.message,
+                at: e.at.to_rfc3339(),
+            }),
         }
     }
 }
@@ -757,3 +1244,45 @@ impl From<super::cache::CacheStats> for shared::dto::soroban::CacheStats {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_metric_updates_are_exact() {
+        let metrics = Arc::new(AtomicContractMetrics::default());
+
+        let handles: Vec<_> = (0..64)
+            .map(|task| {
+                let metrics = metrics.clone();
+                tokio::spawn(async move {
+                    for i in 0..500 {
+                        metrics.record_operation((task + i) % 4 != 0);
+                        if i % 2 == 0 {
+                            metrics.record_cache_hit();
+                        } else {
+                            metrics.record_cache_miss();
+                        }
+                    }
+                    metrics.record_xdr_generated();
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.total_operations, 64 * 500 + 64);
+        assert_eq!(snapshot.successful_operations, 64 * 375);
+        assert_eq!(snapshot.failed_operations, 64 * 125);
+        assert_eq!(snapshot.cache_hits, 64 * 250);
+        assert_eq!(snapshot.cache_misses, 64 * 250);
+        assert_eq!(snapshot.xdr_generated, 64);
+
+        assert_eq!(snapshot.cache_hit_rate(), 50.0);
+        assert_eq!(snapshot.success_rate(), (64.0 * 375.0) / (64.0 * 501.0) * 100.0);
+    }
+}
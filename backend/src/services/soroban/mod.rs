@@ -1,26 +1,44 @@
 // Soroban smart contract infrastructure modules
 
+pub mod budget;
 pub mod cache;
 pub mod circuit_breaker;
 pub mod client;
+pub mod diagnostics;
 pub mod events;
+pub mod limiter;
 pub mod manager;
 pub mod pool;
 pub mod queue;
 pub mod registry;
+pub mod scheduler;
 pub mod simulation;
+pub mod spec;
 pub mod state;
+pub mod subscriptions;
+pub mod token;
+pub mod upgrades;
+pub mod warmup;
 
 // Re-export commonly used types for easier imports
+pub use budget::CostLimits;
 pub use cache::ContractCache;
-pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitBreakerStats};
-pub use client::{generate_leaderboard_xdr, submit_signed_transaction, get_events, simulate_transaction, get_ledger_entries, get_contract_data};
-pub use events::{EventFilter, EventType, Topic, Pagination, GetEventsResponse, EventResponse};
+pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitBreakerStats, LastError};
+pub use client::{generate_leaderboard_xdr, submit_signed_transaction, get_events, simulate_transaction, get_ledger_entries, get_contract_data, get_ttl_report, get_contract_spec, get_contract_wasm_hash, rpc_batch, get_ledger_entries_batch, decode_result_xdr};
+pub use diagnostics::{DiagnosticError, extract_error};
+pub use limiter::{SimulationLimiter, SimulationLimiterConfig};
+pub use events::{EventFilter, EventType, Topic, Pagination, GetEventsResponse, EventResponse, ReflectorPriceEvent};
 pub use simulation::{SimulationOptions, AuthMode, SimulateTransactionResponse, RawSimulateHostFunctionResult, RestorePreamble, StateChangeKind};
-pub use state::{Durability, LedgerEntryResult, GetLedgerEntriesResponse};
+pub use spec::{ContractSpec, FunctionSpec, FunctionInputSpec};
+pub use state::{ContractDataRead, Durability, LedgerEntryResult, GetLedgerEntriesResponse, TtlReport, TtlReportEntry};
 pub use manager::{ScalableContractManager, ContractMetrics, ContractInfo, HealthStatus};
+pub use subscriptions::SubscriptionCursors;
+pub use token::{token_balance, TokenBalance};
+pub use upgrades::{WasmHashTracker, HashObservation};
+pub use warmup::{WarmupCall, WarmupConfig, WarmupReport};
 pub use pool::{StellarRpcPool, PoolConfig, PoolStats, PooledRpcConnection};
-pub use queue::{ContractQueue, ContractOperation, OperationPriority, QueueResult};
+pub use scheduler::RpcScheduler;
+pub use queue::{ContractQueue, ContractOperation, OperationPriority, OperationSuccess, PendingOperation, QueueResult};
 pub use registry::{
     ContractRegistry, ContractMetadata, ContractHandle, NetworkType,
     create_default_registry, RegistryStats,
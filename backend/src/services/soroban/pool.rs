@@ -1,9 +1,14 @@
-use soroban_client::{Server, Options};
-use std::sync::Arc;
+use soroban_client::Server;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use tokio::sync::{Semaphore, RwLock};
-use tracing::info;
+use tracing::{info, warn};
 use std::time::{Duration, Instant};
 use crate::error::{AppError, Result};
+use super::client::rpc_options;
+use super::registry::NetworkType;
+use super::scheduler::RpcScheduler;
 
 /// Connection pool configuration
 #[derive(Debug, Clone)]
@@ -12,6 +17,14 @@ pub struct PoolConfig {
     pub idle_timeout: Duration,
     pub connection_timeout: Duration,
     pub max_retries: u32,
+    /// Outbound RPC requests allowed per second per RPC URL (0 = unlimited)
+    pub max_requests_per_second: u32,
+    /// Pool size overrides per network (contracts can still override these)
+    pub network_max_connections: HashMap<NetworkType, usize>,
+    /// Utilization (0.0 - 1.0) above which the pool counts as saturated
+    pub saturation_threshold: f64,
+    /// How long the pool must stay saturated before a warning is raised
+    pub saturation_window: Duration,
 }
 
 impl Default for PoolConfig {
@@ -21,10 +34,72 @@ impl Default for PoolConfig {
             idle_timeout: Duration::from_secs(300),
             connection_timeout: Duration::from_secs(30),
             max_retries: 3,
+            max_requests_per_second: 20,
+            network_max_connections: HashMap::new(),
+            saturation_threshold: 0.9,
+            saturation_window: Duration::from_secs(60),
         }
     }
 }
 
+impl PoolConfig {
+    /// Default pool config overridden from the environment
+    ///
+    /// - `RPC_POOL_SIZE`: connections per contract pool
+    /// - `RPC_POOL_SIZE_<NETWORK>` (e.g. `RPC_POOL_SIZE_MAINNET`): per-network pool size
+    /// - `RPC_POOL_SATURATION_THRESHOLD` / `RPC_POOL_SATURATION_WINDOW_SECS`: saturation alerting
+    /// - `RPC_MAX_REQUESTS_PER_SECOND`: outbound RPC rate limit
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        let network_max_connections = NetworkType::ALL
+            .iter()
+            .filter_map(|network| {
+                let var = format!("RPC_POOL_SIZE_{:?}", network).to_uppercase();
+                env_parse::<usize>(&var).map(|size| (*network, size))
+            })
+            .collect();
+
+        Self {
+            max_connections: env_parse("RPC_POOL_SIZE").unwrap_or(defaults.max_connections),
+            max_requests_per_second: env_parse("RPC_MAX_REQUESTS_PER_SECOND")
+                .unwrap_or(defaults.max_requests_per_second),
+            network_max_connections,
+            saturation_threshold: env_parse("RPC_POOL_SATURATION_THRESHOLD")
+                .unwrap_or(defaults.saturation_threshold),
+            saturation_window: env_parse("RPC_POOL_SATURATION_WINDOW_SECS")
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.saturation_window),
+            ..defaults
+        }
+    }
+
+    /// Config for one contract's pool: contract override, then network override, then the default
+    pub fn for_contract(&self, network: NetworkType, pool_size: Option<usize>) -> Self {
+        let max_connections = pool_size
+            .or_else(|| self.network_max_connections.get(&network).copied())
+            .unwrap_or(self.max_connections);
+
+        Self {
+            max_connections: max_connections.max(1),
+            ..self.clone()
+        }
+    }
+}
+
+fn env_parse<T: std::str::FromStr>(var: &str) -> Option<T> {
+    std::env::var(var).ok().and_then(|v| v.parse().ok())
+}
+
+/// Tracks peak usage and how long the pool has been saturated
+#[derive(Default)]
+struct SaturationTracker {
+    peak_in_use: AtomicUsize,
+    saturated_since: Mutex<Option<Instant>>,
+    alerted: AtomicBool,
+    alerts: AtomicU64,
+}
+
 /// Pooled RPC connection
 #[derive(Clone)]
 struct PooledConnection {
@@ -37,11 +112,19 @@ pub struct StellarRpcPool {
     rpc_url: String,
     connections: Arc<RwLock<Vec<PooledConnection>>>,
     semaphore: Arc<Semaphore>,
+    scheduler: Arc<RpcScheduler>,
+    saturation: SaturationTracker,
     config: PoolConfig,
 }
 
 impl StellarRpcPool {
     pub fn new(rpc_url: String, config: PoolConfig) -> Result<Self> {
+        let scheduler = Arc::new(RpcScheduler::new(config.max_requests_per_second));
+        Self::with_scheduler(rpc_url, config, scheduler)
+    }
+
+    /// Create a pool that shares its request scheduler with other pools on the same RPC URL
+    pub fn with_scheduler(rpc_url: String, config: PoolConfig, scheduler: Arc<RpcScheduler>) -> Result<Self> {
         info!("🏊 Initializing Stellar RPC pool with {} connections", config.max_connections);
 
         let semaphore = Arc::new(Semaphore::new(config.max_connections));
@@ -51,18 +134,25 @@ impl StellarRpcPool {
             rpc_url,
             connections,
             semaphore,
+            scheduler,
+            saturation: SaturationTracker::default(),
             config,
         })
     }
 
     /// Get a connection from the pool (or create new if needed)
     pub async fn get_connection(&self) -> Result<PooledRpcConnection> {
+        // Wait for a request slot so bursts are spread out instead of throttled by the RPC
+        self.scheduler.acquire().await;
+
         // Acquire semaphore permit (limits concurrent connections)
         let permit = self.semaphore.clone()
             .acquire_owned()
             .await
             .map_err(|e| AppError::StellarRpc(format!("Failed to acquire connection: {}", e)))?;
 
+        self.record_utilization();
+
         // Try to get existing connection
         let mut connections = self.connections.write().await;
 
@@ -76,7 +166,7 @@ impl StellarRpcPool {
             conn
         } else {
             info!("📡 Creating new RPC connection to {}", self.rpc_url);
-            let server = Server::new(&self.rpc_url, Options::default())
+            let server = Server::new(&self.rpc_url, rpc_options(&self.rpc_url))
                 .map_err(|e| AppError::StellarRpc(format!("Failed to create RPC server: {:?}", e)))?;
 
             PooledConnection {
@@ -92,6 +182,37 @@ impl StellarRpcPool {
         })
     }
 
+    /// Record current usage and warn when the pool has been saturated for the whole window
+    fn record_utilization(&self) {
+        let max = self.config.max_connections;
+        let in_use = max.saturating_sub(self.semaphore.available_permits());
+        self.saturation.peak_in_use.fetch_max(in_use, Ordering::Relaxed);
+
+        let utilization = in_use as f64 / max as f64;
+        let mut saturated_since = self.saturation.saturated_since.lock().unwrap();
+
+        if utilization < self.config.saturation_threshold {
+            *saturated_since = None;
+            self.saturation.alerted.store(false, Ordering::Relaxed);
+            return;
+        }
+
+        let since = *saturated_since.get_or_insert_with(Instant::now);
+        if since.elapsed() >= self.config.saturation_window
+            && !self.saturation.alerted.swap(true, Ordering::Relaxed)
+        {
+            self.saturation.alerts.fetch_add(1, Ordering::Relaxed);
+            warn!(
+                "[POOL] ⚠️  RPC pool for {} has been at {:.0}% utilization ({}/{}) for {:?} - consider raising RPC_POOL_SIZE",
+                self.rpc_url,
+                utilization * 100.0,
+                in_use,
+                max,
+                since.elapsed()
+            );
+        }
+    }
+
     /// Get pool statistics
     pub async fn stats(&self) -> PoolStats {
         let connections = self.connections.read().await;
@@ -99,6 +220,11 @@ impl StellarRpcPool {
             total_connections: connections.len(),
             max_connections: self.config.max_connections,
             available: self.semaphore.available_permits(),
+            rpc_requests_per_second: self.scheduler.current_rate().await,
+            max_requests_per_second: self.scheduler.max_requests_per_second(),
+            peak_utilization: self.saturation.peak_in_use.load(Ordering::Relaxed) as f64
+                / self.config.max_connections as f64,
+            saturation_alerts: self.saturation.alerts.load(Ordering::Relaxed),
         }
     }
 }
@@ -135,4 +261,59 @@ pub struct PoolStats {
     pub total_connections: usize,
     pub max_connections: usize,
     pub available: usize,
+    /// Outbound RPC requests sent during the last second (shared per RPC URL)
+    pub rpc_requests_per_second: usize,
+    pub max_requests_per_second: u32,
+    /// Highest fraction of connections in use at once since startup
+    pub peak_utilization: f64,
+    /// Times the pool stayed saturated for a whole saturation window
+    pub saturation_alerts: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RPC_URL: &str = "https://soroban-testnet.stellar.org";
+
+    fn saturating_config(max_connections: usize) -> PoolConfig {
+        PoolConfig {
+            max_connections,
+            max_requests_per_second: 0,
+            saturation_threshold: 1.0,
+            saturation_window: Duration::ZERO,
+            ..PoolConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_saturated_pool_records_peak_utilization() {
+        let pool = StellarRpcPool::new(RPC_URL.to_string(), saturating_config(2)).unwrap();
+
+        let first = pool.get_connection().await.unwrap();
+        assert_eq!(pool.stats().await.peak_utilization, 0.5);
+        assert_eq!(pool.stats().await.saturation_alerts, 0);
+
+        let second = pool.get_connection().await.unwrap();
+        let stats = pool.stats().await;
+        assert_eq!(stats.available, 0);
+        assert_eq!(stats.peak_utilization, 1.0);
+        assert_eq!(stats.saturation_alerts, 1);
+
+        // The peak survives connections being returned
+        drop(first);
+        drop(second);
+        assert_eq!(pool.stats().await.peak_utilization, 1.0);
+    }
+
+    #[test]
+    fn test_pool_size_overrides() {
+        let mut config = PoolConfig::default();
+        config.network_max_connections.insert(NetworkType::Mainnet, 10);
+
+        assert_eq!(config.for_contract(NetworkType::Testnet, None).max_connections, 50);
+        assert_eq!(config.for_contract(NetworkType::Mainnet, None).max_connections, 10);
+        assert_eq!(config.for_contract(NetworkType::Mainnet, Some(5)).max_connections, 5);
+        assert_eq!(config.for_contract(NetworkType::Testnet, Some(0)).max_connections, 1);
+    }
 }
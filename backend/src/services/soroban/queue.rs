@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc;
 use tokio::time::{sleep, Duration};
 use tracing::{error, info, warn};
@@ -13,6 +15,8 @@ pub struct ContractOperation {
     pub function_name: String,
     pub source_account: String,
     pub signed_xdr: Option<String>,
+    /// Network hash of the signed transaction (hex), known before submission
+    pub tx_hash: Option<String>,
     pub priority: OperationPriority,
     pub max_retries: u32,
     pub retry_count: u32,
@@ -40,6 +44,7 @@ impl ContractOperation {
             function_name,
             source_account,
             signed_xdr,
+            tx_hash: None,
             priority: OperationPriority::Normal,
             max_retries: 3,
             retry_count: 0,
@@ -52,6 +57,11 @@ impl ContractOperation {
         self
     }
 
+    pub fn with_tx_hash(mut self, tx_hash: String) -> Self {
+        self.tx_hash = Some(tx_hash);
+        self
+    }
+
     pub fn with_max_retries(mut self, max_retries: u32) -> Self {
         self.max_retries = max_retries;
         self
@@ -73,26 +83,101 @@ pub enum QueueMessage {
     Shutdown,
 }
 
-#[derive(Debug, Clone)]
+/// Outcome of a successfully submitted operation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperationSuccess {
+    /// Hash of the submitted transaction (hex)
+    pub tx_hash: String,
+}
+
+impl std::fmt::Display for OperationSuccess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "tx {}", self.tx_hash)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
 pub enum QueueResult {
-    Success { operation_id: String, result: String },
+    Success { operation_id: String, result: OperationSuccess },
     Retry { operation_id: String, attempt: u32 },
     Failed { operation_id: String, error: String },
 }
 
+/// Summary of an operation waiting for the worker
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingOperation {
+    pub id: String,
+    pub contract_id: String,
+    pub function_name: String,
+    pub source_account: String,
+    pub priority: OperationPriority,
+    pub retry_count: u32,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<&ContractOperation> for PendingOperation {
+    fn from(operation: &ContractOperation) -> Self {
+        Self {
+            id: operation.id.clone(),
+            contract_id: operation.contract_id.clone(),
+            function_name: operation.function_name.clone(),
+            source_account: operation.source_account.clone(),
+            priority: operation.priority,
+            retry_count: operation.retry_count,
+            created_at: operation.created_at,
+        }
+    }
+}
+
+/// Submits one operation; the worker retries it on `Err`
+pub type OperationProcessor = Arc<
+    dyn Fn(ContractOperation) -> Pin<Box<dyn Future<Output = Result<OperationSuccess, String>> + Send>>
+        + Send
+        + Sync,
+>;
+
 /// Async queue for contract operations with retry logic
 pub struct ContractQueue {
     tx: mpsc::UnboundedSender<QueueMessage>,
     result_rx: Arc<tokio::sync::Mutex<mpsc::UnboundedReceiver<QueueResult>>>,
+    /// Operations queued but not yet picked up by the worker, in channel order
+    ///
+    /// The worker claims an operation by removing it here before processing,
+    /// so a flush and the worker can never both take the same operation.
+    pending: Arc<Mutex<Vec<ContractOperation>>>,
+}
+
+/// Remove `operation_id` from the pending list, returning whether it was there
+fn take_pending(pending: &mut Vec<ContractOperation>, operation_id: &str) -> bool {
+    match pending.iter().position(|operation| operation.id == operation_id) {
+        Some(index) => {
+            pending.remove(index);
+            true
+        }
+        None => false,
+    }
 }
 
 impl ContractQueue {
     pub fn new() -> Self {
+        Self::with_processor(|operation| async move { Self::process_operation(&operation).await })
+    }
+
+    /// Create a queue whose worker submits operations with `processor`
+    pub fn with_processor<F, Fut>(processor: F) -> Self
+    where
+        F: Fn(ContractOperation) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<OperationSuccess, String>> + Send + 'static,
+    {
+        let processor: OperationProcessor = Arc::new(move |operation| Box::pin(processor(operation)));
         let (tx, mut rx) = mpsc::unbounded_channel::<QueueMessage>();
         let (result_tx, result_rx) = mpsc::unbounded_channel::<QueueResult>();
 
         // Clone tx for use in retry logic
         let tx_clone = tx.clone();
+        let pending = Arc::new(Mutex::new(Vec::<ContractOperation>::new()));
+        let worker_pending = pending.clone();
 
         // Spawn worker task
         tokio::spawn(async move {
@@ -101,10 +186,15 @@ impl ContractQueue {
             while let Some(message) = rx.recv().await {
                 match message {
                     QueueMessage::Submit(mut operation) => {
+                        // Flushed operations are still in the channel but no longer pending
+                        if !take_pending(&mut worker_pending.lock().unwrap(), &operation.id) {
+                            info!("🗑️  Skipping flushed operation {}", operation.id);
+                            continue;
+                        }
+
                         info!("📥 Processing operation {} (priority: {:?})", operation.id, operation.priority);
 
-                        // Simulate contract submission (replace with actual logic)
-                        let result = Self::process_operation(&operation).await;
+                        let result = processor(operation.clone()).await;
 
                         match result {
                             Ok(success_result) => {
@@ -119,17 +209,25 @@ impl ContractQueue {
                                 warn!("⚠️  Operation {} failed (attempt {}/{}): {}",
                                     operation.id, operation.retry_count, operation.max_retries, error);
 
+                                // Pending again while it backs off, so a flush can still cancel it
+                                worker_pending.lock().unwrap().push(operation.clone());
+
                                 // Exponential backoff
                                 let delay = Duration::from_secs(2u64.pow(operation.retry_count));
                                 sleep(delay).await;
 
-                                // Retry
+                                // Re-queue behind anything submitted during the backoff
+                                let mut pending = worker_pending.lock().unwrap();
+                                if !take_pending(&mut pending, &operation.id) {
+                                    info!("🗑️  Not retrying flushed operation {}", operation.id);
+                                    continue;
+                                }
+                                pending.push(operation.clone());
+
                                 let _ = result_tx.send(QueueResult::Retry {
                                     operation_id: operation.id.clone(),
                                     attempt: operation.retry_count,
                                 });
-
-                                // Re-queue for retry
                                 let _ = tx_clone.send(QueueMessage::Submit(operation));
                             }
                             Err(error) => {
@@ -155,6 +253,7 @@ impl ContractQueue {
         Self {
             tx,
             result_rx: Arc::new(tokio::sync::Mutex::new(result_rx)),
+            pending,
         }
     }
 
@@ -162,9 +261,14 @@ impl ContractQueue {
     pub async fn submit(&self, operation: ContractOperation) -> Result<String, String> {
         let operation_id = operation.id.clone();
 
-        self.tx
-            .send(QueueMessage::Submit(operation))
-            .map_err(|e| format!("Failed to queue operation: {}", e))?;
+        // Send while holding the lock so the pending list matches channel order
+        let mut pending = self.pending.lock().unwrap();
+        pending.push(operation.clone());
+        if let Err(e) = self.tx.send(QueueMessage::Submit(operation)) {
+            pending.pop();
+            return Err(format!("Failed to queue operation: {}", e));
+        }
+        drop(pending);
 
         info!("📤 Operation {} queued successfully", operation_id);
         Ok(operation_id)
@@ -176,6 +280,27 @@ impl ContractQueue {
         rx.recv().await
     }
 
+    /// Operations waiting for the worker, in the order it will run them
+    pub fn pending(&self) -> Vec<PendingOperation> {
+        self.pending
+            .lock()
+            .unwrap()
+            .iter()
+            .map(PendingOperation::from)
+            .collect()
+    }
+
+    /// Cancel every pending operation, returning how many were dropped
+    ///
+    /// Operations the worker is already processing are not interrupted.
+    pub fn flush(&self) -> usize {
+        let dropped = std::mem::take(&mut *self.pending.lock().unwrap()).len();
+        if dropped > 0 {
+            warn!("🗑️  Flushed {} pending operations", dropped);
+        }
+        dropped
+    }
+
     /// Shutdown the queue
     pub async fn shutdown(&self) -> Result<(), String> {
         self.tx
@@ -184,15 +309,18 @@ impl ContractQueue {
     }
 
     /// Process a single operation (placeholder - implement actual contract logic)
-    async fn process_operation(operation: &ContractOperation) -> Result<String, String> {
+    async fn process_operation(operation: &ContractOperation) -> Result<OperationSuccess, String> {
         // TODO: Replace with actual Stellar contract submission logic
         // For now, simulate processing time and success/failure
 
         sleep(Duration::from_millis(100)).await;
 
+        let tx_hash = operation.tx_hash.clone()
+            .ok_or_else(|| format!("Operation {} has no signed transaction", operation.id))?;
+
         // Simulate 90% success rate
         if rand::random::<f32>() < 0.9 {
-            Ok(format!("Transaction submitted successfully for {}", operation.function_name))
+            Ok(OperationSuccess { tx_hash })
         } else {
             Err(format!("Simulated error for {}", operation.function_name))
         }
@@ -212,3 +340,123 @@ pub struct QueueStats {
     pub failed_operations: usize,
     pub retry_operations: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::Notify;
+
+    #[test]
+    fn test_success_result_is_structured() {
+        let result = QueueResult::Success {
+            operation_id: "op-1".to_string(),
+            result: OperationSuccess { tx_hash: "ab".repeat(32) },
+        };
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["status"], "success");
+        assert_eq!(json["operation_id"], "op-1");
+        assert_eq!(json["result"]["tx_hash"], "ab".repeat(32));
+
+        if let QueueResult::Success { result, .. } = &result {
+            assert_eq!(result.to_string(), format!("tx {}", "ab".repeat(32)));
+        }
+    }
+
+    fn operation(function_name: &str, priority: OperationPriority) -> ContractOperation {
+        ContractOperation::new(
+            "CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF".to_string(),
+            function_name.to_string(),
+            "GB43KVROR7TFJ6KAPCYRF2FJROTZAH4FHLTJLPWX4DRZCC5NASLGITR6".to_string(),
+            Some("AAAA".to_string()),
+        )
+        .with_priority(priority)
+        .with_max_retries(0)
+    }
+
+    /// A queue whose worker holds each operation until `release` is notified
+    fn held_queue(release: Arc<Notify>) -> ContractQueue {
+        ContractQueue::with_processor(move |operation| {
+            let release = release.clone();
+            async move {
+                release.notified().await;
+                Ok(OperationSuccess { tx_hash: "ab".repeat(32) })
+            }
+        })
+    }
+
+    /// Submit an operation and wait until the worker has claimed it, so it stays busy
+    async fn occupy_worker(queue: &ContractQueue) -> String {
+        let busy_id = queue.submit(operation("busy", OperationPriority::Normal)).await.unwrap();
+        while queue.pending().iter().any(|op| op.id == busy_id) {
+            tokio::task::yield_now().await;
+        }
+        busy_id
+    }
+
+    #[tokio::test]
+    async fn test_pending_lists_operations_in_execution_order() {
+        let queue = held_queue(Arc::new(Notify::new()));
+        occupy_worker(&queue).await;
+
+        let low = queue.submit(operation("low", OperationPriority::Low)).await.unwrap();
+        let critical = queue.submit(operation("critical", OperationPriority::Critical)).await.unwrap();
+        let normal = queue.submit(operation("normal", OperationPriority::Normal)).await.unwrap();
+
+        let ids: Vec<String> = queue.pending().into_iter().map(|op| op.id).collect();
+        assert_eq!(ids, vec![low, critical, normal]);
+    }
+
+    #[tokio::test]
+    async fn test_flush_drops_pending_operations() {
+        let release = Arc::new(Notify::new());
+        let queue = held_queue(release.clone());
+        let busy_id = occupy_worker(&queue).await;
+        for _ in 0..3 {
+            queue.submit(operation("queued", OperationPriority::High)).await.unwrap();
+        }
+
+        assert_eq!(queue.flush(), 3);
+        assert!(queue.pending().is_empty());
+        assert_eq!(queue.flush(), 0);
+
+        // Only the operation already in progress reports a result
+        release.notify_one();
+        match queue.next_result().await.unwrap() {
+            QueueResult::Success { operation_id, .. } => assert_eq!(operation_id, busy_id),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(tokio::time::timeout(Duration::from_millis(300), queue.next_result()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_queued_operation_reports_tx_hash() {
+        // Submission always succeeds, echoing the operation's hash
+        let queue = ContractQueue::with_processor(|operation| async move {
+            Ok(OperationSuccess { tx_hash: operation.tx_hash.unwrap() })
+        });
+        let operation_id = queue
+            .submit(operation("hello", OperationPriority::Normal).with_tx_hash("cd".repeat(32)))
+            .await
+            .unwrap();
+
+        match queue.next_result().await.unwrap() {
+            QueueResult::Success { operation_id: id, result } => {
+                assert_eq!(id, operation_id);
+                assert_eq!(result.tx_hash, "cd".repeat(32));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_operation_without_signed_transaction_fails() {
+        let queue = ContractQueue::new();
+        queue.submit(operation("unsigned", OperationPriority::Normal)).await.unwrap();
+
+        match queue.next_result().await.unwrap() {
+            QueueResult::Failed { error, .. } => assert!(error.contains("no signed transaction"), "{}", error),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
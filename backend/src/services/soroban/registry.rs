@@ -5,9 +5,12 @@ use tokio::sync::RwLock;
 use tracing::{info, warn};
 
 use super::pool::{StellarRpcPool, PoolConfig};
-use super::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
+use super::scheduler::RpcScheduler;
+use super::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitBreakerError};
 use super::cache::ContractCache;
 
+pub use shared::dto::soroban::NetworkType;
+
 /// Contract metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContractMetadata {
@@ -19,33 +22,18 @@ pub struct ContractMetadata {
     pub description: Option<String>,
     pub version: Option<String>,
     pub enabled: bool,
+    /// Pool size for this contract, overriding the registry/network default
+    #[serde(default)]
+    pub pool_size: Option<usize>,
+    /// Contract is a Reflector price oracle whose events carry price updates
+    #[serde(default)]
+    pub price_oracle: bool,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum NetworkType {
-    Testnet,
-    Mainnet,
-    Futurenet,
-    Standalone,
-}
-
-impl NetworkType {
-    pub fn default_passphrase(&self) -> &'static str {
-        match self {
-            NetworkType::Testnet => "Test SDF Network ; September 2015",
-            NetworkType::Mainnet => "Public Global Stellar Network ; September 2015",
-            NetworkType::Futurenet => "Test SDF Future Network ; October 2022",
-            NetworkType::Standalone => "Standalone Network ; February 2017",
-        }
-    }
-
-    pub fn default_rpc_url(&self) -> &'static str {
-        match self {
-            NetworkType::Testnet => "https://soroban-testnet.stellar.org",
-            NetworkType::Mainnet => "https://mainnet.sorobanrpc.com",
-            NetworkType::Futurenet => "https://rpc-futurenet.stellar.org",
-            NetworkType::Standalone => "http://localhost:8000/soroban/rpc",
-        }
+impl ContractMetadata {
+    /// Whether this contract is a Reflector price oracle (emits price update events)
+    pub fn is_price_oracle(&self) -> bool {
+        self.price_oracle
     }
 }
 
@@ -60,6 +48,8 @@ struct ContractResources {
 /// Multi-contract registry for massive scale
 pub struct ContractRegistry {
     contracts: Arc<RwLock<HashMap<String, ContractResources>>>,
+    /// Request schedulers shared by every contract on the same RPC URL
+    schedulers: Arc<RwLock<HashMap<String, Arc<RpcScheduler>>>>,
     default_pool_config: PoolConfig,
     default_circuit_config: CircuitBreakerConfig,
 }
@@ -73,6 +63,7 @@ impl ContractRegistry {
 
         Self {
             contracts: Arc::new(RwLock::new(HashMap::new())),
+            schedulers: Arc::new(RwLock::new(HashMap::new())),
             default_pool_config: pool_config.unwrap_or_default(),
             default_circuit_config: circuit_config.unwrap_or_default(),
         }
@@ -87,10 +78,12 @@ impl ContractRegistry {
 
         info!("📝 Registering contract: {} ({})", metadata.name, metadata.contract_id);
 
-        // Create RPC pool
-        let rpc_pool = StellarRpcPool::new(
+        // Create RPC pool, rate limited together with other contracts on the same RPC URL
+        let scheduler = self.scheduler_for(&metadata.rpc_url).await;
+        let rpc_pool = StellarRpcPool::with_scheduler(
             metadata.rpc_url.clone(),
-            self.default_pool_config.clone(),
+            self.default_pool_config.for_contract(metadata.network, metadata.pool_size),
+            scheduler,
         )
         .map_err(|e| format!("Failed to create RPC pool: {}", e))?;
 
@@ -114,6 +107,15 @@ impl ContractRegistry {
         Ok(())
     }
 
+    /// Get (or create) the request scheduler for an RPC URL
+    async fn scheduler_for(&self, rpc_url: &str) -> Arc<RpcScheduler> {
+        let mut schedulers = self.schedulers.write().await;
+        schedulers
+            .entry(rpc_url.to_string())
+            .or_insert_with(|| Arc::new(RpcScheduler::new(self.default_pool_config.max_requests_per_second)))
+            .clone()
+    }
+
     /// Get contract by ID
     pub async fn get(&self, contract_id: &str) -> Option<ContractHandle> {
         let contracts = self.contracts.read().await;
@@ -183,15 +185,20 @@ impl ContractHandle {
     }
 
     /// Execute function with circuit breaker protection
-    pub async fn call_with_protection<F, T, E>(&self, f: F) -> Result<T, String>
+    ///
+    /// The call's own error is returned unchanged, so RPC errors keep their
+    /// HTTP status; only a rejection by the open breaker becomes a new error.
+    pub async fn call_with_protection<F, T>(&self, f: F) -> crate::error::Result<T>
     where
-        F: std::future::Future<Output = Result<T, E>>,
-        E: std::fmt::Display,
+        F: std::future::Future<Output = crate::error::Result<T>>,
     {
-        self.circuit_breaker
-            .call(f)
-            .await
-            .map_err(|e| format!("Circuit breaker error: {}", e))
+        self.circuit_breaker.call(f).await.map_err(|e| match e {
+            CircuitBreakerError::CircuitOpen => crate::error::AppError::StellarRpc(format!(
+                "Circuit breaker is open for contract {}",
+                self.metadata.contract_id
+            )),
+            CircuitBreakerError::RequestFailed(e) => e,
+        })
     }
 }
 
@@ -204,7 +211,7 @@ pub struct RegistryStats {
 
 /// Create default contract registry with common contracts
 pub async fn create_default_registry() -> Result<Arc<ContractRegistry>, String> {
-    let registry = Arc::new(ContractRegistry::new(None, None));
+    let registry = Arc::new(ContractRegistry::new(Some(PoolConfig::from_env()), None));
 
     // Register leaderboard contract
     let leaderboard_metadata = ContractMetadata {
@@ -218,6 +225,8 @@ pub async fn create_default_registry() -> Result<Arc<ContractRegistry>, String>
         description: Some("Game leaderboard smart contract".to_string()),
         version: Some("1.0.0".to_string()),
         enabled: true,
+        pool_size: None,
+        price_oracle: false,
     };
 
     registry.register(leaderboard_metadata).await?;
@@ -233,6 +242,8 @@ pub async fn create_default_registry() -> Result<Arc<ContractRegistry>, String>
         description: Some("Reflector price oracle for Stellar - SEP-40 compatible".to_string()),
         version: Some("1.0.0".to_string()),
         enabled: true,
+        pool_size: None,
+        price_oracle: true,
     };
 
     registry.register(reflector_testnet_metadata).await?;
@@ -248,6 +259,8 @@ pub async fn create_default_registry() -> Result<Arc<ContractRegistry>, String>
         description: Some("Reflector FX rates oracle for fiat currencies".to_string()),
         version: Some("1.0.0".to_string()),
         enabled: true,
+        pool_size: None,
+        price_oracle: true,
     };
 
     registry.register(reflector_fx_metadata).await?;
@@ -262,6 +275,8 @@ pub async fn create_default_registry() -> Result<Arc<ContractRegistry>, String>
         description: Some("Reflector price oracle for Stellar - SEP-40 compatible (Mainnet)".to_string()),
         version: Some("1.0.0".to_string()),
         enabled: false, // Disabled by default since we're on testnet
+        pool_size: None,
+        price_oracle: true,
     };
 
     registry.register(reflector_mainnet_metadata).await?;
@@ -277,6 +292,8 @@ pub async fn create_default_registry() -> Result<Arc<ContractRegistry>, String>
         description: Some("Blend lending protocol - Pool Factory for creating lending pools".to_string()),
         version: Some("2.0.0".to_string()),
         enabled: true,
+        pool_size: None,
+        price_oracle: false,
     };
 
     registry.register(blend_pool_factory_metadata).await?;
@@ -292,6 +309,8 @@ pub async fn create_default_registry() -> Result<Arc<ContractRegistry>, String>
         description: Some("Blend lending protocol - Main test lending pool".to_string()),
         version: Some("2.0.0".to_string()),
         enabled: true,
+        pool_size: None,
+        price_oracle: false,
     };
 
     registry.register(blend_test_pool_metadata).await?;
@@ -307,6 +326,8 @@ pub async fn create_default_registry() -> Result<Arc<ContractRegistry>, String>
         description: Some("Blend lending protocol - Backstop module for pool insurance".to_string()),
         version: Some("2.0.0".to_string()),
         enabled: true,
+        pool_size: None,
+        price_oracle: false,
     };
 
     registry.register(blend_backstop_metadata).await?;
@@ -0,0 +1,127 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+use tracing::debug;
+
+/// Window used to measure the current outbound request rate
+const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Leaky-bucket scheduler that spaces outbound RPC requests evenly
+///
+/// Every request is assigned the next free slot, one `1 / max_requests_per_second`
+/// apart. Requests arriving faster than that wait for their slot instead of
+/// hitting the RPC in a burst. A limit of 0 disables scheduling.
+pub struct RpcScheduler {
+    max_requests_per_second: u32,
+    interval: Duration,
+    next_slot: Mutex<Instant>,
+    recent: Mutex<VecDeque<Instant>>,
+}
+
+impl RpcScheduler {
+    pub fn new(max_requests_per_second: u32) -> Self {
+        let interval = if max_requests_per_second == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs(1) / max_requests_per_second
+        };
+
+        Self {
+            max_requests_per_second,
+            interval,
+            next_slot: Mutex::new(Instant::now()),
+            recent: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Wait until this request's slot comes up
+    pub async fn acquire(&self) {
+        if self.max_requests_per_second > 0 {
+            let slot = {
+                let mut next_slot = self.next_slot.lock().await;
+                let slot = (*next_slot).max(Instant::now());
+                *next_slot = slot + self.interval;
+                slot
+            };
+
+            let delay = slot.saturating_duration_since(Instant::now());
+            if !delay.is_zero() {
+                debug!("[RPC] ⏳ Scheduler delaying request by {:?}", delay);
+                tokio::time::sleep_until(slot).await;
+            }
+        }
+
+        let now = Instant::now();
+        let mut recent = self.recent.lock().await;
+        recent.push_back(now);
+        Self::prune(&mut recent, now);
+    }
+
+    /// Requests sent during the last second
+    pub async fn current_rate(&self) -> usize {
+        let mut recent = self.recent.lock().await;
+        Self::prune(&mut recent, Instant::now());
+        recent.len()
+    }
+
+    pub fn max_requests_per_second(&self) -> u32 {
+        self.max_requests_per_second
+    }
+
+    fn prune(recent: &mut VecDeque<Instant>, now: Instant) {
+        while recent.front().is_some_and(|sent| now.duration_since(*sent) >= RATE_WINDOW) {
+            recent.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_burst_is_capped_to_configured_rate() {
+        let start = Instant::now();
+        let scheduler = Arc::new(RpcScheduler::new(20));
+
+        // Fire a burst of 40 requests at once
+        let handles: Vec<_> = (0..40)
+            .map(|_| {
+                let scheduler = scheduler.clone();
+                tokio::spawn(async move {
+                    scheduler.acquire().await;
+                    Instant::now()
+                })
+            })
+            .collect();
+
+        let mut sent_at = Vec::new();
+        for handle in handles {
+            sent_at.push(handle.await.unwrap());
+        }
+
+        // 40 requests at 20/s need at least 39 intervals of 50ms
+        assert!(start.elapsed() >= Duration::from_millis(39 * 50));
+
+        // The first second never carries more than the limit
+        let first_second = sent_at
+            .iter()
+            .filter(|sent| sent.duration_since(start) < RATE_WINDOW)
+            .count();
+        assert!(first_second <= 20, "{} requests within the first second", first_second);
+    }
+
+    #[tokio::test]
+    async fn test_current_rate_counts_recent_requests() {
+        let scheduler = RpcScheduler::new(0);
+
+        for _ in 0..5 {
+            scheduler.acquire().await;
+        }
+
+        assert_eq!(scheduler.current_rate().await, 5);
+        assert_eq!(scheduler.max_requests_per_second(), 0);
+    }
+}
@@ -8,7 +8,7 @@
 // - Preview transaction results
 
 use serde::{Deserialize, Serialize};
-use soroban_client::xdr::{ScVal, Limits, ReadXdr, SorobanAuthorizationEntry};
+use soroban_client::xdr::{ScVal, Limits, ReadXdr, SorobanAuthorizationEntry, SorobanTransactionData};
 
 /// Configuration for how resources will be calculated when simulating transactions
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -31,6 +31,25 @@ pub enum AuthMode {
     Record,
 }
 
+impl AuthMode {
+    /// Parse an auth mode name ("enforce" / "record", case-insensitive)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "enforce" => Some(AuthMode::Enforce),
+            "record" => Some(AuthMode::Record),
+            _ => None,
+        }
+    }
+}
+
+impl SimulationOptions {
+    /// Use `default` as the auth mode unless the caller picked one explicitly
+    pub fn with_default_auth_mode(mut self, default: AuthMode) -> Self {
+        self.auth_mode.get_or_insert(default);
+        self
+    }
+}
+
 impl From<AuthMode> for &str {
     fn from(mode: AuthMode) -> Self {
         match mode {
@@ -93,7 +112,7 @@ impl SimulateTransactionResponse {
                 .filter_map(|e| SorobanAuthorizationEntry::from_xdr_base64(e, Limits::none()).ok())
                 .collect();
 
-            let ret_val = ScVal::from_xdr_base64(&results[0].xdr, Limits::none()).ok()?;
+            let ret_val = ScVal::from_xdr_base64(&results[0].xdr, super::client::scval_read_limits()).ok()?;
 
             Some((ret_val, auth))
         } else {
@@ -111,12 +130,27 @@ impl SimulateTransactionResponse {
         self.restore_preamble.is_some()
     }
 
+    /// Resource fee (stroops) of the restore transaction, if one is needed
+    pub fn restoration_fee(&self) -> Option<u64> {
+        self.restore_preamble
+            .as_ref()
+            .and_then(|preamble| preamble.min_resource_fee.parse::<u64>().ok())
+    }
+
     /// Get the estimated minimum resource fee
     pub fn get_min_resource_fee(&self) -> Option<u64> {
         self.min_resource_fee
             .as_ref()
             .and_then(|fee| fee.parse::<u64>().ok())
     }
+
+    /// Get the CPU instructions budgeted in the recommended transaction data
+    pub fn get_cpu_instructions(&self) -> Option<u64> {
+        self.transaction_data
+            .as_ref()
+            .and_then(|data| SorobanTransactionData::from_xdr_base64(data, Limits::none()).ok())
+            .map(|data| data.resources.instructions as u64)
+    }
 }
 
 /// Raw result from host function simulation
@@ -184,6 +218,26 @@ mod tests {
         assert_eq!(record, "record");
     }
 
+    #[test]
+    fn test_default_auth_mode_applied() {
+        let options = SimulationOptions::default().with_default_auth_mode(AuthMode::Record);
+        assert_eq!(options.auth_mode, Some(AuthMode::Record));
+
+        // An explicit choice is never overridden
+        let explicit = SimulationOptions {
+            cpu_instructions: 0,
+            auth_mode: Some(AuthMode::Enforce),
+        };
+        assert_eq!(explicit.with_default_auth_mode(AuthMode::Record).auth_mode, Some(AuthMode::Enforce));
+    }
+
+    #[test]
+    fn test_auth_mode_parse() {
+        assert_eq!(AuthMode::parse("Record"), Some(AuthMode::Record));
+        assert_eq!(AuthMode::parse(" enforce "), Some(AuthMode::Enforce));
+        assert_eq!(AuthMode::parse("strict"), None);
+    }
+
     #[test]
     fn test_simulation_response_success() {
         let response = SimulateTransactionResponse {
@@ -237,5 +291,6 @@ mod tests {
 
         assert!(response.is_success());
         assert!(response.needs_restoration());
+        assert_eq!(response.restoration_fee(), Some(200));
     }
 }
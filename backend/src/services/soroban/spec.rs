@@ -0,0 +1,262 @@
+// Contract spec introspection for Soroban contracts
+//
+// Soroban contracts embed their interface in a `contractspecv0` custom section
+// of the deployed Wasm. Reading it lets us:
+// - Validate function names before simulating
+// - Check argument counts against the declared inputs
+// - List the functions a contract exposes
+
+use serde::{Deserialize, Serialize};
+use soroban_client::xdr::{Limited, Limits, ReadXdr, ScSpecEntry, ScSpecTypeDef};
+
+use crate::error::{AppError, Result};
+
+/// Name of the Wasm custom section holding the contract spec
+pub const SPEC_SECTION_NAME: &str = "contractspecv0";
+
+/// A single function input as declared in the contract spec
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionInputSpec {
+    pub name: String,
+    pub type_name: String,
+}
+
+/// A function exported by a contract
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionSpec {
+    pub name: String,
+    pub inputs: Vec<FunctionInputSpec>,
+    pub outputs: Vec<String>,
+}
+
+impl FunctionSpec {
+    /// Human-readable signature, e.g. `lastprice(asset: Asset)`
+    pub fn signature(&self) -> String {
+        let inputs = self.inputs
+            .iter()
+            .map(|i| format!("{}: {}", i.name, i.type_name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{}({})", self.name, inputs)
+    }
+}
+
+/// Contract interface decoded from the Wasm spec section
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractSpec {
+    pub functions: Vec<FunctionSpec>,
+}
+
+impl ContractSpec {
+    /// Decode the spec from a contract's Wasm bytecode
+    ///
+    /// Returns an empty spec if the module has no `contractspecv0` section.
+    pub fn from_wasm(wasm: &[u8]) -> Result<Self> {
+        match find_custom_section(wasm, SPEC_SECTION_NAME)? {
+            Some(section) => Self::from_spec_xdr(section),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Decode a stream of XDR-encoded `ScSpecEntry` values
+    pub fn from_spec_xdr(bytes: &[u8]) -> Result<Self> {
+        let mut cursor = std::io::Cursor::new(bytes);
+        let mut functions = Vec::new();
+
+        while (cursor.position() as usize) < bytes.len() {
+            let mut limited = Limited::new(&mut cursor, Limits::none());
+            let entry = ScSpecEntry::read_xdr(&mut limited)
+                .map_err(|e| AppError::XdrDecoding(format!("Failed to decode contract spec: {}", e)))?;
+
+            if let ScSpecEntry::FunctionV0(function) = entry {
+                functions.push(FunctionSpec {
+                    name: function.name.0.to_utf8_string_lossy(),
+                    inputs: function.inputs
+                        .iter()
+                        .map(|input| FunctionInputSpec {
+                            name: input.name.to_utf8_string_lossy(),
+                            type_name: type_name(&input.type_),
+                        })
+                        .collect(),
+                    outputs: function.outputs.iter().map(type_name).collect(),
+                });
+            }
+        }
+
+        Ok(Self { functions })
+    }
+
+    /// Look up a function by name
+    pub fn function(&self, name: &str) -> Option<&FunctionSpec> {
+        self.functions.iter().find(|f| f.name == name)
+    }
+
+    /// Names of all exported functions, sorted
+    pub fn function_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.functions.iter().map(|f| f.name.clone()).collect();
+        names.sort();
+        names
+    }
+
+    /// Check that `function_name` exists and accepts `arg_count` arguments
+    pub fn validate_call(&self, function_name: &str, arg_count: usize) -> Result<()> {
+        let function = self.function(function_name).ok_or_else(|| {
+            AppError::FunctionNotFound(format!(
+                "'{}' is not exported by this contract. Available functions: {}",
+                function_name,
+                self.function_names().join(", ")
+            ))
+        })?;
+
+        if function.inputs.len() != arg_count {
+            return Err(AppError::InvalidInput(format!(
+                "{} expects {} argument(s), got {}",
+                function.signature(),
+                function.inputs.len(),
+                arg_count
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Short type name for a spec type definition
+fn type_name(type_def: &ScSpecTypeDef) -> String {
+    match type_def {
+        ScSpecTypeDef::Udt(udt) => udt.name.to_utf8_string_lossy(),
+        other => other.name().to_lowercase(),
+    }
+}
+
+/// Find a custom section by name in a Wasm module
+fn find_custom_section<'a>(wasm: &'a [u8], name: &str) -> Result<Option<&'a [u8]>> {
+    const WASM_HEADER: [u8; 8] = [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+
+    if wasm.len() < WASM_HEADER.len() || wasm[..WASM_HEADER.len()] != WASM_HEADER {
+        return Err(AppError::XdrDecoding("Contract code is not a Wasm module".to_string()));
+    }
+
+    let mut offset = WASM_HEADER.len();
+    while offset < wasm.len() {
+        let section_id = wasm[offset];
+        offset += 1;
+
+        let section_len = read_leb128(wasm, &mut offset)? as usize;
+        let section_end = offset
+            .checked_add(section_len)
+            .filter(|end| *end <= wasm.len())
+            .ok_or_else(|| AppError::XdrDecoding("Truncated Wasm section".to_string()))?;
+
+        if section_id == 0 {
+            let mut name_offset = offset;
+            let name_len = read_leb128(wasm, &mut name_offset)? as usize;
+            let name_end = name_offset + name_len;
+            if name_end <= section_end && &wasm[name_offset..name_end] == name.as_bytes() {
+                return Ok(Some(&wasm[name_end..section_end]));
+            }
+        }
+
+        offset = section_end;
+    }
+
+    Ok(None)
+}
+
+/// Read an unsigned LEB128 value, advancing `offset`
+fn read_leb128(bytes: &[u8], offset: &mut usize) -> Result<u32> {
+    let mut result: u32 = 0;
+    let mut shift = 0;
+
+    loop {
+        let byte = *bytes
+            .get(*offset)
+            .ok_or_else(|| AppError::XdrDecoding("Truncated LEB128 value".to_string()))?;
+        *offset += 1;
+
+        result |= ((byte & 0x7f) as u32) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+
+        shift += 7;
+        if shift >= 32 {
+            return Err(AppError::XdrDecoding("LEB128 value too large".to_string()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_spec() -> ContractSpec {
+        ContractSpec {
+            functions: vec![
+                FunctionSpec {
+                    name: "hello".to_string(),
+                    inputs: vec![FunctionInputSpec {
+                        name: "to".to_string(),
+                        type_name: "symbol".to_string(),
+                    }],
+                    outputs: vec!["vec".to_string()],
+                },
+                FunctionSpec {
+                    name: "decimals".to_string(),
+                    inputs: vec![],
+                    outputs: vec!["u32".to_string()],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_validate_call_known_function() {
+        let spec = sample_spec();
+        assert!(spec.validate_call("hello", 1).is_ok());
+        assert!(spec.validate_call("decimals", 0).is_ok());
+    }
+
+    #[test]
+    fn test_validate_call_unknown_function() {
+        let spec = sample_spec();
+        let err = spec.validate_call("not_a_function", 0).unwrap_err();
+
+        assert_eq!(err.error_type(), "FUNCTION_NOT_FOUND");
+        assert_eq!(err.status_code(), axum::http::StatusCode::BAD_REQUEST);
+        let message = err.to_string();
+        assert!(message.contains("not_a_function"));
+        assert!(message.contains("decimals, hello"));
+    }
+
+    #[test]
+    fn test_validate_call_wrong_arity() {
+        let spec = sample_spec();
+        let err = spec.validate_call("hello", 2).unwrap_err();
+
+        assert_eq!(err.error_type(), "INVALID_INPUT");
+        assert!(err.to_string().contains("hello(to: symbol) expects 1 argument(s), got 2"));
+    }
+
+    #[test]
+    fn test_find_custom_section() {
+        let mut wasm = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+        // Custom section: id 0, size, name length, name, payload
+        let name = SPEC_SECTION_NAME.as_bytes();
+        let payload = [1u8, 2, 3];
+        wasm.push(0);
+        wasm.push((1 + name.len() + payload.len()) as u8);
+        wasm.push(name.len() as u8);
+        wasm.extend_from_slice(name);
+        wasm.extend_from_slice(&payload);
+
+        let section = find_custom_section(&wasm, SPEC_SECTION_NAME).unwrap();
+        assert_eq!(section, Some(&payload[..]));
+        assert_eq!(find_custom_section(&wasm, "other").unwrap(), None);
+    }
+
+    #[test]
+    fn test_from_wasm_rejects_non_wasm() {
+        assert!(ContractSpec::from_wasm(b"not wasm").is_err());
+    }
+}
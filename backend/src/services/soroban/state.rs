@@ -97,6 +97,15 @@ impl LedgerEntryResult {
     }
 }
 
+/// A contract storage entry and the ledger it was read at
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContractDataRead {
+    pub entry: LedgerEntryResult,
+
+    /// Latest ledger sequence when the entry was read, for its remaining TTL
+    pub latest_ledger: u32,
+}
+
 /// Response from getLedgerEntries RPC call
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -115,6 +124,15 @@ impl GetLedgerEntriesResponse {
         self.entries.as_ref().and_then(|e| e.first())
     }
 
+    /// Combine the responses of several calls; the latest ledger is the newest either saw
+    pub fn merge(mut self, other: Self) -> Self {
+        self.latest_ledger = self.latest_ledger.max(other.latest_ledger);
+        if let Some(entries) = other.entries {
+            self.entries.get_or_insert_with(Vec::new).extend(entries);
+        }
+        self
+    }
+
     /// Get number of entries found
     pub fn entry_count(&self) -> usize {
         self.entries.as_ref().map(|e| e.len()).unwrap_or(0)
@@ -126,6 +144,76 @@ impl GetLedgerEntriesResponse {
     }
 }
 
+/// One storage entry in a TTL report
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TtlReportEntry {
+    /// Storage key as requested (base64 XDR encoded ScVal)
+    pub key: String,
+
+    /// Storage durability the key was looked up with
+    pub durability: Durability,
+
+    /// Whether the RPC returned the entry at all
+    pub found: bool,
+
+    /// Ledger sequence when the entry expires
+    pub live_until_ledger_seq: Option<u32>,
+
+    /// Ledgers left before the entry is archived (negative once expired)
+    pub ledgers_until_expiry: Option<i64>,
+
+    /// Entry is past its TTL
+    pub expired: bool,
+}
+
+/// TTL status of a set of contract storage entries, most at-risk first
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TtlReport {
+    /// Ledger the TTLs were computed against
+    pub latest_ledger: u32,
+
+    pub entries: Vec<TtlReportEntry>,
+}
+
+impl TtlReport {
+    /// Match RPC results back to the requested keys and sort by remaining TTL
+    ///
+    /// `requested` holds `(storage key, durability, ledger key XDR)` for each key,
+    /// where the ledger key is what was sent to `getLedgerEntries`. Entries the
+    /// RPC did not return are reported as not found and sorted last.
+    pub fn build(requested: Vec<(String, Durability, String)>, response: &GetLedgerEntriesResponse) -> Self {
+        let latest_ledger = response.latest_ledger;
+        let found: std::collections::HashMap<&str, &LedgerEntryResult> = response
+            .entries
+            .iter()
+            .flatten()
+            .map(|entry| (entry.key.as_str(), entry))
+            .collect();
+
+        let mut entries: Vec<TtlReportEntry> = requested
+            .into_iter()
+            .map(|(key, durability, ledger_key)| {
+                let entry = found.get(ledger_key.as_str());
+                let live_until_ledger_seq = entry.and_then(|e| e.live_until_ledger_seq);
+
+                TtlReportEntry {
+                    key,
+                    durability,
+                    found: entry.is_some(),
+                    live_until_ledger_seq,
+                    ledgers_until_expiry: live_until_ledger_seq
+                        .map(|live_until| live_until as i64 - latest_ledger as i64),
+                    expired: entry.is_some_and(|e| e.is_expired(latest_ledger)),
+                }
+            })
+            .collect();
+
+        entries.sort_by_key(|entry| (entry.ledgers_until_expiry.is_none(), entry.ledgers_until_expiry));
+
+        Self { latest_ledger, entries }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,4 +302,42 @@ mod tests {
         assert!(!response.has_entries());
         assert!(response.first_entry().is_none());
     }
+
+    #[test]
+    fn test_ttl_report_sorted_by_remaining_ttl() {
+        let entry = |key: &str, live_until: Option<u32>| LedgerEntryResult {
+            last_modified_ledger_seq: Some(100),
+            live_until_ledger_seq: live_until,
+            key: key.to_string(),
+            xdr: "data".to_string(),
+            ext_xdr: None,
+        };
+        let response = GetLedgerEntriesResponse {
+            entries: Some(vec![
+                entry("lk_far", Some(5_000)),
+                entry("lk_soon", Some(1_010)),
+                entry("lk_expired", Some(990)),
+            ]),
+            latest_ledger: 1_000,
+        };
+        let requested = ["far", "missing", "soon", "expired"]
+            .into_iter()
+            .map(|k| (k.to_string(), Durability::Persistent, format!("lk_{}", k)))
+            .collect();
+
+        let report = TtlReport::build(requested, &response);
+        let keys: Vec<&str> = report.entries.iter().map(|e| e.key.as_str()).collect();
+
+        assert_eq!(report.latest_ledger, 1_000);
+        assert_eq!(keys, vec!["expired", "soon", "far", "missing"]);
+
+        assert!(report.entries[0].expired);
+        assert_eq!(report.entries[0].ledgers_until_expiry, Some(-10));
+        assert!(!report.entries[1].expired);
+        assert_eq!(report.entries[1].ledgers_until_expiry, Some(10));
+        assert_eq!(report.entries[2].ledgers_until_expiry, Some(4_000));
+        assert!(!report.entries[3].found);
+        assert!(!report.entries[3].expired);
+        assert_eq!(report.entries[3].ledgers_until_expiry, None);
+    }
 }
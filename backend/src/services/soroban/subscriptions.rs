@@ -0,0 +1,140 @@
+// Cursor persistence for event subscriptions
+//
+// Clients polling or streaming events identify themselves with a subscription id.
+// The last cursor delivered to each subscription is remembered (with a TTL), so a
+// client that reconnects with the same id resumes exactly where it left off
+// instead of re-reading from its original start ledger or skipping ahead.
+
+use std::time::Duration;
+use tracing::debug;
+
+use super::cache::ContractCache;
+use super::events::Pagination;
+
+/// How long an idle subscription keeps its cursor
+const DEFAULT_SUBSCRIPTION_TTL: Duration = Duration::from_secs(3600);
+
+/// Last-delivered cursor per (contract, subscription id)
+pub struct SubscriptionCursors {
+    cursors: ContractCache<String>,
+}
+
+impl SubscriptionCursors {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            cursors: ContractCache::new(ttl),
+        }
+    }
+
+    /// Pagination to use for a request from this subscription
+    ///
+    /// An explicit cursor from the client always wins; otherwise a stored cursor
+    /// replaces the requested start ledger.
+    pub async fn resume(&self, contract_id: &str, subscription_id: &str, requested: Pagination) -> Pagination {
+        if let Pagination::Cursor(_) = requested {
+            return requested;
+        }
+
+        match self.cursors.get(&Self::key(contract_id, subscription_id)).await {
+            Some(cursor) => {
+                debug!("[SUBSCRIPTIONS] Resuming subscription {} from cursor {}", subscription_id, cursor);
+                Pagination::Cursor(cursor)
+            }
+            None => requested,
+        }
+    }
+
+    /// Remember the cursor delivered to this subscription
+    pub async fn record(&self, contract_id: &str, subscription_id: &str, cursor: String) {
+        self.cursors
+            .set(Self::key(contract_id, subscription_id), cursor, None)
+            .await;
+    }
+
+    /// Drop cursors for subscriptions that have been idle past the TTL
+    pub async fn cleanup_expired(&self) -> usize {
+        self.cursors.cleanup_expired().await
+    }
+
+    fn key(contract_id: &str, subscription_id: &str) -> String {
+        format!("{}:{}", contract_id, subscription_id)
+    }
+}
+
+impl Default for SubscriptionCursors {
+    fn default() -> Self {
+        Self::new(DEFAULT_SUBSCRIPTION_TTL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTRACT: &str = "CCYOZJCOPG34LLQQ7N24YXBM7LL62R7ONMZ3G6WZAAYPB5OYKOMJRN63";
+
+    /// Fake event source: events 1..=10, pages of 3, cursor = last delivered id
+    fn fetch_page(pagination: &Pagination) -> (Vec<u32>, String) {
+        let after = match pagination {
+            Pagination::From(_) | Pagination::FromTo(_, _) => 0,
+            Pagination::Cursor(cursor) => cursor.parse().unwrap(),
+        };
+        let events: Vec<u32> = (after + 1..=10).take(3).collect();
+        let cursor = events.last().copied().unwrap_or(after).to_string();
+        (events, cursor)
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_resumes_without_gap_or_duplicate() {
+        let cursors = SubscriptionCursors::default();
+        let mut received = Vec::new();
+
+        // First connection reads two pages, then disconnects
+        for _ in 0..2 {
+            let pagination = cursors.resume(CONTRACT, "dashboard-1", Pagination::From(100)).await;
+            let (events, cursor) = fetch_page(&pagination);
+            received.extend(events);
+            cursors.record(CONTRACT, "dashboard-1", cursor).await;
+        }
+
+        // Reconnect sends its original start ledger again, plus the subscription id
+        loop {
+            let pagination = cursors.resume(CONTRACT, "dashboard-1", Pagination::From(100)).await;
+            let (events, cursor) = fetch_page(&pagination);
+            if events.is_empty() {
+                break;
+            }
+            received.extend(events);
+            cursors.record(CONTRACT, "dashboard-1", cursor).await;
+        }
+
+        assert_eq!(received, (1..=10).collect::<Vec<u32>>());
+    }
+
+    #[tokio::test]
+    async fn test_subscriptions_are_isolated() {
+        let cursors = SubscriptionCursors::default();
+        cursors.record(CONTRACT, "a", "42".to_string()).await;
+
+        match cursors.resume(CONTRACT, "b", Pagination::From(7)).await {
+            Pagination::From(ledger) => assert_eq!(ledger, 7),
+            other => panic!("Unexpected pagination: {:?}", other),
+        }
+
+        // Explicit cursor from the client is never overridden
+        match cursors.resume(CONTRACT, "a", Pagination::Cursor("5".to_string())).await {
+            Pagination::Cursor(cursor) => assert_eq!(cursor, "5"),
+            other => panic!("Unexpected pagination: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cursor_expires() {
+        let cursors = SubscriptionCursors::new(Duration::from_millis(10));
+        cursors.record(CONTRACT, "a", "42".to_string()).await;
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        assert!(matches!(cursors.resume(CONTRACT, "a", Pagination::From(1)).await, Pagination::From(1)));
+        assert_eq!(cursors.cleanup_expired().await, 1);
+    }
+}
@@ -0,0 +1,104 @@
+// Token contract reads (SEP-41) built on the generic contract-call path
+//
+// Callers just name an account; this module knows to call `balance(Address)`
+// and, when asked, `decimals()` to scale the raw amount.
+
+use soroban_client::xdr::{ReadXdr, ScVal};
+use tracing::info;
+
+use crate::error::{AppError, Result};
+use crate::utils::truncate_address;
+use shared::dto::soroban::{CallCacheMode, CallContractFunctionResponse, FunctionParameter};
+
+use super::client::{scval_integer, scval_read_limits};
+use super::manager::ScalableContractManager;
+use super::simulation::SimulationOptions;
+
+/// A token balance in the token's smallest unit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenBalance {
+    pub balance: i128,
+    /// The token's `decimals()`, when scaling was requested
+    pub decimals: Option<u32>,
+}
+
+/// Read `account`'s balance of the token at `contract_id`
+///
+/// Balances change every ledger, so calls use ledger-keyed caching. Contracts
+/// without a SEP-41 `balance`/`decimals` are reported as not being tokens.
+pub async fn token_balance(
+    manager: &ScalableContractManager,
+    contract_id: &str,
+    account: &str,
+    scaled: bool,
+    options: SimulationOptions,
+) -> Result<TokenBalance> {
+    info!("[TOKEN] Reading balance of {} on {}", truncate_address(account), contract_id);
+
+    let parameters = vec![FunctionParameter::Address(account.to_string())];
+    let balance = call_token_function(manager, contract_id, "balance", parameters, options.clone()).await?;
+
+    let decimals = if scaled {
+        let decimals = call_token_function(manager, contract_id, "decimals", Vec::new(), options).await?;
+        Some(u32::try_from(decimals).map_err(|_| not_a_token(contract_id, "decimals"))?)
+    } else {
+        None
+    };
+
+    Ok(TokenBalance { balance, decimals })
+}
+
+/// Call a token function and decode its integer result
+async fn call_token_function(
+    manager: &ScalableContractManager,
+    contract_id: &str,
+    function_name: &str,
+    parameters: Vec<FunctionParameter>,
+    options: SimulationOptions,
+) -> Result<i128> {
+    let response = manager
+        .call_contract_function(contract_id, function_name, parameters, None, Some(options), CallCacheMode::Ledger)
+        .await?;
+
+    if !response.success {
+        let reason = response.error.unwrap_or_else(|| "Unknown contract error".to_string());
+        if is_missing_function(&reason) {
+            return Err(not_a_token(contract_id, function_name));
+        }
+        return Err(AppError::Contract(reason));
+    }
+
+    integer_result(&response).ok_or_else(|| not_a_token(contract_id, function_name))
+}
+
+/// The call's return value, if it is an integer
+fn integer_result(response: &CallContractFunctionResponse) -> Option<i128> {
+    let result_xdr = response.result_xdr.as_deref()?;
+    let scval = ScVal::from_xdr_base64(result_xdr, scval_read_limits()).ok()?;
+    scval_integer(&scval)
+}
+
+/// Whether a simulation failed because the contract has no such function
+fn is_missing_function(reason: &str) -> bool {
+    let reason = reason.to_lowercase();
+    reason.contains("non-existent contract function") || reason.contains("wasmvm, missingvalue")
+}
+
+fn not_a_token(contract_id: &str, function_name: &str) -> AppError {
+    AppError::Contract(format!(
+        "Contract {} is not a token: it has no SEP-41 {}() returning an integer",
+        contract_id, function_name
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_function_detected() {
+        assert!(is_missing_function("HostError: Error(WasmVm, MissingValue)"));
+        assert!(is_missing_function("trying to invoke non-existent contract function, balance"));
+        assert!(!is_missing_function("HostError: Error(Contract, #10)"));
+    }
+}
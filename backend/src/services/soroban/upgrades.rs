@@ -0,0 +1,112 @@
+// Contract upgrade detection
+//
+// `ContractMetadata.version` is only what we registered; the contract itself can
+// be upgraded on-chain at any time (e.g. Reflector shipping a new Wasm). The
+// tracker remembers the Wasm hash each contract instance points to and flags
+// contracts whose hash changes so operators notice dependency upgrades.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+/// Seconds between on-chain upgrade checks unless `UPGRADE_CHECK_INTERVAL_SECS` says otherwise
+pub const DEFAULT_UPGRADE_CHECK_INTERVAL_SECS: u64 = 600;
+
+/// Interval between upgrade checks from `UPGRADE_CHECK_INTERVAL_SECS`; `None` (0) disables them
+pub fn upgrade_check_interval_from_env() -> Option<Duration> {
+    let secs = std::env::var("UPGRADE_CHECK_INTERVAL_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_UPGRADE_CHECK_INTERVAL_SECS);
+    (secs > 0).then(|| Duration::from_secs(secs))
+}
+
+/// Result of comparing a freshly read Wasm hash with the stored one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HashObservation {
+    /// First time this contract's hash was seen
+    First,
+    /// Hash matches the stored value
+    Unchanged,
+    /// Hash differs from the stored value
+    Changed { previous: String },
+}
+
+/// Known Wasm hash per contract, plus which contracts have been upgraded
+#[derive(Default)]
+pub struct WasmHashTracker {
+    known: RwLock<HashMap<String, String>>,
+    upgraded: RwLock<HashSet<String>>,
+}
+
+impl WasmHashTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the current Wasm hash for a contract and report whether it changed
+    pub async fn observe(&self, contract_id: &str, wasm_hash: &str) -> HashObservation {
+        let mut known = self.known.write().await;
+
+        match known.insert(contract_id.to_string(), wasm_hash.to_string()) {
+            None => {
+                info!("[UPGRADES] Tracking {} at Wasm hash {}", contract_id, wasm_hash);
+                HashObservation::First
+            }
+            Some(previous) if previous == wasm_hash => HashObservation::Unchanged,
+            Some(previous) => {
+                warn!(
+                    "[UPGRADES] ⚠️  Contract {} was upgraded: Wasm hash {} -> {}",
+                    contract_id, previous, wasm_hash
+                );
+                self.upgraded.write().await.insert(contract_id.to_string());
+                HashObservation::Changed { previous }
+            }
+        }
+    }
+
+    /// Whether the contract's Wasm hash changed since it was first tracked
+    pub async fn is_upgraded(&self, contract_id: &str) -> bool {
+        self.upgraded.read().await.contains(contract_id)
+    }
+
+    /// Current known Wasm hash for a contract
+    pub async fn wasm_hash(&self, contract_id: &str) -> Option<String> {
+        self.known.read().await.get(contract_id).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTRACT: &str = "CAVLP5DH2GJPZMVO7IJY4CVOD5MWEFTJFVPD2YY2FQXOQHRGHK4D6HLP";
+
+    #[tokio::test]
+    async fn test_hash_change_flags_upgrade() {
+        let tracker = WasmHashTracker::new();
+
+        assert_eq!(tracker.observe(CONTRACT, "aaaa").await, HashObservation::First);
+        assert_eq!(tracker.observe(CONTRACT, "aaaa").await, HashObservation::Unchanged);
+        assert!(!tracker.is_upgraded(CONTRACT).await);
+
+        assert_eq!(
+            tracker.observe(CONTRACT, "bbbb").await,
+            HashObservation::Changed { previous: "aaaa".to_string() }
+        );
+        assert!(tracker.is_upgraded(CONTRACT).await);
+        assert_eq!(tracker.wasm_hash(CONTRACT).await.as_deref(), Some("bbbb"));
+
+        // Flag sticks once set
+        assert_eq!(tracker.observe(CONTRACT, "bbbb").await, HashObservation::Unchanged);
+        assert!(tracker.is_upgraded(CONTRACT).await);
+    }
+
+    #[tokio::test]
+    async fn test_untracked_contract_not_upgraded() {
+        let tracker = WasmHashTracker::new();
+        assert!(!tracker.is_upgraded(CONTRACT).await);
+        assert!(tracker.wasm_hash(CONTRACT).await.is_none());
+    }
+}
@@ -0,0 +1,93 @@
+// Cache warmup for frequently used contract calls
+//
+// The first dashboard load and first oracle query otherwise pay the full RPC
+// round-trip. When enabled, configured read-only calls are executed once in the
+// background after the manager starts so their results are already cached.
+
+use serde::{Deserialize, Serialize};
+use shared::dto::soroban::FunctionParameter;
+use tracing::warn;
+
+/// A single (contract, function, params) call to pre-populate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarmupCall {
+    pub contract_id: String,
+    pub function_name: String,
+    #[serde(default)]
+    pub parameters: Vec<FunctionParameter>,
+}
+
+/// Startup warmup configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WarmupConfig {
+    /// Whether to run the warmup after the manager initializes
+    pub enabled: bool,
+    /// Calls to execute during warmup
+    pub calls: Vec<WarmupCall>,
+}
+
+impl WarmupConfig {
+    /// Load from `WARMUP_ENABLED` and `WARMUP_CALLS` (a JSON array of calls)
+    ///
+    /// Example:
+    /// `WARMUP_CALLS='[{"contract_id":"CCYO...","function_name":"decimals"}]'`
+    pub fn from_env() -> Self {
+        let enabled = std::env::var("WARMUP_ENABLED")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false);
+
+        let calls = match std::env::var("WARMUP_CALLS") {
+            Ok(raw) => Self::parse_calls(&raw).unwrap_or_else(|e| {
+                warn!("[CONFIG] ⚠️  Ignoring invalid WARMUP_CALLS: {}", e);
+                Vec::new()
+            }),
+            Err(_) => Vec::new(),
+        };
+
+        Self { enabled, calls }
+    }
+
+    /// Parse a JSON array of warmup calls
+    pub fn parse_calls(raw: &str) -> Result<Vec<WarmupCall>, String> {
+        serde_json::from_str(raw).map_err(|e| e.to_string())
+    }
+}
+
+/// Outcome of a warmup run
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WarmupReport {
+    /// Calls whose results are now cached
+    pub warmed: usize,
+    /// Calls that failed or returned an unsuccessful result
+    pub failed: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_calls() {
+        let calls = WarmupConfig::parse_calls(
+            r#"[
+                {"contract_id": "CCYOZJCOPG34LLQQ7N24YXBM7LL62R7ONMZ3G6WZAAYPB5OYKOMJRN63", "function_name": "decimals"},
+                {"contract_id": "CCYOZJCOPG34LLQQ7N24YXBM7LL62R7ONMZ3G6WZAAYPB5OYKOMJRN63", "function_name": "lastprice",
+                 "parameters": [{"type": "enum", "value": ["Other", {"type": "symbol", "value": "EUR"}]}]}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].function_name, "decimals");
+        assert!(calls[0].parameters.is_empty());
+        assert_eq!(calls[1].parameters.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_calls_rejects_invalid() {
+        assert!(WarmupConfig::parse_calls("not json").is_err());
+        assert!(WarmupConfig::default().calls.is_empty());
+        assert!(!WarmupConfig::default().enabled);
+    }
+}
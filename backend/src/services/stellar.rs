@@ -1,20 +1,35 @@
 use soroban_client::{
-    Server, Options,
+    Server,
     transaction::{Account, TransactionBuilder, AccountBehavior, TransactionBuilderBehavior, TransactionBehavior},
     contract::{Contracts, ContractBehavior},
-    xdr::{Limits, WriteXdr, ReadXdr, TransactionEnvelope},
+    keypair::{Keypair, KeypairBehavior},
+    xdr::{
+        Limits, WriteXdr, ReadXdr, TransactionEnvelope, Transaction, Hash,
+        TransactionSignaturePayload, TransactionSignaturePayloadTaggedTransaction,
+        TransactionExt, SorobanTransactionData, LedgerKey, ContractDataDurability, AccountId, PublicKey,
+        Operation, OperationBody, PaymentOp, Asset, AlphaNum4, AlphaNum12, AssetCode4, AssetCode12,
+        MuxedAccount, Uint256, TimeBounds, TimePoint, Preconditions, HostFunction,
+    },
+    address::{Address, AddressTrait},
 };
+use sha2::{Digest, Sha256};
 use std::{cell::RefCell, rc::Rc};
 use tracing::{info, debug, error};
 
 use crate::error::{AppError, Result};
-use crate::types::ContractFunction;
+use crate::services::soroban::client::rpc_options;
+use crate::services::soroban::CostLimits;
+use crate::types::{ContractFunction, DecodedOperation, FeeEstimate, FootprintSummary, LedgerKeySummary};
+use shared::amount::{format_amount, STELLAR_DECIMALS};
+use shared::dto::payment::NATIVE_ASSET;
 
 #[derive(Debug, Clone)]
 pub struct XdrConfig {
     pub contract_id: String,
     pub network_passphrase: String,
     pub rpc_url: String,
+    /// Seconds a generated transaction stays valid (its `maxTime`)
+    pub tx_timeout_secs: u64,
 }
 
 impl Default for XdrConfig {
@@ -23,6 +38,7 @@ impl Default for XdrConfig {
             contract_id: "CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF".to_string(),
             network_passphrase: "Test SDF Network ; September 2015".to_string(),
             rpc_url: "https://soroban-testnet.stellar.org".to_string(),
+            tx_timeout_secs: DEFAULT_TX_TIMEOUT_SECS,
         }
     }
 }
@@ -45,13 +61,106 @@ impl XdrConfig {
             return Err(AppError::Config("RPC URL cannot be empty".to_string()));
         }
 
+        validate_tx_timeout(self.tx_timeout_secs)?;
+
         Ok(())
     }
 }
 
-pub async fn generate_hello_yew_xdr(config: &XdrConfig, source_account: &str, function: &ContractFunction) -> Result<String> {
+/// Reject timeouts that would make transactions expire before a wallet can sign,
+/// or linger long enough to be replayed much later
+pub fn validate_tx_timeout(tx_timeout_secs: u64) -> Result<()> {
+    if !(MIN_TX_TIMEOUT_SECS..=MAX_TX_TIMEOUT_SECS).contains(&tx_timeout_secs) {
+        return Err(AppError::Config(format!(
+            "TX_TIMEOUT_SECS must be between {} and {}, got: {}",
+            MIN_TX_TIMEOUT_SECS, MAX_TX_TIMEOUT_SECS, tx_timeout_secs
+        )));
+    }
+
+    Ok(())
+}
+
+/// How long a generated transaction stays valid for signing and submission
+pub const DEFAULT_TX_TIMEOUT_SECS: u64 = 300;
+/// Shortest allowed timeout; anything less rarely survives a wallet prompt
+pub const MIN_TX_TIMEOUT_SECS: u64 = 30;
+/// Longest allowed timeout
+pub const MAX_TX_TIMEOUT_SECS: u64 = 3600;
+
+/// A prepared, unsigned transaction and what the wallet is being asked to sign
+#[derive(Debug, Clone)]
+pub struct GeneratedTransaction {
+    pub xdr: String,
+    /// The envelope's `maxTime`; the network rejects it after this
+    pub valid_until: Option<chrono::DateTime<chrono::Utc>>,
+    pub operation: Option<DecodedOperation>,
+}
+
+pub async fn generate_hello_yew_xdr(config: &XdrConfig, source_account: &str, function: &ContractFunction, cost_limits: &CostLimits) -> Result<String> {
+    generate_hello_yew_transaction(config, source_account, function, cost_limits)
+        .await
+        .map(|generated| generated.xdr)
+}
+
+/// Prepare the contract call and return the envelope with its expiry and decoded operation
+///
+/// Refuses to return a transaction whose prepared budget exceeds `cost_limits`.
+pub async fn generate_hello_yew_transaction(config: &XdrConfig, source_account: &str, function: &ContractFunction, cost_limits: &CostLimits) -> Result<GeneratedTransaction> {
     debug!("generate_hello_yew_xdr called with contract_id={}, source_account={}, function={}", config.contract_id, source_account, function.name());
 
+    let envelope = prepare_hello_yew_envelope(config, source_account, function).await?;
+    cost_limits.check_envelope(&envelope)?;
+
+    info!("Encoding to base64 XDR");
+    let tx_envelope_xdr = envelope.to_xdr_base64(Limits::none())
+        .map_err(|e| {
+            error!("XDR encoding failed: {:?}", e);
+            AppError::XdrEncoding(format!("Failed to encode XDR to base64: {:?}", e))
+        })?;
+    debug!("XDR encoding completed successfully");
+
+    info!("Successfully generated XDR envelope");
+    info!("XDR length: {} characters", tx_envelope_xdr.len());
+    debug!("Generated XDR: {}", tx_envelope_xdr);
+    info!("Ready to send to Freighter wallet for signing");
+
+    let (valid_until, operation) = match &envelope {
+        TransactionEnvelope::Tx(v1) => (valid_until(&v1.tx), decode_operation(&v1.tx)),
+        _ => (None, None),
+    };
+
+    Ok(GeneratedTransaction {
+        xdr: tx_envelope_xdr,
+        valid_until,
+        operation,
+    })
+}
+
+/// Prepare the transaction and return only its fee and decoded footprint
+///
+/// Stops before encoding the envelope, for callers that just want to show a fee.
+pub async fn estimate_hello_yew_fee(config: &XdrConfig, source_account: &str, function: &ContractFunction) -> Result<FeeEstimate> {
+    debug!("estimate_hello_yew_fee called with contract_id={}, source_account={}, function={}", config.contract_id, source_account, function.name());
+
+    let envelope = prepare_hello_yew_envelope(config, source_account, function).await?;
+
+    let tx = match &envelope {
+        TransactionEnvelope::Tx(v1) => &v1.tx,
+        _ => return Err(AppError::XdrEncoding("Prepared transaction is not a v1 envelope".to_string())),
+    };
+
+    let soroban_data = match &tx.ext {
+        TransactionExt::V1(data) => Some(data),
+        _ => None,
+    };
+
+    let estimate = fee_estimate(tx.fee, soroban_data);
+    info!("Estimated fee: {} stroops (resource fee: {})", estimate.fee, estimate.resource_fee);
+    Ok(estimate)
+}
+
+/// Build, simulate and prepare the contract call, returning the unsigned envelope
+async fn prepare_hello_yew_envelope(config: &XdrConfig, source_account: &str, function: &ContractFunction) -> Result<TransactionEnvelope> {
     config.validate()?;
     debug!("Config validation passed");
 
@@ -59,10 +168,10 @@ pub async fn generate_hello_yew_xdr(config: &XdrConfig, source_account: &str, fu
     info!("Using RPC: {}", config.rpc_url);
     info!("Network: {}", config.network_passphrase);
 
-    let rpc = Server::new(&config.rpc_url, Options::default())
+    let rpc = Server::new(&config.rpc_url, rpc_options(&config.rpc_url))
         .map_err(|e| AppError::StellarRpc(format!("Failed to connect to Soroban RPC: {:?}", e)))?;
 
-    info!("Fetching account info for: {}", source_account);
+    info!("Fetching account info for: {}", crate::utils::truncate_address(source_account));
     let account_response = rpc.get_account(source_account).await
         .map_err(|e| AppError::Account(format!("Failed to get account info: {:?}", e)))?;
 
@@ -77,7 +186,7 @@ pub async fn generate_hello_yew_xdr(config: &XdrConfig, source_account: &str, fu
         let mut tx_builder = TransactionBuilder::new(
             account_rc,
             &config.network_passphrase,
-            None
+            Some(time_bounds(config.tx_timeout_secs))
         );
 
         debug!("Setting fee: 1,000,000 stroops");
@@ -135,20 +244,110 @@ pub async fn generate_hello_yew_xdr(config: &XdrConfig, source_account: &str, fu
         })?;
     debug!("Transaction envelope created successfully");
 
-    info!("Encoding to base64 XDR");
-    let tx_envelope_xdr = envelope.to_xdr_base64(Limits::none())
-        .map_err(|e| {
-            error!("XDR encoding failed: {:?}", e);
-            AppError::XdrEncoding(format!("Failed to encode XDR to base64: {:?}", e))
-        })?;
-    debug!("XDR encoding completed successfully");
+    Ok(envelope)
+}
 
-    info!("Successfully generated XDR envelope");
-    info!("XDR length: {} characters", tx_envelope_xdr.len());
-    debug!("Generated XDR: {}", tx_envelope_xdr);
-    info!("Ready to send to Freighter wallet for signing");
+/// Time bounds valid from now until `timeout_secs` from now
+pub(crate) fn time_bounds(timeout_secs: u64) -> TimeBounds {
+    let now = chrono::Utc::now().timestamp().max(0) as u64;
+    TimeBounds {
+        min_time: TimePoint(0),
+        max_time: TimePoint(now + timeout_secs),
+    }
+}
+
+/// When the transaction expires, or `None` if it has no upper time bound
+fn valid_until(tx: &Transaction) -> Option<chrono::DateTime<chrono::Utc>> {
+    let bounds = match &tx.cond {
+        Preconditions::Time(bounds) => bounds,
+        Preconditions::V2(conditions) => conditions.time_bounds.as_ref()?,
+        Preconditions::None => return None,
+    };
+
+    // maxTime 0 means "no expiry"
+    let max_time = i64::try_from(bounds.max_time.0).ok().filter(|t| *t > 0)?;
+    chrono::DateTime::from_timestamp(max_time, 0)
+}
 
-    Ok(tx_envelope_xdr)
+/// Decode the transaction's first operation for display before signing
+pub(crate) fn decode_operation(tx: &Transaction) -> Option<DecodedOperation> {
+    let operation = tx.operations.first()?;
+    let mut decoded = DecodedOperation {
+        kind: operation.body.name().to_string(),
+        contract_id: None,
+        function: None,
+        args: Vec::new(),
+    };
+
+    if let OperationBody::InvokeHostFunction(op) = &operation.body {
+        if let HostFunction::InvokeContract(call) = &op.host_function {
+            decoded.contract_id = Address::from_sc_address(&call.contract_address).ok().map(|a| a.to_string());
+            decoded.function = Some(call.function_name.0.to_utf8_string_lossy());
+            decoded.args = call.args.iter().map(crate::services::soroban::client::scval_to_json).collect();
+        }
+    }
+
+    Some(decoded)
+}
+
+/// Fee estimate from a prepared transaction's fee and Soroban data
+fn fee_estimate(fee: u32, soroban_data: Option<&SorobanTransactionData>) -> FeeEstimate {
+    let Some(data) = soroban_data else {
+        return FeeEstimate {
+            fee,
+            resource_fee: 0,
+            instructions: 0,
+            footprint: FootprintSummary::default(),
+        };
+    };
+
+    let footprint = &data.resources.footprint;
+    FeeEstimate {
+        fee,
+        resource_fee: data.resource_fee,
+        instructions: data.resources.instructions,
+        footprint: FootprintSummary {
+            read_only: footprint.read_only.iter().map(summarize_ledger_key).collect(),
+            read_write: footprint.read_write.iter().map(summarize_ledger_key).collect(),
+        },
+    }
+}
+
+/// Decode a footprint ledger key into something readable
+fn summarize_ledger_key(key: &LedgerKey) -> LedgerKeySummary {
+    let account_strkey = |account_id: &AccountId| match &account_id.0 {
+        PublicKey::PublicKeyTypeEd25519(key) => stellar_strkey::ed25519::PublicKey(key.0).to_string(),
+    };
+
+    let mut summary = LedgerKeySummary {
+        kind: key.name().to_string(),
+        owner: None,
+        key: None,
+        durability: None,
+    };
+
+    match key {
+        LedgerKey::ContractData(data) => {
+            summary.owner = Address::from_sc_address(&data.contract).ok().map(|a| a.to_string());
+            summary.key = Some(crate::services::soroban::client::scval_to_json(&data.key));
+            summary.durability = Some(match data.durability {
+                ContractDataDurability::Persistent => "persistent".to_string(),
+                ContractDataDurability::Temporary => "temporary".to_string(),
+            });
+        }
+        LedgerKey::ContractCode(code) => {
+            summary.key = Some(serde_json::Value::String(hex::encode(code.hash.0)));
+        }
+        LedgerKey::Account(account) => {
+            summary.owner = Some(account_strkey(&account.account_id));
+        }
+        LedgerKey::Trustline(trustline) => {
+            summary.owner = Some(account_strkey(&trustline.account_id));
+        }
+        _ => {}
+    }
+
+    summary
 }
 
 pub async fn submit_signed_transaction(signed_xdr: &str, function: &ContractFunction) -> Result<(String, String)> {
@@ -169,7 +368,7 @@ pub async fn submit_signed_transaction(signed_xdr: &str, function: &ContractFunc
     }
 
     // Decode and validate the signed XDR
-    let _tx_envelope = TransactionEnvelope::from_xdr_base64(signed_xdr, Limits::none())
+    let tx_envelope = TransactionEnvelope::from_xdr_base64(signed_xdr, Limits::none())
         .map_err(|e| AppError::XdrDecoding(format!("Failed to decode signed XDR: {:?}", e)))?;
 
     info!("Successfully decoded signed transaction envelope");
@@ -185,6 +384,21 @@ pub async fn submit_signed_transaction(signed_xdr: &str, function: &ContractFunc
 
     info!("Transaction analysis completed: {}", tx_hash);
 
+    // Classic payments share this submit path but have no contract function
+    if let Some(payment) = describe_payment(&tx_envelope) {
+        info!("Payment transaction analysis completed successfully!");
+        return Ok((tx_hash.clone(), format!(
+            "{}\n\n\
+            Transaction Status: SIGNED & VALIDATED\n\
+            Transaction ID: {}\n\
+            Signed XDR Length: {} characters\n\
+            Network: Stellar Testnet",
+            payment,
+            tx_hash,
+            signed_xdr.len()
+        )));
+    }
+
     // Create detailed contract execution summary
     let contract_result = format!(
         "Contract function '{}' ready for execution!\n\n\
@@ -221,4 +435,519 @@ pub async fn submit_signed_transaction(signed_xdr: &str, function: &ContractFunc
 
     info!("Contract transaction analysis completed successfully!");
     Ok((tx_hash, contract_result))
-}
\ No newline at end of file
+}
+
+/// Base fee for a single-operation classic transaction
+const PAYMENT_FEE: u32 = 100;
+
+/// Unsigned classic payment envelope plus the normalized values it was built from
+#[derive(Debug, Clone)]
+pub struct PaymentXdr {
+    pub xdr: String,
+    pub amount_stroops: i64,
+    pub asset: String,
+    /// The envelope's `maxTime`; the network rejects it after this
+    pub valid_until: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Build an unsigned classic payment from `source` to `destination`
+///
+/// `amount` is a decimal string in whole units; `asset` is `XLM`/`native` (the
+/// default) or `CODE:ISSUER`. Only the source's sequence number is read from RPC.
+pub async fn generate_payment_xdr(
+    config: &XdrConfig,
+    source: &str,
+    destination: &str,
+    amount: &str,
+    asset: Option<&str>,
+) -> Result<PaymentXdr> {
+    info!(
+        "Generating payment XDR: {} {} from {} to {}",
+        amount,
+        asset.unwrap_or(NATIVE_ASSET),
+        crate::utils::truncate_address(source),
+        crate::utils::truncate_address(destination)
+    );
+
+    account_key(source, "Source")?;
+    let destination_key = account_key(destination, "Destination")?;
+    let amount_stroops = parse_amount(amount)?;
+    let (xdr_asset, asset_name) = parse_asset(asset)?;
+
+    config.validate()?;
+
+    let rpc = Server::new(&config.rpc_url, rpc_options(&config.rpc_url))
+        .map_err(|e| AppError::StellarRpc(format!("Failed to connect to Soroban RPC: {:?}", e)))?;
+
+    let account_response = rpc.get_account(source).await
+        .map_err(|e| AppError::Account(format!("Failed to get account info: {:?}", e)))?;
+
+    let account = Account::new(source, &account_response.sequence_number())
+        .map_err(|e| AppError::Account(format!("Failed to create account: {:?}", e)))?;
+
+    let envelope = build_payment_envelope(
+        account,
+        &config.network_passphrase,
+        config.tx_timeout_secs,
+        destination_key,
+        amount_stroops,
+        xdr_asset,
+    )?;
+    let xdr = envelope.to_xdr_base64(Limits::none())
+        .map_err(|e| AppError::XdrEncoding(format!("Failed to encode payment XDR: {:?}", e)))?;
+
+    let valid_until = match &envelope {
+        TransactionEnvelope::Tx(v1) => valid_until(&v1.tx),
+        _ => None,
+    };
+
+    info!("Payment XDR generated ({} characters)", xdr.len());
+    Ok(PaymentXdr {
+        xdr,
+        amount_stroops,
+        asset: asset_name,
+        valid_until,
+    })
+}
+
+/// Build the single-operation payment envelope; no network access
+fn build_payment_envelope(
+    account: Account,
+    network_passphrase: &str,
+    timeout_secs: u64,
+    destination: [u8; 32],
+    amount_stroops: i64,
+    asset: Asset,
+) -> Result<TransactionEnvelope> {
+    let operation = Operation {
+        source_account: None,
+        body: OperationBody::Payment(PaymentOp {
+            destination: MuxedAccount::Ed25519(Uint256(destination)),
+            asset,
+            amount: amount_stroops,
+        }),
+    };
+
+    let tx = {
+        let mut tx_builder = TransactionBuilder::new(
+            Rc::new(RefCell::new(account)),
+            network_passphrase,
+            Some(time_bounds(timeout_secs))
+        );
+        tx_builder.fee(PAYMENT_FEE);
+        tx_builder.add_operation(operation);
+        tx_builder.build()
+    };
+
+    tx.to_envelope()
+        .map_err(|e| AppError::XdrEncoding(format!("Failed to create payment envelope: {:?}", e)))
+}
+
+/// Parse a decimal payment amount ("12.5") into stroops
+///
+/// Uses the shared parser (no rounding, no negatives), then requires a positive
+/// amount that fits the i64 a classic payment carries.
+pub fn parse_amount(amount: &str) -> Result<i64> {
+    let stroops = shared::amount::parse_amount(amount, STELLAR_DECIMALS)
+        .map_err(|e| AppError::InvalidInput(e.to_string()))?;
+
+    if stroops == 0 {
+        return Err(AppError::InvalidInput("Amount must be greater than zero".to_string()));
+    }
+
+    i64::try_from(stroops).map_err(|_| AppError::InvalidInput("Amount is too large".to_string()))
+}
+
+/// Parse `XLM`/`native` or `CODE:ISSUER` into an XDR asset and its normalized name
+fn parse_asset(asset: Option<&str>) -> Result<(Asset, String)> {
+    let asset = asset.map(str::trim).filter(|a| !a.is_empty()).unwrap_or(NATIVE_ASSET);
+
+    if asset.eq_ignore_ascii_case(NATIVE_ASSET) || asset.eq_ignore_ascii_case("native") {
+        return Ok((Asset::Native, NATIVE_ASSET.to_string()));
+    }
+
+    let (code, issuer) = asset.split_once(':')
+        .ok_or_else(|| AppError::InvalidInput("Asset must be 'XLM' or 'CODE:ISSUER'".to_string()))?;
+
+    if code.is_empty() || code.len() > 12 || !code.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(AppError::InvalidInput(format!("Invalid asset code: '{}'", code)));
+    }
+
+    let issuer_id = AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(account_key(issuer, "Asset issuer")?)));
+
+    // Codes are right-padded with zero bytes
+    let mut padded = [0u8; 12];
+    padded[..code.len()].copy_from_slice(code.as_bytes());
+
+    let xdr_asset = if code.len() <= 4 {
+        let mut code4 = [0u8; 4];
+        code4.copy_from_slice(&padded[..4]);
+        Asset::CreditAlphanum4(AlphaNum4 { asset_code: AssetCode4(code4), issuer: issuer_id })
+    } else {
+        Asset::CreditAlphanum12(AlphaNum12 { asset_code: AssetCode12(padded), issuer: issuer_id })
+    };
+
+    Ok((xdr_asset, format!("{}:{}", code, issuer)))
+}
+
+/// Raw ed25519 key of a G... address
+fn account_key(address: &str, label: &str) -> Result<[u8; 32]> {
+    stellar_strkey::ed25519::PublicKey::from_string(address)
+        .map(|key| key.0)
+        .map_err(|_| AppError::InvalidInput(format!("{} must be a Stellar account address (G...)", label)))
+}
+
+/// Summary of a single-payment envelope; `None` for anything else (e.g. contract calls)
+fn describe_payment(envelope: &TransactionEnvelope) -> Option<String> {
+    let TransactionEnvelope::Tx(v1) = envelope else { return None };
+    let [operation] = v1.tx.operations.as_slice() else { return None };
+    let OperationBody::Payment(payment) = &operation.body else { return None };
+
+    let destination = match &payment.destination {
+        MuxedAccount::Ed25519(key) => stellar_strkey::ed25519::PublicKey(key.0).to_string(),
+        MuxedAccount::MuxedEd25519(muxed) => stellar_strkey::ed25519::PublicKey(muxed.ed25519.0).to_string(),
+    };
+
+    let asset_code = |code: &[u8]| String::from_utf8_lossy(code).trim_end_matches('\0').to_string();
+    let issuer_strkey = |issuer: &AccountId| match &issuer.0 {
+        PublicKey::PublicKeyTypeEd25519(key) => stellar_strkey::ed25519::PublicKey(key.0).to_string(),
+    };
+    let asset = match &payment.asset {
+        Asset::Native => NATIVE_ASSET.to_string(),
+        Asset::CreditAlphanum4(a) => format!("{}:{}", asset_code(&a.asset_code.0), issuer_strkey(&a.issuer)),
+        Asset::CreditAlphanum12(a) => format!("{}:{}", asset_code(&a.asset_code.0), issuer_strkey(&a.issuer)),
+    };
+
+    // "12.5000000" -> "12.5"; always has a decimal point at 7 places, so trimming is safe
+    let amount = format_amount(payment.amount.into(), STELLAR_DECIMALS);
+    let amount = amount.trim_end_matches('0').trim_end_matches('.');
+
+    Some(format!("Payment of {} {} to {}", amount, asset, destination))
+}
+
+/// Signs transaction envelopes on the server
+///
+/// Browser users sign with Freighter; this is for automated tests and demos
+/// that need to sign without a wallet.
+pub trait Signer: Send + Sync {
+    /// Public key (G...) of the signing account
+    fn public_key(&self) -> String;
+
+    /// Add this signer's signature to a base64 transaction envelope
+    fn sign_envelope(&self, envelope_xdr: &str, network_passphrase: &str) -> Result<String>;
+}
+
+/// Signer backed by a secret key held in memory
+///
+/// Only meant for ephemeral testnet keys; see `AppConfig::enable_local_signer`.
+pub struct LocalSigner {
+    secret: String,
+    public_key: String,
+}
+
+impl LocalSigner {
+    pub fn from_secret(secret: &str) -> Result<Self> {
+        let keypair = Keypair::from_secret(secret)
+            .map_err(|e| AppError::Config(format!("Invalid signer secret key: {:?}", e)))?;
+
+        Ok(Self {
+            secret: secret.to_string(),
+            public_key: keypair.public_key(),
+        })
+    }
+}
+
+impl Signer for LocalSigner {
+    fn public_key(&self) -> String {
+        self.public_key.clone()
+    }
+
+    fn sign_envelope(&self, envelope_xdr: &str, network_passphrase: &str) -> Result<String> {
+        let envelope = TransactionEnvelope::from_xdr_base64(envelope_xdr, Limits::none())
+            .map_err(|e| AppError::XdrDecoding(format!("Failed to decode transaction envelope: {:?}", e)))?;
+
+        let mut v1 = match envelope {
+            TransactionEnvelope::Tx(v1) => v1,
+            _ => return Err(AppError::InvalidInput("Only v1 transaction envelopes can be signed".to_string())),
+        };
+
+        let hash = transaction_hash(&v1.tx, network_passphrase)?;
+        let keypair = Keypair::from_secret(&self.secret)
+            .map_err(|e| AppError::Config(format!("Invalid signer secret key: {:?}", e)))?;
+
+        let mut signatures = v1.signatures.to_vec();
+        signatures.push(keypair.sign_decorated(&hash));
+        v1.signatures = signatures.try_into()
+            .map_err(|_| AppError::Transaction("Too many signatures on envelope".to_string()))?;
+
+        debug!("Signed envelope with local signer {}", crate::utils::truncate_address(&self.public_key));
+
+        TransactionEnvelope::Tx(v1).to_xdr_base64(Limits::none())
+            .map_err(|e| AppError::XdrEncoding(format!("Failed to encode signed envelope: {:?}", e)))
+    }
+}
+
+/// Compute the network-specific hash that signers sign
+pub fn transaction_hash(tx: &Transaction, network_passphrase: &str) -> Result<[u8; 32]> {
+    let network_id = Hash(Sha256::digest(network_passphrase.as_bytes()).into());
+    let payload = TransactionSignaturePayload {
+        network_id,
+        tagged_transaction: TransactionSignaturePayloadTaggedTransaction::Tx(tx.clone()),
+    };
+
+    let payload_xdr = payload.to_xdr(Limits::none())
+        .map_err(|e| AppError::XdrEncoding(format!("Failed to encode signature payload: {:?}", e)))?;
+
+    Ok(Sha256::digest(&payload_xdr).into())
+}
+
+/// Hex transaction hash of a base64 v1 envelope, as reported by the network
+pub fn envelope_hash(envelope_xdr: &str, network_passphrase: &str) -> Result<String> {
+    let envelope = TransactionEnvelope::from_xdr_base64(envelope_xdr, Limits::none())
+        .map_err(|e| AppError::InvalidInput(format!("Invalid transaction envelope: {:?}", e)))?;
+
+    match envelope {
+        TransactionEnvelope::Tx(v1) => Ok(hex::encode(transaction_hash(&v1.tx, network_passphrase)?)),
+        _ => Err(AppError::InvalidInput("Only v1 transaction envelopes are supported".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::spawn_mock_rpc;
+
+    // Deterministic throwaway testnet key
+    const TEST_SECRET: &str = "SAAQEAYEAUDAOCAJBIFQYDIOB4IBCEQTCQKRMFYYDENBWHA5DYPSBF5K";
+    const TEST_PUBLIC: &str = "GB43KVROR7TFJ6KAPCYRF2FJROTZAH4FHLTJLPWX4DRZCC5NASLGITR6";
+
+    /// Build an unsigned contract call envelope without touching the network
+    fn unsigned_envelope(config: &XdrConfig) -> String {
+        let account = Account::new(TEST_PUBLIC, "1").unwrap();
+        let tx = {
+            let mut tx_builder = TransactionBuilder::new(
+                Rc::new(RefCell::new(account)),
+                &config.network_passphrase,
+                None
+            );
+            tx_builder.fee(1000000u32);
+            let contract = Contracts::new(&config.contract_id).unwrap();
+            tx_builder.add_operation(contract.call("hello", None));
+            tx_builder.build()
+        };
+        tx.to_envelope().unwrap().to_xdr_base64(Limits::none()).unwrap()
+    }
+
+    /// Mock RPC answering sendTransaction, accepting only singly-signed envelopes
+    async fn spawn_send_rpc() -> String {
+        spawn_mock_rpc(|body| {
+            assert_eq!(body["method"], "sendTransaction");
+            let envelope = TransactionEnvelope::from_xdr_base64(
+                body["params"]["transaction"].as_str().unwrap(),
+                Limits::none(),
+            ).unwrap();
+            let signature_count = match envelope {
+                TransactionEnvelope::Tx(v1) => v1.signatures.len(),
+                _ => 0,
+            };
+            serde_json::json!({
+                "status": if signature_count == 1 { "PENDING" } else { "ERROR" },
+                "hash": "ab".repeat(32),
+                "latestLedger": 1000,
+                "latestLedgerCloseTime": "1700000000"
+            })
+        })
+        .await
+    }
+
+    #[test]
+    fn test_fee_estimate_decodes_footprint() {
+        // SorobanTransactionData reading one persistent contract instance entry
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&0u32.to_be_bytes()); // ext v0
+        bytes.extend_from_slice(&1u32.to_be_bytes()); // read_only: 1 key
+        bytes.extend_from_slice(&6u32.to_be_bytes()); // LedgerKey::ContractData
+        bytes.extend_from_slice(&1u32.to_be_bytes()); // ScAddress::Contract
+        bytes.extend_from_slice(&[7u8; 32]);
+        bytes.extend_from_slice(&20u32.to_be_bytes()); // ScVal::LedgerKeyContractInstance
+        bytes.extend_from_slice(&1u32.to_be_bytes()); // Persistent
+        bytes.extend_from_slice(&0u32.to_be_bytes()); // read_write: empty
+        bytes.extend_from_slice(&1_000_000u32.to_be_bytes()); // instructions
+        bytes.extend_from_slice(&2_048u32.to_be_bytes()); // read bytes
+        bytes.extend_from_slice(&0u32.to_be_bytes()); // write bytes
+        bytes.extend_from_slice(&12_345i64.to_be_bytes()); // resource fee
+        let data = SorobanTransactionData::from_xdr(&bytes, Limits::none()).unwrap();
+
+        let estimate = fee_estimate(112_345, Some(&data));
+        let json = serde_json::to_value(&estimate).unwrap();
+
+        assert_eq!(json["fee"], 112_345);
+        assert_eq!(json["resource_fee"], 12_345);
+        assert_eq!(json["instructions"], 1_000_000);
+        assert_eq!(json["footprint"]["read_write"], serde_json::json!([]));
+
+        let entry = &json["footprint"]["read_only"][0];
+        assert_eq!(entry["kind"], "ContractData");
+        assert_eq!(entry["owner"], stellar_strkey::Contract([7u8; 32]).to_string());
+        assert_eq!(entry["durability"], "persistent");
+    }
+
+    #[test]
+    fn test_local_signer_public_key() {
+        let signer = LocalSigner::from_secret(TEST_SECRET).unwrap();
+        assert_eq!(signer.public_key(), TEST_PUBLIC);
+
+        assert!(LocalSigner::from_secret("not-a-secret").is_err());
+    }
+
+    #[test]
+    fn test_local_signer_adds_signature() {
+        let config = XdrConfig::default();
+        let signer = LocalSigner::from_secret(TEST_SECRET).unwrap();
+
+        let signed = signer.sign_envelope(&unsigned_envelope(&config), &config.network_passphrase).unwrap();
+
+        match TransactionEnvelope::from_xdr_base64(&signed, Limits::none()).unwrap() {
+            TransactionEnvelope::Tx(v1) => {
+                assert_eq!(v1.signatures.len(), 1);
+                // Hint is the last 4 bytes of the public key
+                assert_eq!(hex::encode(v1.signatures[0].hint.0), "ad049664");
+            }
+            _ => panic!("Expected v1 envelope"),
+        }
+    }
+
+    #[test]
+    fn test_envelope_hash_ignores_signatures() {
+        let config = XdrConfig::default();
+        let unsigned = unsigned_envelope(&config);
+        let signed = LocalSigner::from_secret(TEST_SECRET).unwrap()
+            .sign_envelope(&unsigned, &config.network_passphrase).unwrap();
+
+        let hash = envelope_hash(&unsigned, &config.network_passphrase).unwrap();
+        assert_eq!(hash.len(), 64);
+        assert_eq!(envelope_hash(&signed, &config.network_passphrase).unwrap(), hash);
+        assert_ne!(envelope_hash(&unsigned, "Public Global Stellar Network ; September 2015").unwrap(), hash);
+        assert!(matches!(envelope_hash("AAAA", &config.network_passphrase), Err(AppError::InvalidInput(_))));
+    }
+
+    #[tokio::test]
+    async fn test_generate_sign_submit_round_trip() {
+        let rpc_url = spawn_send_rpc().await;
+        let config = XdrConfig {
+            rpc_url: rpc_url.clone(),
+            ..XdrConfig::default()
+        };
+        let signer = LocalSigner::from_secret(TEST_SECRET).unwrap();
+
+        let unsigned = unsigned_envelope(&config);
+        let signed = signer.sign_envelope(&unsigned, &config.network_passphrase).unwrap();
+        let response = crate::services::soroban::client::send_transaction(&config, &signed).await.unwrap();
+
+        assert_eq!(response.status, "PENDING");
+        assert_eq!(response.hash, "ab".repeat(32));
+        assert_eq!(response.latest_ledger, 1000);
+    }
+
+    #[test]
+    fn test_payment_envelope_is_valid() {
+        let config = XdrConfig::default();
+        let destination = stellar_strkey::ed25519::PublicKey([9u8; 32]);
+        let account = Account::new(TEST_PUBLIC, "1").unwrap();
+
+        let envelope = build_payment_envelope(account, &config.network_passphrase, DEFAULT_TX_TIMEOUT_SECS, destination.0, parse_amount("12.5").unwrap(), Asset::Native).unwrap();
+        let xdr = envelope.to_xdr_base64(Limits::none()).unwrap();
+
+        let TransactionEnvelope::Tx(v1) = TransactionEnvelope::from_xdr_base64(&xdr, Limits::none()).unwrap() else {
+            panic!("Expected v1 envelope");
+        };
+        assert!(v1.signatures.is_empty());
+        assert_eq!(v1.tx.seq_num.0, 2);
+        assert_eq!(v1.tx.operations.len(), 1);
+        match &v1.tx.operations[0].body {
+            OperationBody::Payment(payment) => {
+                assert_eq!(payment.amount, 125_000_000);
+                assert_eq!(payment.asset, Asset::Native);
+                assert_eq!(payment.destination, MuxedAccount::Ed25519(Uint256(destination.0)));
+            }
+            other => panic!("Expected payment operation, got {:?}", other),
+        }
+
+        let summary = describe_payment(&TransactionEnvelope::from_xdr_base64(&xdr, Limits::none()).unwrap()).unwrap();
+        assert_eq!(summary, format!("Payment of 12.5 XLM to {}", destination));
+    }
+
+    #[test]
+    fn test_payment_envelope_expires_after_timeout() {
+        let config = XdrConfig::default();
+        let destination = stellar_strkey::ed25519::PublicKey([9u8; 32]);
+        let account = Account::new(TEST_PUBLIC, "1").unwrap();
+        let now = chrono::Utc::now().timestamp() as u64;
+
+        let envelope = build_payment_envelope(account, &config.network_passphrase, 120, destination.0, 1, Asset::Native).unwrap();
+        let xdr = envelope.to_xdr_base64(Limits::none()).unwrap();
+
+        let TransactionEnvelope::Tx(v1) = TransactionEnvelope::from_xdr_base64(&xdr, Limits::none()).unwrap() else {
+            panic!("Expected v1 envelope");
+        };
+        let Preconditions::Time(bounds) = &v1.tx.cond else {
+            panic!("Expected time bounds, got {:?}", v1.tx.cond);
+        };
+        assert_eq!(bounds.min_time, TimePoint(0));
+        assert!((now + 120..=now + 121).contains(&bounds.max_time.0));
+        assert_eq!(valid_until(&v1.tx).unwrap().timestamp() as u64, bounds.max_time.0);
+    }
+
+    #[test]
+    fn test_parse_amount() {
+        assert_eq!(parse_amount("1").unwrap(), 10_000_000);
+        assert_eq!(parse_amount("0.0000001").unwrap(), 1);
+        assert_eq!(parse_amount(" 12.5 ").unwrap(), 125_000_000);
+        assert_eq!(parse_amount(".5").unwrap(), 5_000_000);
+
+        for invalid in ["", "0", "0.0", "-1", "1e5", "abc", "1.00000001", "1.2.3", "99999999999999999999"] {
+            assert!(parse_amount(invalid).is_err(), "{} should be rejected", invalid);
+        }
+    }
+
+    #[test]
+    fn test_parse_asset() {
+        let issuer = stellar_strkey::ed25519::PublicKey([3u8; 32]).to_string();
+
+        assert_eq!(parse_asset(None).unwrap().1, "XLM");
+        assert_eq!(parse_asset(Some("native")).unwrap().0, Asset::Native);
+
+        let (usdc, name) = parse_asset(Some(&format!("USDC:{}", issuer))).unwrap();
+        assert!(matches!(usdc, Asset::CreditAlphanum4(ref a) if &a.asset_code.0 == b"USDC"));
+        assert_eq!(name, format!("USDC:{}", issuer));
+
+        let (long, _) = parse_asset(Some(&format!("YIELDTOKEN:{}", issuer))).unwrap();
+        assert!(matches!(long, Asset::CreditAlphanum12(ref a) if &a.asset_code.0[..10] == b"YIELDTOKEN"));
+
+        assert!(parse_asset(Some("USDC")).is_err());
+        assert!(parse_asset(Some("USDC:not-an-issuer")).is_err());
+        assert!(parse_asset(Some(&format!("TOOLONGASSETCODE:{}", issuer))).is_err());
+    }
+
+    #[test]
+    fn test_tx_timeout_validated() {
+        assert!(validate_tx_timeout(DEFAULT_TX_TIMEOUT_SECS).is_ok());
+        assert!(validate_tx_timeout(MIN_TX_TIMEOUT_SECS).is_ok());
+        assert!(validate_tx_timeout(MAX_TX_TIMEOUT_SECS).is_ok());
+        assert!(validate_tx_timeout(0).is_err());
+        assert!(validate_tx_timeout(MAX_TX_TIMEOUT_SECS + 1).is_err());
+
+        let config = XdrConfig { tx_timeout_secs: 0, ..XdrConfig::default() };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_time_bounds_expire_after_timeout() {
+        let now = chrono::Utc::now().timestamp() as u64;
+
+        let bounds = time_bounds(120);
+
+        assert_eq!(bounds.min_time, TimePoint(0));
+        assert!((now + 120..=now + 121).contains(&bounds.max_time.0));
+    }
+}
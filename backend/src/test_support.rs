@@ -0,0 +1,51 @@
+//! Helpers shared by the crate's unit tests
+
+use axum::{routing::post, Json, Router};
+use std::sync::{Arc, Mutex};
+
+/// Writer collecting formatted log lines into a shared buffer
+#[derive(Clone, Default)]
+pub struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl CapturedLogs {
+    /// Everything written so far
+    pub fn output(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl std::io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Minimal JSON-RPC server on a random local port, returning its URL
+///
+/// `respond` receives the full request body and returns the `result` member
+/// of the reply; the JSON-RPC envelope is filled in here.
+pub async fn spawn_mock_rpc<F>(respond: F) -> String
+where
+    F: Fn(&serde_json::Value) -> serde_json::Value + Clone + Send + Sync + 'static,
+{
+    let app = Router::new().route("/", post(move |Json(body): Json<serde_json::Value>| {
+        let respond = respond.clone();
+        async move {
+            Json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": body["id"],
+                "result": respond(&body)
+            }))
+        }
+    }));
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    format!("http://{}", addr)
+}
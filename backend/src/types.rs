@@ -1,5 +1,10 @@
 use serde::{Deserialize, Serialize};
+use soroban_client::address::{Address, AddressTrait};
 use soroban_client::xdr::ScVal;
+use shared::dto::soroban::ContractFunctionInfo;
+pub use shared::dto::soroban::DecodedOperation;
+
+use crate::services::stellar::{GeneratedTransaction, XdrConfig};
 
 /// Available contract functions with their signatures
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -16,6 +21,10 @@ pub enum ContractFunction {
     X { y: String },
     /// edge_case_test_yew_123_end(edge_input: string) -> string
     EdgeCaseTestYew123End { edge_input: String },
+    /// submit_score(player: address, score: u64) -> ()
+    SubmitScore { player: String, score: u64 },
+    /// top_scores(n: u32) -> vec<(address, u64)>
+    TopScores { n: u32 },
 }
 
 impl ContractFunction {
@@ -28,6 +37,8 @@ impl ContractFunction {
             ContractFunction::TestFunc123 { .. } => "test_func_123",
             ContractFunction::X { .. } => "x",
             ContractFunction::EdgeCaseTestYew123End { .. } => "edge_case_test_yew_123_end",
+            ContractFunction::SubmitScore { .. } => "submit_score",
+            ContractFunction::TopScores { .. } => "top_scores",
         }
     }
 
@@ -40,6 +51,8 @@ impl ContractFunction {
             ContractFunction::TestFunc123 { .. } => "test_func_123(param_1: string, param_2: u32) -> string",
             ContractFunction::X { .. } => "x(y: string) -> string",
             ContractFunction::EdgeCaseTestYew123End { .. } => "edge_case_test_yew_123_end(edge_input: string) -> string",
+            ContractFunction::SubmitScore { .. } => "submit_score(player: address, score: u64) -> ()",
+            ContractFunction::TopScores { .. } => "top_scores(n: u32) -> vec<(address, u64)>",
         }
     }
 
@@ -52,6 +65,17 @@ impl ContractFunction {
             ContractFunction::TestFunc123 { .. } => "Function with numbers and underscores to test encoding",
             ContractFunction::X { .. } => "Function with single character name",
             ContractFunction::EdgeCaseTestYew123End { .. } => "Function that might trigger encoding edge cases",
+            ContractFunction::SubmitScore { .. } => "Record a player's score on the leaderboard (signed by the player)",
+            ContractFunction::TopScores { .. } => "Read the n highest scores, best first",
+        }
+    }
+
+    /// Name, signature and description for API consumers
+    pub fn info(&self) -> ContractFunctionInfo {
+        ContractFunctionInfo {
+            name: self.name().to_string(),
+            signature: self.signature().to_string(),
+            description: self.description().to_string(),
         }
     }
 
@@ -104,6 +128,14 @@ impl ContractFunction {
                         ))?
                 )])
             }
+            ContractFunction::SubmitScore { player, score } => {
+                let address = Address::new(player)
+                    .map_err(|e| crate::error::AppError::InvalidInput(format!("Invalid player address: {}", e)))?;
+                let sc_address = address.to_sc_address()
+                    .map_err(|e| crate::error::AppError::XdrEncoding(format!("Failed to convert player address: {}", e)))?;
+                Ok(vec![ScVal::Address(sc_address), ScVal::U64(*score)])
+            }
+            ContractFunction::TopScores { n } => Ok(vec![ScVal::U32(*n)]),
         }
     }
 
@@ -121,6 +153,8 @@ impl ContractFunction {
             ContractFunction::EdgeCaseTestYew123End {
                 edge_input: "edge_test".to_string()
             },
+            // submit_score needs a real player, so it is built via /api/leaderboard/submit
+            ContractFunction::TopScores { n: shared::dto::leaderboard::DEFAULT_TOP_N },
         ]
     }
 
@@ -139,6 +173,9 @@ impl ContractFunction {
             Some("edge_case_test_yew_123_end") => ContractFunction::EdgeCaseTestYew123End {
                 edge_input: "edge_test".to_string(),
             },
+            // Leaderboard calls carry real arguments; the name is enough to label a submission
+            Some("submit_score") => ContractFunction::SubmitScore { player: String::new(), score: 0 },
+            Some("top_scores") => ContractFunction::TopScores { n: shared::dto::leaderboard::DEFAULT_TOP_N },
             _ => ContractFunction::TestFunc123 {
                 param_1: "hello".to_string(),
                 param_2: 42,
@@ -159,6 +196,9 @@ pub struct XdrRequest {
     /// The contract function to call (simple name)
     #[serde(default)]
     pub function_name: Option<String>,
+    /// Only return the estimated fee and footprint, not the envelope to sign
+    #[serde(default)]
+    pub estimate_only: bool,
 }
 
 impl XdrRequest {
@@ -185,14 +225,44 @@ pub struct XdrResponse {
     pub success: bool,
     pub xdr: String,
     pub message: String,
+    /// Network passphrase the transaction is built for; the wallet must sign for the same one
+    pub network: String,
+    pub contract_id: String,
+    /// When the transaction's time bounds expire; it must be signed and submitted before then
+    pub valid_until: Option<chrono::DateTime<chrono::Utc>>,
+    /// The operation the wallet is being asked to sign
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decoded_operation: Option<DecodedOperation>,
+    /// Fee and footprint of the prepared transaction (estimate-only requests)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<FeeEstimate>,
 }
 
 impl XdrResponse {
-    pub fn success(xdr: String, message: String) -> Self {
+    pub fn success(generated: GeneratedTransaction, config: &XdrConfig, message: String) -> Self {
         Self {
             success: true,
-            xdr,
+            xdr: generated.xdr,
             message,
+            network: config.network_passphrase.clone(),
+            contract_id: config.contract_id.clone(),
+            valid_until: generated.valid_until,
+            decoded_operation: generated.operation,
+            estimate: None,
+        }
+    }
+
+    /// Estimate-only response - no envelope is returned
+    pub fn estimate(estimate: FeeEstimate, config: &XdrConfig, message: String) -> Self {
+        Self {
+            success: true,
+            xdr: String::new(),
+            message,
+            network: config.network_passphrase.clone(),
+            contract_id: config.contract_id.clone(),
+            valid_until: None,
+            decoded_operation: None,
+            estimate: Some(estimate),
         }
     }
 
@@ -201,10 +271,50 @@ impl XdrResponse {
             success: false,
             xdr: String::new(),
             message,
+            network: String::new(),
+            contract_id: String::new(),
+            valid_until: None,
+            decoded_operation: None,
+            estimate: None,
         }
     }
 }
 
+/// Fee and resource footprint of a prepared transaction
+#[derive(Debug, Clone, Serialize)]
+pub struct FeeEstimate {
+    /// Total transaction fee in stroops (inclusion fee + resource fee)
+    pub fee: u32,
+    /// Soroban resource fee in stroops
+    pub resource_fee: i64,
+    /// CPU instructions reserved for the call
+    pub instructions: u32,
+    pub footprint: FootprintSummary,
+}
+
+/// Ledger entries the transaction reads and writes
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FootprintSummary {
+    pub read_only: Vec<LedgerKeySummary>,
+    pub read_write: Vec<LedgerKeySummary>,
+}
+
+/// Decoded ledger key from a transaction footprint
+#[derive(Debug, Clone, Serialize)]
+pub struct LedgerKeySummary {
+    /// Ledger entry type, e.g. "ContractData", "ContractCode", "Account"
+    pub kind: String,
+    /// Contract (C...) or account (G...) the entry belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Storage key for contract data entries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<serde_json::Value>,
+    /// "persistent" or "temporary" for contract data entries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub durability: Option<String>,
+}
+
 /// Request for submitting a signed transaction
 /// Accepts signed XDR from any Stellar wallet
 #[derive(Debug, Deserialize)]
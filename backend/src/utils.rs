@@ -1,16 +1,55 @@
-use axum::http::Method;
-use tower_http::cors::{CorsLayer, Any};
+use axum::http::{HeaderName, HeaderValue, Method};
+use tower_http::cors::{AllowOrigin, CorsLayer, Any};
+use tracing::warn;
+
+/// Response headers readable by browser JS unless overridden by CORS_EXPOSED_HEADERS
+pub const DEFAULT_EXPOSED_HEADERS: &[&str] = &[
+    "x-request-id",
+    "x-cache",
+    "retry-after",
+    "x-ratelimit-limit",
+    "x-ratelimit-remaining",
+    "x-ratelimit-reset",
+];
+
+pub fn create_cors_layer(allowed_origins: Vec<String>, exposed_headers: Vec<String>) -> CorsLayer {
+    let exposed_headers: Vec<HeaderName> = exposed_headers
+        .iter()
+        .filter_map(|name| match name.parse() {
+            Ok(header) => Some(header),
+            Err(_) => {
+                warn!("Ignoring invalid CORS exposed header: {}", name);
+                None
+            }
+        })
+        .collect();
 
-pub fn create_cors_layer(allowed_origins: Vec<String>) -> CorsLayer {
     let mut cors = CorsLayer::new()
-        .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
+        .allow_methods([Method::GET, Method::POST, Method::DELETE, Method::OPTIONS])
         .allow_headers([
             axum::http::header::CONTENT_TYPE,
             axum::http::header::AUTHORIZATION,
-        ]);
+        ])
+        .expose_headers(exposed_headers);
 
     if allowed_origins.is_empty() || allowed_origins.contains(&"*".to_string()) {
         cors = cors.allow_origin(Any);
+    } else if allowed_origins.iter().any(|origin| origin.contains('*')) {
+        // Wildcard subdomains need a predicate; only listed patterns are ever echoed back
+        let patterns: Vec<OriginPattern> = allowed_origins
+            .iter()
+            .filter_map(|origin| match OriginPattern::parse(origin) {
+                Some(pattern) => Some(pattern),
+                None => {
+                    warn!("Ignoring invalid CORS origin: {}", origin);
+                    None
+                }
+            })
+            .collect();
+
+        cors = cors.allow_origin(AllowOrigin::predicate(move |origin: &HeaderValue, _| {
+            origin.to_str().is_ok_and(|origin| origin_allowed(&patterns, origin))
+        }));
     } else {
         // Parse all origins at once for proper matching
         let parsed_origins: Vec<axum::http::HeaderValue> = allowed_origins
@@ -26,6 +65,62 @@ pub fn create_cors_layer(allowed_origins: Vec<String>) -> CorsLayer {
     cors
 }
 
+/// An entry of ALLOWED_ORIGINS: an exact origin or a wildcard subdomain (`https://*.example.com`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OriginPattern {
+    Exact(String),
+    /// Matches `{scheme}://<subdomain>{suffix}`, e.g. suffix `.example.com`
+    WildcardSubdomain { scheme: String, suffix: String },
+}
+
+impl OriginPattern {
+    /// Parse an allowed origin; `*` is only accepted as the leftmost host label
+    pub fn parse(origin: &str) -> Option<Self> {
+        let origin = origin.trim().trim_end_matches('/');
+        let (scheme, host) = origin.split_once("://")?;
+        if scheme.is_empty() || host.is_empty() {
+            return None;
+        }
+
+        match host.strip_prefix('*') {
+            None if !host.contains('*') => Some(OriginPattern::Exact(origin.to_ascii_lowercase())),
+            // The suffix must itself be a domain, so `https://*` or `https://*.com` can't match everything
+            Some(suffix) if suffix.starts_with('.') && !suffix.contains('*') && suffix[1..].contains('.') => {
+                Some(OriginPattern::WildcardSubdomain {
+                    scheme: scheme.to_ascii_lowercase(),
+                    suffix: suffix.to_ascii_lowercase(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    pub fn matches(&self, origin: &str) -> bool {
+        let origin = origin.to_ascii_lowercase();
+        match self {
+            OriginPattern::Exact(allowed) => origin == *allowed,
+            OriginPattern::WildcardSubdomain { scheme, suffix } => {
+                let Some(host) = origin.strip_prefix(scheme.as_str()).and_then(|rest| rest.strip_prefix("://")) else {
+                    return false;
+                };
+                let Some(subdomain) = host.strip_suffix(suffix.as_str()) else {
+                    return false;
+                };
+
+                !subdomain.is_empty()
+                    && subdomain.split('.').all(|label| {
+                        !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+                    })
+            }
+        }
+    }
+}
+
+/// Whether an Origin header value matches any allowed pattern
+pub fn origin_allowed(patterns: &[OriginPattern], origin: &str) -> bool {
+    patterns.iter().any(|pattern| pattern.matches(origin))
+}
+
 pub fn validate_stellar_address(address: &str) -> bool {
     address.starts_with('G') && address.len() == 56
 }
@@ -34,9 +129,67 @@ pub fn validate_contract_id(contract_id: &str) -> bool {
     contract_id.starts_with('C') && contract_id.len() == 56
 }
 
+/// Canonical form of a wallet address for storage and comparison
+///
+/// Trims whitespace and uppercases before checking the `G...` strkey checksum,
+/// so the same account always maps to the same stored value.
+pub fn normalize_wallet_address(address: &str) -> Result<String, String> {
+    let candidate = address.trim().to_ascii_uppercase();
+
+    stellar_strkey::ed25519::PublicKey::from_string(&candidate)
+        .map(|key| key.to_string())
+        .map_err(|_| format!("'{}' is not a valid wallet address (expected a 56-character G... strkey)", address.trim()))
+}
+
+/// Parse a contract id given as a `C...` strkey or 64-character hex string
+///
+/// Returns `(strkey, hex)`. The form is chosen by length, since hex ids may
+/// also start with `C`. The strkey checksum is verified.
+pub fn convert_contract_id(contract_id: &str) -> Result<(String, String), String> {
+    let contract_id = contract_id.trim();
+    let hex_id = contract_id.trim_start_matches("0x");
+
+    let hash = if contract_id.len() == 56 {
+        stellar_strkey::Contract::from_string(contract_id)
+            .map_err(|_| format!("Invalid contract strkey (bad checksum): {}", contract_id))?
+            .0
+    } else if hex_id.len() == 64 {
+        let mut hash = [0u8; 32];
+        hex::decode_to_slice(hex_id, &mut hash)
+            .map_err(|_| format!("Invalid hex contract id: {}", contract_id))?;
+        hash
+    } else {
+        return Err(format!(
+            "Contract id must be a 56-character C... strkey or 64 hex characters: {}",
+            contract_id
+        ));
+    };
+
+    Ok((stellar_strkey::Contract(hash).to_string(), hex::encode(hash)))
+}
+
+/// Guard for handlers taking a contract id: rejects malformed ids (format or checksum)
+/// with `INVALID_CONTRACT_ID` before any RPC work happens
+pub fn ensure_valid_contract_id(contract_id: &str) -> crate::error::Result<()> {
+    if validate_contract_id(contract_id) && stellar_strkey::Contract::from_string(contract_id).is_ok() {
+        Ok(())
+    } else {
+        Err(crate::error::AppError::InvalidContractId(format!(
+            "'{}' is not a valid contract address (expected a 56-character C... strkey)",
+            contract_id
+        )))
+    }
+}
+
+/// Shortens an address for logging. Works on chars rather than bytes so
+/// unvalidated input (path segments, request bodies) can't panic on a
+/// multibyte boundary.
 pub fn truncate_address(address: &str) -> String {
-    if address.len() >= 12 {
-        format!("{}...{}", &address[..6], &address[address.len()-6..])
+    if address.chars().count() >= 12 {
+        let head: String = address.chars().take(6).collect();
+        let mut tail: Vec<char> = address.chars().rev().take(6).collect();
+        tail.reverse();
+        format!("{}...{}", head, tail.into_iter().collect::<String>())
     } else {
         address.to_string()
     }
@@ -60,10 +213,138 @@ mod tests {
         assert!(!validate_contract_id("CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUI"));
     }
 
+    #[test]
+    fn test_normalize_wallet_address() {
+        let canonical = "GDAT5HWTGIU4TSSZ4752OUC4SABDLTLZFRPZUJ3D6LKBNEPA7V2CIG54";
+
+        assert_eq!(normalize_wallet_address(canonical).unwrap(), canonical);
+        assert_eq!(normalize_wallet_address(&format!("  {}\n", canonical)).unwrap(), canonical);
+        assert_eq!(normalize_wallet_address(&canonical.to_lowercase()).unwrap(), canonical);
+
+        assert!(normalize_wallet_address("GDAT5HWTGIU4TSSZ4752OUC4SABDLTLZFRPZUJ3D6LKBNEPA7V2CIG55").is_err());
+        assert!(normalize_wallet_address("CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF").is_err());
+        assert!(normalize_wallet_address("").is_err());
+    }
+
+    #[test]
+    fn test_ensure_valid_contract_id() {
+        assert!(ensure_valid_contract_id("CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF").is_ok());
+
+        let err = ensure_valid_contract_id("CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIA").unwrap_err();
+        assert_eq!(err.error_type(), "INVALID_CONTRACT_ID");
+        assert_eq!(err.status_code(), axum::http::StatusCode::BAD_REQUEST);
+
+        assert!(ensure_valid_contract_id("GDAT5HWTGIU4TSSZ4752OUC4SABDLTLZFRPZUJ3D6LKBNEPA7V2CIG54").is_err());
+        assert!(ensure_valid_contract_id("").is_err());
+    }
+
+    #[test]
+    fn test_convert_contract_id() {
+        let strkey = "CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF";
+
+        let (converted_strkey, hex) = convert_contract_id(strkey).unwrap();
+        assert_eq!(converted_strkey, strkey);
+        assert_eq!(hex.len(), 64);
+
+        // Hex converts back to the same strkey
+        let (roundtrip_strkey, roundtrip_hex) = convert_contract_id(&hex.to_uppercase()).unwrap();
+        assert_eq!(roundtrip_strkey, strkey);
+        assert_eq!(roundtrip_hex, hex);
+    }
+
+    #[test]
+    fn test_convert_contract_id_invalid() {
+        // Last character changed - checksum no longer matches
+        assert!(convert_contract_id("CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIA").is_err());
+        assert!(convert_contract_id("abcd").is_err());
+        assert!(convert_contract_id("not a contract").is_err());
+    }
+
+    #[test]
+    fn test_convert_contract_id_hex_starting_with_c() {
+        let hex = format!("c0ffee{}", "00".repeat(29));
+
+        let (strkey, converted_hex) = convert_contract_id(&hex.to_uppercase()).unwrap();
+        assert_eq!(converted_hex, hex);
+        assert_eq!(strkey, stellar_strkey::Contract(hex::decode(&hex).unwrap().try_into().unwrap()).to_string());
+
+        let (prefixed, _) = convert_contract_id(&format!("0x{}", hex)).unwrap();
+        assert_eq!(prefixed, strkey);
+    }
+
+    #[test]
+    fn test_wildcard_origin_matching() {
+        let patterns: Vec<OriginPattern> = ["https://*.example.com", "http://localhost:8080"]
+            .iter()
+            .map(|origin| OriginPattern::parse(origin).unwrap())
+            .collect();
+
+        assert!(origin_allowed(&patterns, "https://preview-42.example.com"));
+        assert!(origin_allowed(&patterns, "https://a.b.example.com"));
+        assert!(origin_allowed(&patterns, "https://PR-7.Example.com"));
+        assert!(origin_allowed(&patterns, "http://localhost:8080"));
+
+        assert!(!origin_allowed(&patterns, "https://example.com"));
+        assert!(!origin_allowed(&patterns, "http://preview.example.com"));
+        assert!(!origin_allowed(&patterns, "https://evilexample.com"));
+        assert!(!origin_allowed(&patterns, "https://example.com.evil.org"));
+        assert!(!origin_allowed(&patterns, "https://evil.org/.example.com"));
+        assert!(!origin_allowed(&patterns, "https://preview.example.com:8443"));
+        assert!(!origin_allowed(&patterns, "http://localhost:8081"));
+    }
+
+    #[test]
+    fn test_origin_pattern_rejects_overly_broad_wildcards() {
+        assert_eq!(OriginPattern::parse("https://*"), None);
+        assert_eq!(OriginPattern::parse("https://*.com"), None);
+        assert_eq!(OriginPattern::parse("https://app.*.example.com"), None);
+        assert_eq!(OriginPattern::parse("*.example.com"), None);
+        assert_eq!(
+            OriginPattern::parse("https://*.example.com/"),
+            Some(OriginPattern::WildcardSubdomain {
+                scheme: "https".to_string(),
+                suffix: ".example.com".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cors_layer_echoes_only_matching_origins() {
+        use axum::{body::Body, http::Request, routing::get, Router};
+        use tower::ServiceExt;
+
+        let app = Router::new()
+            .route("/", get(|| async { "ok" }))
+            .layer(create_cors_layer(vec!["https://*.example.com".to_string()], vec![]));
+
+        let allow_origin_for = |origin: &'static str| {
+            let app = app.clone();
+            async move {
+                let response = app
+                    .oneshot(Request::builder().uri("/").header("origin", origin).body(Body::empty()).unwrap())
+                    .await
+                    .unwrap();
+                response.headers().get("access-control-allow-origin").cloned()
+            }
+        };
+
+        assert_eq!(
+            allow_origin_for("https://preview.example.com").await.unwrap(),
+            "https://preview.example.com"
+        );
+        assert!(allow_origin_for("https://attacker.org").await.is_none());
+    }
+
     #[test]
     fn test_truncate_address() {
         let address = "GDAT5HWTGIU4TSSZ4752OUC4SABDLTLZFRPZUJ3D6LKBNEPA7V2CIG54";
         assert_eq!(truncate_address(address), "GDAT5H...2CIG54");
         assert_eq!(truncate_address("short"), "short");
     }
+
+    #[test]
+    fn test_truncate_address_non_ascii() {
+        assert_eq!(truncate_address("éGDAT5HWTGIU4TSSZ4752ü"), "éGDAT5...4752ü");
+        assert_eq!(truncate_address("ééééééééééé"), "ééééééééééé");
+    }
 }
\ No newline at end of file
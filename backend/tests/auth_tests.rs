@@ -410,7 +410,7 @@ async fn test_guest_registration() {
     let test_db = TestDb::new().await;
     let app = create_test_app(test_db.pool.clone()).await;
 
-    let wallet_address = "GBTEST123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890AB";
+    let wallet_address = "GASSTX4UVSZXX2PK4JMETCRQLPWXU3KIXTTSWHHCW5BKKKLYBGJFMJPO";
     let payload = json!({
         "username": "wallet_user",
         "wallet_address": wallet_address,
@@ -458,7 +458,7 @@ async fn test_link_wallet_to_existing_account() {
     let token = test_user.get_token(&config);
     let app = create_test_app(test_db.pool.clone()).await;
 
-    let new_wallet = "GBNEWWALLET123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ12345";
+    let new_wallet = "GCS6ILHEZO5IUMXQPNMES3H757AH2IGNZAAFTS4C6UZFRN6YKKCUGHYF";
     let payload = json!({
         "wallet_address": new_wallet,
     });
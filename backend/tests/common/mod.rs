@@ -2,13 +2,25 @@
 ///
 /// This module provides:
 /// - Test database setup and teardown
+/// - Mock Stellar RPC server for Soroban routes
 /// - Test user creation helpers
 /// - HTTP client utilities
 /// - Assertion helpers
 
 use sqlx::{PgPool, Row};
-use stellar_xdr_service::{AppConfig, create_app};
-use axum::Router;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use serde_json::{json, Value};
+use stellar_xdr_service::{AppConfig, AppState, create_app, create_router};
+use stellar_xdr_service::database::connection::unavailable_pool;
+use stellar_xdr_service::utils::DEFAULT_EXPOSED_HEADERS;
+use stellar_xdr_service::server::ServerConfig;
+use stellar_xdr_service::services::soroban::{
+    AuthMode, ContractMetadata, ContractQueue, ContractRegistry, CostLimits, NetworkType, ScalableContractManager,
+    WarmupConfig,
+};
+use axum::{routing::post, Json, Router};
+use soroban_client::xdr::{HostFunction, Limits, OperationBody, ReadXdr, TransactionEnvelope};
 
 /// Test database configuration
 pub struct TestDb {
@@ -121,14 +133,16 @@ impl TestDb {
     }
 }
 
-/// Create a test app router with the given database pool
-pub async fn create_test_app(pool: PgPool) -> Router {
-    let config = AppConfig {
+/// Configuration used by every test app
+pub fn test_config() -> AppConfig {
+    AppConfig {
         port: 3001,
         contract_id: "CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF".to_string(),
         network_passphrase: "Test SDF Network ; September 2015".to_string(),
         rpc_url: "https://soroban-testnet.stellar.org".to_string(),
         allowed_origins: vec!["http://localhost:8080".to_string()],
+        cors_exposed_headers: DEFAULT_EXPOSED_HEADERS.iter().map(|h| h.to_string()).collect(),
+        server: ServerConfig::default(),
         jwt_secret: "test-secret-key-with-minimum-32-characters-for-testing!".to_string(),
         jwt_expiration_hours: 24,
         cookie_name: "test_auth".to_string(),
@@ -136,13 +150,291 @@ pub async fn create_test_app(pool: PgPool) -> Router {
         cookie_secure: false,
         cookie_http_only: true,
         cookie_same_site: "Lax".to_string(),
-    };
+        cost_limits: CostLimits::standard(),
+        admin_cost_limits: CostLimits::admin(),
+        enable_local_signer: false,
+        local_signer_secret: None,
+        warmup: WarmupConfig::default(),
+        simulation_auth_mode: AuthMode::Record,
+        contract_call_timeout_secs: 30,
+        tx_timeout_secs: 300,
+        require_database: true,
+    }
+}
 
-    create_app(config, pool)
+/// Create a test app router with the given database pool
+pub async fn create_test_app(pool: PgPool) -> Router {
+    create_app(test_config(), pool)
         .await
         .expect("Failed to create test app")
 }
 
+/// Latest ledger reported by the mock RPC
+pub const MOCK_LATEST_LEDGER: u32 = 1000;
+
+/// Canned responses served by `MockRpc`
+#[derive(Debug, Clone, Default)]
+pub struct MockRpcResponses {
+    results: HashMap<String, Value>,
+    errors: HashMap<String, Value>,
+    simulations: HashMap<String, Value>,
+    ledger_entries: HashMap<String, (String, u32)>,
+    hanging: Vec<String>,
+}
+
+impl MockRpcResponses {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer every call to `method` with this `result`
+    pub fn result(mut self, method: &str, result: Value) -> Self {
+        self.results.insert(method.to_string(), result);
+        self
+    }
+
+    /// Answer every call to `method` with this JSON-RPC `error` object
+    pub fn error(mut self, method: &str, error: Value) -> Self {
+        self.errors.insert(method.to_string(), error);
+        self
+    }
+
+    /// Answer simulations invoking contract function `function` with this `result`,
+    /// instead of the plain `simulateTransaction` result
+    pub fn simulation(mut self, function: &str, result: Value) -> Self {
+        self.simulations.insert(function.to_string(), result);
+        self
+    }
+
+    /// Serve this ledger entry (base64 `LedgerEntryData`) from getLedgerEntries
+    pub fn ledger_entry(self, key: String, xdr: String) -> Self {
+        self.ledger_entry_live_until(key, xdr, MOCK_LATEST_LEDGER + 10_000)
+    }
+
+    /// Like `ledger_entry`, with the entry's TTL ending at `live_until`
+    pub fn ledger_entry_live_until(mut self, key: String, xdr: String, live_until: u32) -> Self {
+        self.ledger_entries.insert(key, (xdr, live_until));
+        self
+    }
+
+    /// Never answer calls to `method`, like an RPC node that stalls
+    pub fn hang(mut self, method: &str) -> Self {
+        self.hanging.push(method.to_string());
+        self
+    }
+
+    fn respond(&self, method: &str, params: &Value) -> std::result::Result<Value, Value> {
+        if let Some(error) = self.errors.get(method) {
+            return Err(error.clone());
+        }
+
+        if method == "getLedgerEntries" {
+            let entries: Vec<Value> = params["keys"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|key| key.as_str())
+                .filter_map(|key| {
+                    self.ledger_entries.get(key).map(|(xdr, live_until)| json!({
+                        "key": key,
+                        "xdr": xdr,
+                        "lastModifiedLedgerSeq": MOCK_LATEST_LEDGER - 100,
+                        "liveUntilLedgerSeq": live_until
+                    }))
+                })
+                .collect();
+            return Ok(json!({ "entries": entries, "latestLedger": MOCK_LATEST_LEDGER }));
+        }
+
+        if method == "simulateTransaction" {
+            if let Some(result) = invoked_function(params).and_then(|function| self.simulations.get(&function)) {
+                return Ok(result.clone());
+            }
+        }
+
+        self.results
+            .get(method)
+            .cloned()
+            .ok_or_else(|| json!({ "code": -32601, "message": format!("method not found: {}", method) }))
+    }
+}
+
+/// Contract function invoked by the transaction being simulated
+fn invoked_function(params: &Value) -> Option<String> {
+    let envelope = TransactionEnvelope::from_xdr_base64(params["transaction"].as_str()?, Limits::none()).ok()?;
+    let TransactionEnvelope::Tx(v1) = envelope else {
+        return None;
+    };
+    let OperationBody::InvokeHostFunction(op) = &v1.tx.operations.first()?.body else {
+        return None;
+    };
+    let HostFunction::InvokeContract(args) = &op.host_function else {
+        return None;
+    };
+    Some(args.function_name.0.to_utf8_string_lossy())
+}
+
+/// Local JSON-RPC server standing in for Stellar RPC
+///
+/// Serves canned responses so Soroban routes can be exercised without network access.
+pub struct MockRpc {
+    pub url: String,
+    calls: Arc<Mutex<Vec<(String, Value)>>>,
+    responses: Arc<Mutex<MockRpcResponses>>,
+}
+
+impl MockRpc {
+    pub async fn start(responses: MockRpcResponses) -> Self {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let recorded = calls.clone();
+        let responses = Arc::new(Mutex::new(responses));
+        let served = responses.clone();
+
+        let app = Router::new().route("/", post(move |Json(body): Json<Value>| {
+            let served = served.clone();
+            let recorded = recorded.clone();
+            async move {
+                // Batched requests arrive as an array and are answered with one
+                let requests = match &body {
+                    Value::Array(requests) => requests.clone(),
+                    request => vec![request.clone()],
+                };
+
+                let mut hangs = false;
+                let mut answers = Vec::new();
+                {
+                    let responses = served.lock().unwrap();
+                    let mut recorded = recorded.lock().unwrap();
+                    for request in &requests {
+                        let method = request["method"].as_str().unwrap_or_default().to_string();
+                        recorded.push((method.clone(), request["params"].clone()));
+                        hangs |= responses.hanging.contains(&method);
+
+                        answers.push(match responses.respond(&method, &request["params"]) {
+                            Ok(result) => json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }),
+                            Err(error) => json!({ "jsonrpc": "2.0", "id": request["id"], "error": error }),
+                        });
+                    }
+                }
+                if hangs {
+                    std::future::pending::<()>().await;
+                }
+
+                Json(if body.is_array() { Value::Array(answers) } else { answers.remove(0) })
+            }
+        }));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        Self {
+            url: format!("http://{}", addr),
+            calls,
+            responses,
+        }
+    }
+
+    /// JSON-RPC methods received so far, in order
+    pub fn methods(&self) -> Vec<String> {
+        self.calls.lock().unwrap().iter().map(|(method, _)| method.clone()).collect()
+    }
+
+    /// Replace the result served for `method`, e.g. to simulate a new ledger closing
+    pub fn set_result(&self, method: &str, result: Value) {
+        self.responses.lock().unwrap().results.insert(method.to_string(), result);
+    }
+
+    /// Replace the ledger entry served for `key`, e.g. to simulate a contract upgrade
+    pub fn set_ledger_entry(&self, key: String, xdr: String) {
+        self.responses.lock().unwrap().ledger_entries.insert(key, (xdr, MOCK_LATEST_LEDGER + 10_000));
+    }
+
+    /// Params of each call to `method` received so far, in order
+    pub fn params(&self, method: &str) -> Vec<Value> {
+        self.calls
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(called, _)| called == method)
+            .map(|(_, params)| params.clone())
+            .collect()
+    }
+}
+
+/// Full app wired to a mock RPC, plus the manager so tests can register contracts
+pub struct TestSorobanApp {
+    pub app: Router,
+    pub manager: Arc<ScalableContractManager>,
+}
+
+/// Create a test app whose Soroban routes talk to `rpc` instead of the network
+///
+/// The contract registry starts empty; register contracts with `mock_contract`.
+pub async fn create_test_app_with_rpc(pool: PgPool, rpc: &MockRpc) -> TestSorobanApp {
+    create_test_app_with_queue(pool, rpc, ContractQueue::new()).await
+}
+
+/// Like `create_test_app_with_rpc`, with a submission queue the test controls
+pub async fn create_test_app_with_queue(pool: PgPool, rpc: &MockRpc, queue: ContractQueue) -> TestSorobanApp {
+    let (state, manager) = mock_rpc_state(pool, rpc, queue, test_config());
+
+    TestSorobanApp {
+        app: create_router(state),
+        manager,
+    }
+}
+
+/// Like `create_test_app_with_rpc`, starting from `config` instead of `test_config()`
+pub async fn create_test_app_with_config(pool: PgPool, rpc: &MockRpc, config: AppConfig) -> TestSorobanApp {
+    let (state, manager) = mock_rpc_state(pool, rpc, ContractQueue::new(), config);
+
+    TestSorobanApp {
+        app: create_router(state),
+        manager,
+    }
+}
+
+/// Like `create_test_app_with_rpc`, but running degraded with no database at all
+pub async fn create_test_app_without_database(rpc: &MockRpc) -> TestSorobanApp {
+    let (state, manager) = mock_rpc_state(unavailable_pool(), rpc, ContractQueue::new(), test_config());
+
+    TestSorobanApp {
+        app: create_router(state.without_database()),
+        manager,
+    }
+}
+
+fn mock_rpc_state(pool: PgPool, rpc: &MockRpc, queue: ContractQueue, config: AppConfig) -> (AppState, Arc<ScalableContractManager>) {
+    let config = AppConfig {
+        rpc_url: rpc.url.clone(),
+        ..config
+    };
+
+    let registry = Arc::new(ContractRegistry::new(None, None));
+    let manager = Arc::new(ScalableContractManager::with_registry_and_queue(registry, queue));
+    let state = AppState::with_manager(config, pool, manager.clone())
+        .expect("Failed to create test state");
+
+    (state, manager)
+}
+
+/// Metadata for a testnet contract served by the mock RPC
+pub fn mock_contract(contract_id: &str, rpc: &MockRpc) -> ContractMetadata {
+    ContractMetadata {
+        contract_id: contract_id.to_string(),
+        name: "Mock Contract".to_string(),
+        network: NetworkType::Testnet,
+        network_passphrase: NetworkType::Testnet.default_passphrase().to_string(),
+        rpc_url: rpc.url.clone(),
+        description: None,
+        version: None,
+        enabled: true,
+        pool_size: None,
+        price_oracle: false,
+    }
+}
+
 /// Test user fixture
 #[derive(Debug, Clone)]
 pub struct TestUser {
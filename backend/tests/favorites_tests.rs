@@ -0,0 +1,256 @@
+/// Integration tests for contract favorites
+///
+/// Tests:
+/// - FavoriteRepository add/remove/list scoped per user
+/// - /api/favorites endpoints require authentication
+/// - Endpoints only ever see the caller's own favorites
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode, header},
+};
+use tower::ServiceExt;
+use serde_json::{json, Value};
+
+use stellar_xdr_service::database::repositories::favorite_repository::FavoriteRepository;
+use common::{TestDb, TestUser, create_test_app, response_json};
+
+const ORACLE_CONTRACT: &str = "CCYOZJCOPG34LLQQ7N24YXBM7LL62R7ONMZ3G6WZAAYPB5OYKOMJRN63";
+const HELLO_CONTRACT: &str = "CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF";
+
+fn test_config() -> stellar_xdr_service::AppConfig {
+    stellar_xdr_service::AppConfig {
+        jwt_secret: "test-secret-key-with-minimum-32-characters-for-testing!".to_string(),
+        jwt_expiration_hours: 24,
+        ..stellar_xdr_service::AppConfig::default()
+    }
+}
+
+// ============================================================================
+// REPOSITORY TESTS
+// ============================================================================
+
+#[tokio::test]
+async fn test_add_and_list_favorites() {
+    // Arrange
+    let test_db = TestDb::new().await;
+    let user = TestUser::new().create_in_db(&test_db.pool).await;
+
+    // Act
+    FavoriteRepository::add(&test_db.pool, user.id, ORACLE_CONTRACT, Some("Reflector"))
+        .await
+        .expect("Failed to add favorite");
+    FavoriteRepository::add(&test_db.pool, user.id, HELLO_CONTRACT, None)
+        .await
+        .expect("Failed to add favorite");
+
+    let favorites = FavoriteRepository::list_for_user(&test_db.pool, user.id)
+        .await
+        .expect("Failed to list favorites");
+
+    // Assert - most recent first
+    assert_eq!(favorites.len(), 2);
+    assert_eq!(favorites[0].contract_id, HELLO_CONTRACT);
+    assert_eq!(favorites[1].contract_id, ORACLE_CONTRACT);
+    assert_eq!(favorites[1].label.as_deref(), Some("Reflector"));
+
+    // Cleanup
+    test_db.cleanup().await;
+}
+
+#[tokio::test]
+async fn test_add_favorite_twice_updates_label() {
+    // Arrange
+    let test_db = TestDb::new().await;
+    let user = TestUser::new().create_in_db(&test_db.pool).await;
+
+    // Act
+    FavoriteRepository::add(&test_db.pool, user.id, ORACLE_CONTRACT, Some("Old"))
+        .await
+        .unwrap();
+    FavoriteRepository::add(&test_db.pool, user.id, ORACLE_CONTRACT, Some("New"))
+        .await
+        .unwrap();
+
+    let favorites = FavoriteRepository::list_for_user(&test_db.pool, user.id).await.unwrap();
+
+    // Assert
+    assert_eq!(favorites.len(), 1);
+    assert_eq!(favorites[0].label.as_deref(), Some("New"));
+
+    // Cleanup
+    test_db.cleanup().await;
+}
+
+#[tokio::test]
+async fn test_favorites_are_scoped_to_user() {
+    // Arrange
+    let test_db = TestDb::new().await;
+    let alice = TestUser::new().create_in_db(&test_db.pool).await;
+    let bob = TestUser::new().create_in_db(&test_db.pool).await;
+
+    FavoriteRepository::add(&test_db.pool, alice.id, ORACLE_CONTRACT, None).await.unwrap();
+    FavoriteRepository::add(&test_db.pool, bob.id, HELLO_CONTRACT, None).await.unwrap();
+
+    // Act - Bob cannot remove Alice's favorite
+    let removed_by_bob = FavoriteRepository::remove(&test_db.pool, bob.id, ORACLE_CONTRACT).await.unwrap();
+    let removed_by_alice = FavoriteRepository::remove(&test_db.pool, alice.id, ORACLE_CONTRACT).await.unwrap();
+
+    // Assert
+    assert!(!removed_by_bob);
+    assert!(removed_by_alice);
+    assert!(FavoriteRepository::list_for_user(&test_db.pool, alice.id).await.unwrap().is_empty());
+
+    let bob_favorites = FavoriteRepository::list_for_user(&test_db.pool, bob.id).await.unwrap();
+    assert_eq!(bob_favorites.len(), 1);
+    assert_eq!(bob_favorites[0].contract_id, HELLO_CONTRACT);
+
+    // Cleanup
+    test_db.cleanup().await;
+}
+
+// ============================================================================
+// ENDPOINT TESTS
+// ============================================================================
+
+#[tokio::test]
+async fn test_favorites_endpoint_requires_auth() {
+    // Arrange
+    let test_db = TestDb::new().await;
+    let app = create_test_app(test_db.pool.clone()).await;
+
+    // Act
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/favorites")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Cleanup
+    test_db.cleanup().await;
+}
+
+#[tokio::test]
+async fn test_favorites_crud_via_api() {
+    // Arrange
+    let test_db = TestDb::new().await;
+    let alice = TestUser::new().create_in_db(&test_db.pool).await;
+    let bob = TestUser::new().create_in_db(&test_db.pool).await;
+    let alice_cookie = format!("test_auth={}", alice.get_token(&test_config()));
+    let bob_cookie = format!("test_auth={}", bob.get_token(&test_config()));
+    let app = create_test_app(test_db.pool.clone()).await;
+
+    // Act - Alice adds a favorite
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/favorites")
+                .header(header::COOKIE, &alice_cookie)
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(json!({ "contract_id": ORACLE_CONTRACT, "label": "Reflector" }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Assert - Alice sees it, Bob does not
+    let list = |cookie: String| {
+        let app = app.clone();
+        async move {
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .uri("/api/favorites")
+                        .header(header::COOKIE, cookie)
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let json: Value = response_json(response).await;
+            json["data"].as_array().cloned().unwrap_or_default()
+        }
+    };
+
+    let alice_favorites = list(alice_cookie.clone()).await;
+    assert_eq!(alice_favorites.len(), 1);
+    assert_eq!(alice_favorites[0]["contract_id"], ORACLE_CONTRACT);
+    assert_eq!(alice_favorites[0]["label"], "Reflector");
+    assert!(list(bob_cookie.clone()).await.is_empty());
+
+    // Act - Bob cannot delete Alice's favorite
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("DELETE")
+                .uri(format!("/api/favorites/{}", ORACLE_CONTRACT))
+                .header(header::COOKIE, &bob_cookie)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // Act - Alice deletes it
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("DELETE")
+                .uri(format!("/api/favorites/{}", ORACLE_CONTRACT))
+                .header(header::COOKIE, &alice_cookie)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(list(alice_cookie).await.is_empty());
+
+    // Cleanup
+    test_db.cleanup().await;
+}
+
+#[tokio::test]
+async fn test_add_favorite_rejects_invalid_contract_id() {
+    // Arrange
+    let test_db = TestDb::new().await;
+    let user = TestUser::new().create_in_db(&test_db.pool).await;
+    let app = create_test_app(test_db.pool.clone()).await;
+
+    // Act
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/favorites")
+                .header(header::COOKIE, format!("test_auth={}", user.get_token(&test_config())))
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(json!({ "contract_id": "not-a-contract" }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let json: Value = response_json(response).await;
+    assert_eq!(json["error_type"], "INVALID_CONTRACT_ID");
+
+    // Cleanup
+    test_db.cleanup().await;
+}
@@ -199,6 +199,40 @@ async fn test_cors_headers_present() {
     test_db.cleanup().await;
 }
 
+#[tokio::test]
+async fn test_cors_exposes_diagnostic_headers() {
+    // Arrange
+    let test_db = TestDb::new().await;
+    let app = create_test_app(test_db.pool.clone()).await;
+
+    // Act
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/health")
+                .header(header::ORIGIN, "http://localhost:8080")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    // Assert - Custom headers are readable from browser JS
+    let exposed = response
+        .headers()
+        .get(header::ACCESS_CONTROL_EXPOSE_HEADERS)
+        .and_then(|v| v.to_str().ok())
+        .expect("Access-Control-Expose-Headers should be present")
+        .to_lowercase();
+
+    for name in ["x-request-id", "x-cache", "retry-after", "x-ratelimit-remaining"] {
+        assert!(exposed.contains(name), "{} should be exposed, got: {}", name, exposed);
+    }
+
+    // Cleanup
+    test_db.cleanup().await;
+}
+
 #[tokio::test]
 async fn test_public_endpoints_no_auth() {
     // Arrange
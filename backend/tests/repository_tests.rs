@@ -24,7 +24,7 @@ async fn test_create_guest_user() {
     let user = UserRepository::create_guest(
         &test_db.pool,
         "test_guest",
-        "GATEST123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890"
+        "GCCJQPDA67NK3QOLQ2MGEH4AFQGZ7GR4HQUVZAIHJD5QJAIVYGDOY6XH"
     )
     .await
     .expect("Failed to create guest user");
@@ -32,7 +32,7 @@ async fn test_create_guest_user() {
     // Assert
     assert!(user.id > 0);
     assert_eq!(user.username, "test_guest");
-    assert_eq!(user.wallet_address, "GATEST123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890");
+    assert_eq!(user.wallet_address, "GCCJQPDA67NK3QOLQ2MGEH4AFQGZ7GR4HQUVZAIHJD5QJAIVYGDOY6XH");
     assert_eq!(user.role, "user");
     assert_eq!(user.email_verified, false);
     assert!(user.email.is_none());
@@ -74,7 +74,7 @@ async fn test_create_user_with_password() {
 async fn test_create_duplicate_wallet_address() {
     // Arrange
     let test_db = TestDb::new().await;
-    let wallet = "GADUP123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890";
+    let wallet = "GCPLMIBUGXFT4ABT6VCOHP3PDN2LCOGHMX6ERGRYUCJOR55NXWLDQSCR";
 
     // Create first user
     UserRepository::create_guest(&test_db.pool, "user1", wallet)
@@ -179,7 +179,7 @@ async fn test_find_user_by_email_not_found() {
 async fn test_find_user_by_wallet_address() {
     // Arrange
     let test_db = TestDb::new().await;
-    let wallet = "GAFIND123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890";
+    let wallet = "GDTGIDPIGWWQT6YKONT64LQLVGOQCQWBHHNQE4QUNY2VHC6QOR47ZF47";
 
     UserRepository::create_guest(&test_db.pool, "walletuser", wallet)
         .await
@@ -238,7 +238,7 @@ async fn test_find_user_by_id() {
 async fn test_update_username() {
     // Arrange
     let test_db = TestDb::new().await;
-    let wallet = "GAUPDATE1234567890ABCDEFGHIJKLMNOPQRSTUVWXYZ123456";
+    let wallet = "GAUTOAJ7EGAYCBQGWKTZTMC33IUET47DNGRATAVECOHQ4CSVTBGOIVFI";
 
     UserRepository::create_guest(&test_db.pool, "oldname", wallet)
         .await
@@ -313,7 +313,7 @@ async fn test_link_wallet() {
     .await
     .unwrap();
 
-    let new_wallet = "GALINK123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ123456789";
+    let new_wallet = "GCY3DPNUQDDB2B2TADM3756ZZNU46MLJL2QERZDY7LFN6QTORUH3A4UE";
 
     // Act
     let updated_user = UserRepository::link_wallet(&test_db.pool, user.id, new_wallet)
@@ -339,7 +339,7 @@ async fn test_link_wallet() {
 async fn test_link_wallet_already_taken() {
     // Arrange
     let test_db = TestDb::new().await;
-    let wallet = "GATAKEN123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ1234567";
+    let wallet = "GCMTIHKWZQUIAU3QXH2ETQCEUTHQBIPH5MADGYQRNSAU7WLITHCVZLKP";
 
     // Create user1 with this wallet
     UserRepository::create_guest(&test_db.pool, "user1", wallet)
@@ -409,3 +409,59 @@ async fn test_concurrent_user_creation() {
     // Cleanup
     test_db.cleanup().await;
 }
+
+// ============================================================================
+// WALLET ADDRESS NORMALIZATION
+// ============================================================================
+
+#[tokio::test]
+async fn test_wallet_variants_map_to_same_stored_value() {
+    // Arrange
+    let test_db = TestDb::new().await;
+    let canonical = "GDNNK2PBAGWUGLHFQM3LJZBYPWS4ORA4RZZLXVI5Q77YPTLWXOVPTI5G";
+
+    // Act - Store with surrounding whitespace, look up with other variants
+    let user = UserRepository::create_guest(&test_db.pool, "variant_user", &format!("  {}\n", canonical))
+        .await
+        .expect("Failed to create guest user");
+    let by_lowercase = UserRepository::find_by_wallet_address(&test_db.pool, &canonical.to_lowercase())
+        .await
+        .unwrap();
+    let by_padded = UserRepository::find_by_wallet_address(&test_db.pool, &format!("\t{} ", canonical))
+        .await
+        .unwrap();
+    let duplicate = UserRepository::create_guest(&test_db.pool, "variant_dup", &canonical.to_lowercase()).await;
+
+    // Assert
+    assert_eq!(user.wallet_address, canonical);
+    assert_eq!(by_lowercase.map(|u| u.id), Some(user.id));
+    assert_eq!(by_padded.map(|u| u.id), Some(user.id));
+    assert!(duplicate.is_err(), "Casing variant should hit the uniqueness constraint");
+
+    // Cleanup
+    test_db.cleanup().await;
+}
+
+#[tokio::test]
+async fn test_invalid_wallet_rejected() {
+    // Arrange
+    let test_db = TestDb::new().await;
+
+    // Act - Checksum broken by changing the last character
+    let result = UserRepository::create_guest(
+        &test_db.pool,
+        "bad_wallet",
+        "GDNNK2PBAGWUGLHFQM3LJZBYPWS4ORA4RZZLXVI5Q77YPTLWXOVPTI5H",
+    )
+    .await;
+
+    // Assert
+    assert!(
+        matches!(result, Err(sqlx::Error::InvalidArgument(_))),
+        "Invalid wallet should be rejected before insert: {:?}",
+        result
+    );
+
+    // Cleanup
+    test_db.cleanup().await;
+}
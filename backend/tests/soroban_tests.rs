@@ -13,6 +13,9 @@ use axum::{
 use tower::ServiceExt;
 use serde_json::{json, Value};
 
+use stellar_xdr_service::AppConfig;
+use stellar_xdr_service::services::soroban::{AuthMode, SimulationOptions};
+
 use common::{TestDb, create_test_app, response_json};
 
 // ============================================================================
@@ -161,6 +164,59 @@ async fn test_list_contracts_endpoint() {
     test_db.cleanup().await;
 }
 
+// ============================================================================
+// FUNCTION CATALOG TESTS
+// ============================================================================
+
+#[tokio::test]
+async fn test_list_functions_includes_signatures() {
+    // Arrange
+    let test_db = TestDb::new().await;
+    let app = create_test_app(test_db.pool.clone()).await;
+
+    // Act
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/soroban/functions")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    // Assert - Served without a Soroban manager, one entry per known function
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json: Value = response_json(response).await;
+    let functions = json["functions"].as_array().expect("functions array");
+    assert_eq!(json["count"], functions.len());
+
+    let hello_yew = functions.iter()
+        .find(|f| f["name"] == "hello_yew")
+        .expect("hello_yew should be listed");
+    assert_eq!(hello_yew["signature"], "hello_yew(to: string) -> vec<string>");
+    assert!(!hello_yew["description"].as_str().unwrap().is_empty());
+
+    let test_func = functions.iter()
+        .find(|f| f["name"] == "test_func_123")
+        .expect("test_func_123 should be listed");
+    assert_eq!(test_func["signature"], "test_func_123(param_1: string, param_2: u32) -> string");
+
+    assert!(
+        functions.iter().all(|f| f["name"] != "submit_score"),
+        "submit_score has no usable defaults and should not be listed"
+    );
+
+    let top_scores = functions.iter()
+        .find(|f| f["name"] == "top_scores")
+        .expect("top_scores should be listed");
+    assert_eq!(top_scores["signature"], "top_scores(n: u32) -> vec<(address, u64)>");
+
+    // Cleanup
+    test_db.cleanup().await;
+}
+
 // ============================================================================
 // ERROR HANDLING TESTS
 // ============================================================================
@@ -200,3 +256,250 @@ async fn test_invalid_contract_id_format() {
     // Cleanup
     test_db.cleanup().await;
 }
+
+/// Send a request with a malformed contract id and assert the structured 400
+async fn assert_invalid_contract_id(method: &str, uri: &str, payload: Option<Value>) {
+    // Arrange
+    let test_db = TestDb::new().await;
+    let app = create_test_app(test_db.pool.clone()).await;
+
+    let mut builder = Request::builder().method(method).uri(uri);
+    let body = match payload {
+        Some(payload) => {
+            builder = builder.header(header::CONTENT_TYPE, "application/json");
+            Body::from(serde_json::to_vec(&payload).unwrap())
+        }
+        None => Body::empty(),
+    };
+
+    // Act
+    let response = app.oneshot(builder.body(body).unwrap()).await.unwrap();
+
+    // Assert - Rejected up front, before any RPC work
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{} {} should reject the id", method, uri);
+    let json: Value = response_json(response).await;
+    assert_eq!(json["error_type"], "INVALID_CONTRACT_ID");
+
+    // Cleanup
+    test_db.cleanup().await;
+}
+
+#[tokio::test]
+async fn test_contract_info_rejects_invalid_contract_id() {
+    assert_invalid_contract_id("GET", "/api/soroban/contract/NOT_A_CONTRACT", None).await;
+}
+
+#[tokio::test]
+async fn test_query_events_rejects_invalid_contract_id() {
+    assert_invalid_contract_id("POST", "/api/soroban/events", Some(json!({
+        "contract_id": "NOT_A_CONTRACT",
+        "pagination": { "type": "from", "ledger": 1000 },
+        "filters": []
+    }))).await;
+}
+
+#[tokio::test]
+async fn test_simulate_rejects_invalid_contract_id() {
+    assert_invalid_contract_id("POST", "/api/soroban/simulate", Some(json!({
+        "contract_id": "NOT_A_CONTRACT",
+        "transaction_xdr": "AAAA"
+    }))).await;
+}
+
+#[tokio::test]
+async fn test_contract_data_rejects_invalid_contract_id() {
+    assert_invalid_contract_id("POST", "/api/soroban/contract-data", Some(json!({
+        "contract_id": "NOT_A_CONTRACT",
+        "key": "AAAADwAAAAVBZG1pbgAAAA==",
+        "durability": "persistent"
+    }))).await;
+}
+
+#[tokio::test]
+async fn test_call_function_rejects_invalid_contract_id() {
+    // Right shape, wrong checksum
+    assert_invalid_contract_id("POST", "/api/soroban/call-function", Some(json!({
+        "contract_id": "CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIA",
+        "function_name": "decimals",
+        "parameters": []
+    }))).await;
+}
+
+// ============================================================================
+// SIMULATION AUTH MODE TESTS
+// ============================================================================
+
+#[test]
+fn test_read_simulations_default_to_record_auth_mode() {
+    // Arrange
+    let config = AppConfig::default();
+
+    // Act
+    let implicit = config.read_simulation_options(None);
+    let explicit = config.read_simulation_options(Some(SimulationOptions {
+        cpu_instructions: 1000,
+        auth_mode: Some(AuthMode::Enforce),
+    }));
+
+    // Assert - Record is filled in only when the caller didn't choose
+    assert_eq!(implicit.auth_mode, Some(AuthMode::Record));
+    assert_eq!(explicit.auth_mode, Some(AuthMode::Enforce));
+    assert_eq!(explicit.cpu_instructions, 1000);
+}
+
+// ============================================================================
+// CONTRACT ID CONVERSION TESTS
+// ============================================================================
+
+async fn post_convert_contract_id(contract_id: &str) -> (StatusCode, Value) {
+    let test_db = TestDb::new().await;
+    let app = create_test_app(test_db.pool.clone()).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/soroban/convert-contract-id")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(serde_json::to_vec(&json!({ "contract_id": contract_id })).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let json: Value = response_json(response).await;
+    test_db.cleanup().await;
+    (status, json)
+}
+
+#[tokio::test]
+async fn test_convert_contract_id_strkey_to_hex() {
+    // Arrange
+    let strkey = "CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF";
+
+    // Act
+    let (status, json) = post_convert_contract_id(strkey).await;
+
+    // Assert
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["strkey"], strkey);
+    assert_eq!(json["hex"].as_str().unwrap().len(), 64);
+}
+
+#[tokio::test]
+async fn test_convert_contract_id_hex_to_strkey() {
+    // Arrange - Hex form of the strkey above
+    let (_, strkey_json) = post_convert_contract_id("CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF").await;
+    let hex = strkey_json["hex"].as_str().unwrap().to_string();
+
+    // Act
+    let (status, json) = post_convert_contract_id(&hex).await;
+
+    // Assert
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["strkey"], "CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF");
+    assert_eq!(json["hex"], hex);
+}
+
+#[tokio::test]
+async fn test_convert_contract_id_rejects_bad_checksum() {
+    // Act - Last character changed so the checksum no longer matches
+    let (status, json) = post_convert_contract_id("CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIA").await;
+
+    // Assert
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error_type"], "INVALID_INPUT");
+}
+
+// ============================================================================
+// TTL REPORT TESTS
+// ============================================================================
+
+async fn post_ttl_report(contract_id: &str, payload: Value) -> (StatusCode, Value) {
+    let test_db = TestDb::new().await;
+    let app = create_test_app(test_db.pool.clone()).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/soroban/contract/{}/ttl-report", contract_id))
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(serde_json::to_vec(&payload).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let json: Value = response_json(response).await;
+
+    test_db.cleanup().await;
+    (status, json)
+}
+
+#[tokio::test]
+async fn test_ttl_report_rejects_invalid_contract_id() {
+    assert_invalid_contract_id(
+        "POST",
+        "/api/soroban/contract/NOT_A_CONTRACT/ttl-report",
+        Some(json!({ "keys": [{ "key": "AAAAFA==", "durability": "persistent" }] })),
+    ).await;
+}
+
+#[tokio::test]
+async fn test_ttl_report_requires_keys() {
+    // Act
+    let (status, json) = post_ttl_report(
+        "CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF",
+        json!({ "keys": [] }),
+    ).await;
+
+    // Assert
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error_type"], "INVALID_INPUT");
+}
+
+#[tokio::test]
+async fn test_ttl_report_rejects_malformed_key() {
+    // Act
+    let (status, json) = post_ttl_report(
+        "CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF",
+        json!({ "keys": [{ "key": "not base64 xdr", "durability": "persistent" }] }),
+    ).await;
+
+    // Assert
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error_type"], "INVALID_INPUT");
+}
+
+// ============================================================================
+// ACCOUNT LOOKUP TESTS
+// ============================================================================
+
+#[tokio::test]
+async fn test_account_lookup_rejects_invalid_address() {
+    // Arrange
+    let test_db = TestDb::new().await;
+    let app = create_test_app(test_db.pool.clone()).await;
+
+    // Act
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/account/GNOTANADDRESS")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    // Assert - Rejected before any RPC work
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let json: Value = response_json(response).await;
+    assert_eq!(json["error_type"], "INVALID_INPUT");
+
+    // Cleanup
+    test_db.cleanup().await;
+}
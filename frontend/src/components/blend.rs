@@ -4,26 +4,31 @@ use wasm_bindgen_futures::spawn_local;
 use serde_json;
 
 use shared::dto::soroban::*;
+use crate::services::backend_url;
+use crate::components::JsonTree;
 
-const BACKEND_URL: &str = "http://127.0.0.1:3001";
 const BLEND_POOL_ID: &str = "CDDG7DLOWSHRYQ2HWGZEZ4UTR7LPTKFFHN3QUCSZEXOWOPARMONX6T65";
 const BLEND_BACKSTOP_ID: &str = "CBHWKF4RHIKOKSURAKXSJRIIA7RJAMJH4VHRVPYGUF4AJ5L544LYZ35X";
 
 #[function_component(BlendProtocol)]
 pub fn blend_protocol() -> Html {
     let pool_result = use_state(|| String::from("Click a button to query Blend protocol data"));
+    let pool_json = use_state(|| None::<serde_json::Value>);
     let is_querying = use_state(|| false);
 
     // Query pool config
     let query_pool_config = {
         let pool_result = pool_result.clone();
+        let pool_json = pool_json.clone();
         let is_querying = is_querying.clone();
 
         Callback::from(move |_| {
             let pool_result = pool_result.clone();
+            let pool_json = pool_json.clone();
             let is_querying = is_querying.clone();
 
             is_querying.set(true);
+            pool_json.set(None);
             pool_result.set("🔄 Querying pool configuration...".to_string());
 
             spawn_local(async move {
@@ -32,18 +37,19 @@ pub fn blend_protocol() -> Html {
                     function_name: "get_config".to_string(),
                     parameters: vec![],
                     source_account: None,
+                    return_type: None,
+                    cache_mode: CallCacheMode::Ttl,
                 };
 
-                let url = format!("{}/api/soroban/call-function", BACKEND_URL);
+                let url = format!("{}/api/soroban/call-function", backend_url());
 
                 match Request::post(&url).json(&request).unwrap().send().await {
                     Ok(response) => {
                         if let Ok(response_text) = response.text().await {
-                            if let Ok(data) = serde_json::from_str::<CallContractFunctionResponse>(&response_text) {
+                            if let Ok(data) = CallContractFunctionResponse::from_body(response.ok(), &response_text) {
                                 if data.success {
-                                    let pretty = serde_json::to_string_pretty(&data.result)
-                                        .unwrap_or_else(|_| "Error formatting result".to_string());
-                                    pool_result.set(format!("✅ Pool Config:\n\n{}", pretty));
+                                    pool_result.set("✅ Pool Config".to_string());
+                                    pool_json.set(data.result);
                                 } else {
                                     pool_result.set(format!("❌ Error: {}", data.error.unwrap_or_default()));
                                 }
@@ -62,13 +68,16 @@ pub fn blend_protocol() -> Html {
     // Query pool reserves
     let query_pool_reserves = {
         let pool_result = pool_result.clone();
+        let pool_json = pool_json.clone();
         let is_querying = is_querying.clone();
 
         Callback::from(move |_| {
             let pool_result = pool_result.clone();
+            let pool_json = pool_json.clone();
             let is_querying = is_querying.clone();
 
             is_querying.set(true);
+            pool_json.set(None);
             pool_result.set("🔄 Querying pool reserves...".to_string());
 
             spawn_local(async move {
@@ -77,18 +86,19 @@ pub fn blend_protocol() -> Html {
                     function_name: "get_reserve_list".to_string(),
                     parameters: vec![],
                     source_account: None,
+                    return_type: None,
+                    cache_mode: CallCacheMode::Ttl,
                 };
 
-                let url = format!("{}/api/soroban/call-function", BACKEND_URL);
+                let url = format!("{}/api/soroban/call-function", backend_url());
 
                 match Request::post(&url).json(&request).unwrap().send().await {
                     Ok(response) => {
                         if let Ok(response_text) = response.text().await {
-                            if let Ok(data) = serde_json::from_str::<CallContractFunctionResponse>(&response_text) {
+                            if let Ok(data) = CallContractFunctionResponse::from_body(response.ok(), &response_text) {
                                 if data.success {
-                                    let pretty = serde_json::to_string_pretty(&data.result)
-                                        .unwrap_or_else(|_| "Error formatting result".to_string());
-                                    pool_result.set(format!("✅ Pool Reserves:\n\n{}", pretty));
+                                    pool_result.set("✅ Pool Reserves".to_string());
+                                    pool_json.set(data.result);
                                 } else {
                                     pool_result.set(format!("❌ Error: {}", data.error.unwrap_or_default()));
                                 }
@@ -107,13 +117,16 @@ pub fn blend_protocol() -> Html {
     // Query pool admin
     let query_pool_admin = {
         let pool_result = pool_result.clone();
+        let pool_json = pool_json.clone();
         let is_querying = is_querying.clone();
 
         Callback::from(move |_| {
             let pool_result = pool_result.clone();
+            let pool_json = pool_json.clone();
             let is_querying = is_querying.clone();
 
             is_querying.set(true);
+            pool_json.set(None);
             pool_result.set("🔄 Querying pool admin...".to_string());
 
             spawn_local(async move {
@@ -122,18 +135,19 @@ pub fn blend_protocol() -> Html {
                     function_name: "get_admin".to_string(),
                     parameters: vec![],
                     source_account: None,
+                    return_type: None,
+                    cache_mode: CallCacheMode::Ttl,
                 };
 
-                let url = format!("{}/api/soroban/call-function", BACKEND_URL);
+                let url = format!("{}/api/soroban/call-function", backend_url());
 
                 match Request::post(&url).json(&request).unwrap().send().await {
                     Ok(response) => {
                         if let Ok(response_text) = response.text().await {
-                            if let Ok(data) = serde_json::from_str::<CallContractFunctionResponse>(&response_text) {
+                            if let Ok(data) = CallContractFunctionResponse::from_body(response.ok(), &response_text) {
                                 if data.success {
-                                    let pretty = serde_json::to_string_pretty(&data.result)
-                                        .unwrap_or_else(|_| "Error formatting result".to_string());
-                                    pool_result.set(format!("✅ Pool Admin:\n\n{}", pretty));
+                                    pool_result.set("✅ Pool Admin".to_string());
+                                    pool_json.set(data.result);
                                 } else {
                                     pool_result.set(format!("❌ Error: {}", data.error.unwrap_or_default()));
                                 }
@@ -152,13 +166,16 @@ pub fn blend_protocol() -> Html {
     // Query reward zone
     let query_reward_zone = {
         let pool_result = pool_result.clone();
+        let pool_json = pool_json.clone();
         let is_querying = is_querying.clone();
 
         Callback::from(move |_| {
             let pool_result = pool_result.clone();
+            let pool_json = pool_json.clone();
             let is_querying = is_querying.clone();
 
             is_querying.set(true);
+            pool_json.set(None);
             pool_result.set("🔄 Querying reward zone...".to_string());
 
             spawn_local(async move {
@@ -167,18 +184,19 @@ pub fn blend_protocol() -> Html {
                     function_name: "reward_zone".to_string(),
                     parameters: vec![],
                     source_account: None,
+                    return_type: None,
+                    cache_mode: CallCacheMode::Ttl,
                 };
 
-                let url = format!("{}/api/soroban/call-function", BACKEND_URL);
+                let url = format!("{}/api/soroban/call-function", backend_url());
 
                 match Request::post(&url).json(&request).unwrap().send().await {
                     Ok(response) => {
                         if let Ok(response_text) = response.text().await {
-                            if let Ok(data) = serde_json::from_str::<CallContractFunctionResponse>(&response_text) {
+                            if let Ok(data) = CallContractFunctionResponse::from_body(response.ok(), &response_text) {
                                 if data.success {
-                                    let pretty = serde_json::to_string_pretty(&data.result)
-                                        .unwrap_or_else(|_| "Error formatting result".to_string());
-                                    pool_result.set(format!("✅ Reward Zone:\n\n{}", pretty));
+                                    pool_result.set("✅ Reward Zone".to_string());
+                                    pool_json.set(data.result);
                                 } else {
                                     pool_result.set(format!("❌ Error: {}", data.error.unwrap_or_default()));
                                 }
@@ -197,13 +215,16 @@ pub fn blend_protocol() -> Html {
     // Query backstop token
     let query_backstop = {
         let pool_result = pool_result.clone();
+        let pool_json = pool_json.clone();
         let is_querying = is_querying.clone();
 
         Callback::from(move |_| {
             let pool_result = pool_result.clone();
+            let pool_json = pool_json.clone();
             let is_querying = is_querying.clone();
 
             is_querying.set(true);
+            pool_json.set(None);
             pool_result.set("🔄 Querying backstop data...".to_string());
 
             spawn_local(async move {
@@ -212,18 +233,19 @@ pub fn blend_protocol() -> Html {
                     function_name: "backstop_token".to_string(),
                     parameters: vec![],
                     source_account: None,
+                    return_type: None,
+                    cache_mode: CallCacheMode::Ttl,
                 };
 
-                let url = format!("{}/api/soroban/call-function", BACKEND_URL);
+                let url = format!("{}/api/soroban/call-function", backend_url());
 
                 match Request::post(&url).json(&request).unwrap().send().await {
                     Ok(response) => {
                         if let Ok(response_text) = response.text().await {
-                            if let Ok(data) = serde_json::from_str::<CallContractFunctionResponse>(&response_text) {
+                            if let Ok(data) = CallContractFunctionResponse::from_body(response.ok(), &response_text) {
                                 if data.success {
-                                    let pretty = serde_json::to_string_pretty(&data.result)
-                                        .unwrap_or_else(|_| "Error formatting result".to_string());
-                                    pool_result.set(format!("✅ Backstop Token:\n\n{}", pretty));
+                                    pool_result.set("✅ Backstop Token".to_string());
+                                    pool_json.set(data.result);
                                 } else {
                                     pool_result.set(format!("❌ Error: {}", data.error.unwrap_or_default()));
                                 }
@@ -300,6 +322,11 @@ pub fn blend_protocol() -> Html {
 
             <div class="result-display">
                 <pre>{&*pool_result}</pre>
+                {if let Some(value) = (*pool_json).clone() {
+                    html! { <JsonTree value={value} /> }
+                } else {
+                    html! {}
+                }}
             </div>
         </div>
     }
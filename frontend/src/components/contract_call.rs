@@ -0,0 +1,429 @@
+use yew::prelude::*;
+use gloo_net::http::Request;
+use wasm_bindgen_futures::spawn_local;
+
+use shared::dto::favorites::Favorite;
+use shared::dto::presets::ParameterPreset;
+use shared::dto::soroban::{
+    CallCacheMode, CallContractFunctionRequest, CallContractFunctionResponse, ContractMetadata, FunctionParameter,
+};
+use crate::components::JsonTree;
+use crate::services::{backend_url, ApiClient, SorobanApiClient};
+use crate::services::call_history::{CallHistory, CallHistoryEntry};
+use crate::services::contract_list::pinned_contract_list;
+
+const DEFAULT_CONTRACT_ID: &str = "CCYOZJCOPG34LLQQ7N24YXBM7LL62R7ONMZ3G6WZAAYPB5OYKOMJRN63";
+
+/// Contract call explorer - call any read-only function, with a replayable history
+#[function_component(ContractCallExplorer)]
+pub fn contract_call_explorer() -> Html {
+    let contract_id = use_state(|| DEFAULT_CONTRACT_ID.to_string());
+    let function_name = use_state(|| "decimals".to_string());
+    let parameters_json = use_state(|| "[]".to_string());
+
+    let history = use_state(CallHistory::load);
+    let result = use_state(|| None::<serde_json::Value>);
+    let result_message = use_state(|| String::from("Enter a function and click 'Call'"));
+    let is_calling = use_state(|| false);
+
+    let presets = use_state(Vec::<ParameterPreset>::new);
+
+    let registered = use_state(Vec::<ContractMetadata>::new);
+    let favorites = use_state(Vec::<Favorite>::new);
+    let favorites_message = use_state(|| None::<String>);
+
+    // Load the registered contracts once
+    {
+        let registered = registered.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Ok(response) = SorobanApiClient::new().list_contracts().await {
+                    registered.set(response.contracts);
+                }
+            });
+            || ()
+        });
+    }
+
+    // Load the logged-in user's favorites once
+    {
+        let favorites = favorites.clone();
+        let favorites_message = favorites_message.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                match ApiClient::new().list_favorites().await {
+                    Ok(list) => favorites.set(list),
+                    Err(e) => favorites_message.set(Some(e)),
+                }
+            });
+            || ()
+        });
+    }
+
+    // Fetch presets whenever a complete contract id is entered
+    {
+        let presets = presets.clone();
+        use_effect_with(contract_id.trim().to_string(), move |contract_id| {
+            presets.set(Vec::new());
+            if contract_id.len() == 56 && contract_id.starts_with('C') {
+                let contract_id = contract_id.clone();
+                spawn_local(async move {
+                    if let Ok(response) = SorobanApiClient::new().get_presets(&contract_id).await {
+                        presets.set(response.presets);
+                    }
+                });
+            }
+            || ()
+        });
+    }
+
+    // Send a call and record it in the history once the result comes back
+    let send_call = {
+        let history = history.clone();
+        let result = result.clone();
+        let result_message = result_message.clone();
+        let is_calling = is_calling.clone();
+
+        Callback::from(move |request: CallContractFunctionRequest| {
+            let history = history.clone();
+            let result = result.clone();
+            let result_message = result_message.clone();
+            let is_calling = is_calling.clone();
+
+            is_calling.set(true);
+            result.set(None);
+            result_message.set(format!("🔄 Calling {}...", request.function_name));
+
+            spawn_local(async move {
+                let url = format!("{}/api/soroban/call-function", backend_url());
+
+                let success = match Request::post(&url).json(&request).unwrap().send().await {
+                    Ok(response) => match response.text().await
+                        .map_err(|e| e.to_string())
+                        .and_then(|body| CallContractFunctionResponse::from_body(response.ok(), &body).map_err(|e| e.to_string()))
+                    {
+                        Ok(data) if data.success => {
+                            match data.simulation.as_ref().filter(|s| s.requires_restore) {
+                                Some(simulation) => result_message.set(format!(
+                                    "⚠️ {} reads archived data - restore it for {} stroops before submitting",
+                                    request.function_name,
+                                    simulation.restore_fee_stroops.unwrap_or_default()
+                                )),
+                                None => result_message.set(format!("✅ {} returned", request.function_name)),
+                            }
+                            result.set(data.result);
+                            true
+                        }
+                        Ok(data) => {
+                            result_message.set(format!("❌ Error: {}", data.error.unwrap_or_default()));
+                            false
+                        }
+                        Err(e) => {
+                            result_message.set(format!("❌ Parse Error: {}", e));
+                            false
+                        }
+                    },
+                    Err(e) => {
+                        result_message.set(format!("❌ Request Error: {}", e));
+                        false
+                    }
+                };
+
+                let mut updated = (*history).clone();
+                updated.record(CallHistoryEntry {
+                    request,
+                    success,
+                    timestamp: chrono::Utc::now().timestamp(),
+                });
+                if let Err(e) = updated.save() {
+                    web_sys::console::error_1(&format!("❌ [CALL HISTORY] Failed to save: {}", e).into());
+                }
+                history.set(updated);
+                is_calling.set(false);
+            });
+        })
+    };
+
+    let on_call = {
+        let contract_id = contract_id.clone();
+        let function_name = function_name.clone();
+        let parameters_json = parameters_json.clone();
+        let result_message = result_message.clone();
+        let send_call = send_call.clone();
+
+        Callback::from(move |_| {
+            let parameters = match serde_json::from_str::<Vec<FunctionParameter>>(&parameters_json) {
+                Ok(parameters) => parameters,
+                Err(e) => {
+                    result_message.set(format!("❌ Invalid parameters JSON: {}", e));
+                    return;
+                }
+            };
+
+            let contract_id = contract_id.clone();
+            let function_name = function_name.trim().to_string();
+            let result_message = result_message.clone();
+            let send_call = send_call.clone();
+
+            // Normalize pasted ids (hex or strkey) before calling
+            spawn_local(async move {
+                match SorobanApiClient::new().convert_contract_id(&contract_id).await {
+                    Ok(converted) => {
+                        contract_id.set(converted.strkey.clone());
+                        send_call.emit(CallContractFunctionRequest {
+                            contract_id: converted.strkey,
+                            function_name,
+                            parameters,
+                            source_account: None,
+                            return_type: None,
+                            cache_mode: CallCacheMode::Ttl,
+                        });
+                    }
+                    Err(e) => result_message.set(format!("❌ Invalid contract ID: {}", e)),
+                }
+            });
+        })
+    };
+
+    // Re-populate the form from a history entry and send it again
+    let on_replay = {
+        let contract_id = contract_id.clone();
+        let function_name = function_name.clone();
+        let parameters_json = parameters_json.clone();
+        let send_call = send_call.clone();
+
+        Callback::from(move |entry: CallHistoryEntry| {
+            contract_id.set(entry.request.contract_id.clone());
+            function_name.set(entry.request.function_name.clone());
+            parameters_json.set(
+                serde_json::to_string(&entry.request.parameters).unwrap_or_else(|_| "[]".to_string())
+            );
+            send_call.emit(entry.request);
+        })
+    };
+
+    let on_clear_history = {
+        let history = history.clone();
+        let contract_id = contract_id.clone();
+        Callback::from(move |_| {
+            let mut updated = (*history).clone();
+            updated.clear_contract(contract_id.trim());
+            let _ = updated.save();
+            history.set(updated);
+        })
+    };
+
+    let is_favorite = favorites.iter().any(|f| f.contract_id == contract_id.trim());
+
+    let on_toggle_favorite = {
+        let contract_id = contract_id.clone();
+        let favorites = favorites.clone();
+        let favorites_message = favorites_message.clone();
+        Callback::from(move |_| {
+            let contract_id = contract_id.trim().to_string();
+            let favorites = favorites.clone();
+            let favorites_message = favorites_message.clone();
+
+            spawn_local(async move {
+                let client = ApiClient::new();
+                let mut updated = (*favorites).clone();
+                let result = if is_favorite {
+                    client.remove_favorite(&contract_id).await
+                        .map(|_| updated.retain(|f| f.contract_id != contract_id))
+                } else {
+                    client.add_favorite(&contract_id, None).await
+                        .map(|favorite| updated.insert(0, favorite))
+                };
+
+                match result {
+                    Ok(()) => {
+                        favorites.set(updated);
+                        favorites_message.set(None);
+                    }
+                    Err(e) => favorites_message.set(Some(e)),
+                }
+            });
+        })
+    };
+
+    // Prefill the form from a preset
+    let on_contract_select = {
+        let contract_id = contract_id.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            if !select.value().is_empty() {
+                contract_id.set(select.value());
+            }
+        })
+    };
+
+    let on_preset_change = {
+        let presets = presets.clone();
+        let function_name = function_name.clone();
+        let parameters_json = parameters_json.clone();
+        let result_message = result_message.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let Some(preset) = select.value().parse::<usize>().ok().and_then(|i| presets.get(i)) else {
+                return;
+            };
+
+            function_name.set(preset.function_name.clone());
+            parameters_json.set(
+                serde_json::to_string_pretty(&preset.parameters).unwrap_or_else(|_| "[]".to_string())
+            );
+            result_message.set(if preset.placeholders.is_empty() {
+                format!("Preset loaded: {}", preset.label)
+            } else {
+                format!("Preset loaded: {} - replace the example {}", preset.label, preset.placeholders.join(", "))
+            });
+        })
+    };
+
+    let on_input = |state: UseStateHandle<String>| {
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            state.set(input.value());
+        })
+    };
+
+    let on_parameters_input = {
+        let parameters_json = parameters_json.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            parameters_json.set(input.value());
+        })
+    };
+
+    let calls = history.for_contract(contract_id.trim()).to_vec();
+    let contract_list = pinned_contract_list(&favorites, &registered);
+    let listed = contract_list.iter().any(|item| item.contract_id == contract_id.trim());
+
+    html! {
+        <div class="soroban-test-section contract-call-section">
+            <h2>{"📞 Contract Call Explorer"}</h2>
+            <p class="test-description">
+                {"Call any read-only contract function via simulation. Recent calls are kept in this browser and can be replayed."}
+            </p>
+
+            <div class="contract-call-layout">
+                <div class="test-card">
+                    <label>{"Contracts"}</label>
+                    <select class="function-dropdown" onchange={on_contract_select}>
+                        <option value="" selected={!listed}>{"Choose a contract..."}</option>
+                        { for contract_list.iter().map(|item| html! {
+                            <option value={item.contract_id.clone()} selected={item.contract_id == contract_id.trim()}>
+                                {format!("{}{}", if item.favorite { "★ " } else { "" }, item.label)}
+                            </option>
+                        }) }
+                    </select>
+                    {if let Some(message) = (*favorites_message).clone() {
+                        html! { <p class="test-info">{message}</p> }
+                    } else {
+                        html! {}
+                    }}
+
+                    <label>{"Contract ID"}</label>
+                    <input
+                        class="form-input"
+                        type="text"
+                        value={(*contract_id).clone()}
+                        oninput={on_input(contract_id.clone())}
+                        placeholder="C..."
+                    />
+                    <button class="btn btn-secondary" onclick={on_toggle_favorite}>
+                        {if is_favorite { "★ Unfavorite" } else { "☆ Favorite" }}
+                    </button>
+
+                    {if !presets.is_empty() {
+                        html! {
+                            <>
+                                <label>{"Preset"}</label>
+                                <select class="function-dropdown" onchange={on_preset_change}>
+                                    <option value="" selected=true>{"Choose a preset..."}</option>
+                                    { for presets.iter().enumerate().map(|(i, preset)| html! {
+                                        <option value={i.to_string()}>
+                                            {format!("{} - {}", preset.function_name, preset.label)}
+                                        </option>
+                                    }) }
+                                </select>
+                            </>
+                        }
+                    } else {
+                        html! {}
+                    }}
+
+                    <label>{"Function"}</label>
+                    <input
+                        class="form-input"
+                        type="text"
+                        value={(*function_name).clone()}
+                        oninput={on_input(function_name.clone())}
+                        placeholder="lastprice"
+                    />
+
+                    <label>{"Parameters (JSON)"}</label>
+                    <textarea
+                        class="result-textarea"
+                        value={(*parameters_json).clone()}
+                        oninput={on_parameters_input}
+                        rows="4"
+                        placeholder={r#"[{"type": "symbol", "value": "BTC"}]"#}
+                    />
+
+                    <button class="btn btn-test" onclick={on_call} disabled={*is_calling}>
+                        {if *is_calling { "Calling..." } else { "Call" }}
+                    </button>
+
+                    <p class="test-info">{(*result_message).clone()}</p>
+
+                    {if let Some(value) = (*result).clone() {
+                        html! { <JsonTree value={value} /> }
+                    } else {
+                        html! {}
+                    }}
+                </div>
+
+                <aside class="call-history">
+                    <h3>{"🕘 History"}</h3>
+                    {if calls.is_empty() {
+                        html! { <p class="test-info">{"No calls yet for this contract"}</p> }
+                    } else {
+                        html! {
+                            <>
+                                <ul class="call-history-list">
+                                    { for calls.into_iter().map(|entry| {
+                                        let on_replay = on_replay.clone();
+                                        let replay_entry = entry.clone();
+                                        let called_at = chrono::DateTime::<chrono::Utc>::from_timestamp(entry.timestamp, 0)
+                                            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                                            .unwrap_or_else(|| entry.timestamp.to_string());
+                                        html! {
+                                            <li class="call-history-item">
+                                                <div>
+                                                    <strong>{if entry.success { "✅ " } else { "❌ " }}{&entry.request.function_name}</strong>
+                                                    <span class="call-history-params">
+                                                        {serde_json::to_string(&entry.request.parameters).unwrap_or_default()}
+                                                    </span>
+                                                    <span class="call-history-time">{called_at}</span>
+                                                </div>
+                                                <button
+                                                    class="btn btn-secondary"
+                                                    disabled={*is_calling}
+                                                    onclick={Callback::from(move |_| on_replay.emit(replay_entry.clone()))}
+                                                >
+                                                    {"Replay"}
+                                                </button>
+                                            </li>
+                                        }
+                                    }) }
+                                </ul>
+                                <button class="btn btn-secondary" onclick={on_clear_history}>{"Clear History"}</button>
+                            </>
+                        }
+                    }}
+                </aside>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,246 @@
+use yew::prelude::*;
+use gloo_net::http::Request;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlSelectElement;
+
+use shared::dto::soroban::{DurabilityDto, GetContractDataRequest, GetContractDataResponse, LedgerEntryResultDto};
+use crate::services::{StorageKeyType, TtlStatus, backend_url, build_storage_key, ttl_status};
+use crate::components::JsonTree;
+
+const DEFAULT_CONTRACT_ID: &str = "CAVLP5DH2GJPZMVO7IJY4CVOD5MWEFTJFVPD2YY2FQXOQHRGHK4D6HLP";
+
+/// Contract storage explorer - reads a single storage entry by key
+#[function_component(ContractStorage)]
+pub fn contract_storage() -> Html {
+    let contract_id = use_state(|| DEFAULT_CONTRACT_ID.to_string());
+    let key_type = use_state(|| StorageKeyType::Instance);
+    let key_value = use_state(String::new);
+    let durability = use_state(|| DurabilityDto::Persistent);
+
+    let entry = use_state(|| None::<GetContractDataResponse>);
+    let result_message = use_state(|| String::from("Enter a contract and key, then click 'Read'"));
+    let is_reading = use_state(|| false);
+
+    let on_contract_id_input = {
+        let contract_id = contract_id.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            contract_id.set(input.value());
+        })
+    };
+
+    let on_key_type_change = {
+        let key_type = key_type.clone();
+        Callback::from(move |e: Event| {
+            let target = e.target().unwrap();
+            let select = target.dyn_into::<HtmlSelectElement>().unwrap();
+            if let Some(selected) = StorageKeyType::from_name(&select.value()) {
+                key_type.set(selected);
+            }
+        })
+    };
+
+    let on_key_value_input = {
+        let key_value = key_value.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            key_value.set(input.value());
+        })
+    };
+
+    let on_toggle_durability = {
+        let durability = durability.clone();
+        Callback::from(move |_| {
+            durability.set(match *durability {
+                DurabilityDto::Persistent => DurabilityDto::Temporary,
+                DurabilityDto::Temporary => DurabilityDto::Persistent,
+            });
+        })
+    };
+
+    let on_read = {
+        let contract_id = contract_id.clone();
+        let key_type = key_type.clone();
+        let key_value = key_value.clone();
+        let durability = durability.clone();
+        let entry = entry.clone();
+        let result_message = result_message.clone();
+        let is_reading = is_reading.clone();
+
+        Callback::from(move |_| {
+            let key = match build_storage_key(*key_type, &key_value) {
+                Ok(key) => key,
+                Err(e) => {
+                    result_message.set(format!("❌ Invalid key: {}", e));
+                    return;
+                }
+            };
+
+            let request = GetContractDataRequest {
+                contract_id: contract_id.trim().to_string(),
+                key,
+                durability: *durability,
+            };
+
+            let entry = entry.clone();
+            let result_message = result_message.clone();
+            let is_reading = is_reading.clone();
+
+            is_reading.set(true);
+            entry.set(None);
+            result_message.set("🔄 Reading contract storage...".to_string());
+
+            web_sys::console::log_1(&format!("🗄️ [STORAGE] Reading {} key from {}", key_type.name(), request.contract_id).into());
+
+            spawn_local(async move {
+                let url = format!("{}/api/soroban/contract-data", backend_url());
+
+                match Request::post(&url)
+                    .json(&request)
+                    .unwrap()
+                    .send()
+                    .await
+                {
+                    Ok(response) => {
+                        match response.json::<GetContractDataResponse>().await {
+                            Ok(data) if data.success && data.data.is_some() => {
+                                result_message.set("✅ Entry found".to_string());
+                                entry.set(Some(data));
+                            }
+                            Ok(data) => {
+                                let error = data.error.unwrap_or_else(|| "Unknown error".to_string());
+                                result_message.set(format!("❌ Error: {}", error));
+                            }
+                            Err(e) => {
+                                result_message.set(format!("❌ Parse Error: {}", e));
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        web_sys::console::error_1(&format!("❌ [STORAGE] Request Error: {}", e).into());
+                        result_message.set(format!("❌ Request Error: {}", e));
+                    }
+                }
+                is_reading.set(false);
+            });
+        })
+    };
+
+    let durability_label = match *durability {
+        DurabilityDto::Persistent => "Persistent",
+        DurabilityDto::Temporary => "Temporary",
+    };
+
+    html! {
+        <div class="soroban-test-section contract-storage-section">
+            <h2>{"🗄️ Contract Storage Explorer"}</h2>
+            <p class="test-description">
+                {"Read a single contract storage entry directly from the ledger, along with its TTL."}
+            </p>
+
+            <div class="test-card">
+                <label>{"Contract ID"}</label>
+                <input
+                    class="form-input"
+                    type="text"
+                    value={(*contract_id).clone()}
+                    oninput={on_contract_id_input}
+                    placeholder="C..."
+                />
+
+                <label>{"Key Type"}</label>
+                <select class="function-dropdown" onchange={on_key_type_change}>
+                    { for StorageKeyType::all().into_iter().map(|t| html! {
+                        <option value={t.name()} selected={t == *key_type}>{t.name()}</option>
+                    }) }
+                </select>
+
+                {if key_type.needs_value() {
+                    html! {
+                        <>
+                            <label>{"Key"}</label>
+                            <input
+                                class="form-input"
+                                type="text"
+                                value={(*key_value).clone()}
+                                oninput={on_key_value_input}
+                                placeholder={if *key_type == StorageKeyType::Symbol { "COUNTER" } else { "G... or C..." }}
+                            />
+                        </>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                <div class="button-group">
+                    <button class="btn btn-secondary" onclick={on_toggle_durability}>
+                        {format!("Durability: {}", durability_label)}
+                    </button>
+                    <button
+                        class="btn btn-test"
+                        onclick={on_read}
+                        disabled={*is_reading}
+                    >
+                        {if *is_reading { "Reading..." } else { "Read" }}
+                    </button>
+                </div>
+
+                <p class="test-info">{(*result_message).clone()}</p>
+
+                {if let Some(GetContractDataResponse { data: Some(data), value, latest_ledger, .. }) = (*entry).clone() {
+                    html! { <StorageEntryView entry={data} {value} {latest_ledger} /> }
+                } else {
+                    html! {}
+                }}
+            </div>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct StorageEntryViewProps {
+    entry: LedgerEntryResultDto,
+    /// Stored value decoded by the backend
+    value: Option<serde_json::Value>,
+    /// Ledger the entry was read at
+    latest_ledger: Option<u32>,
+}
+
+/// Decoded view of a ledger entry with its TTL status
+#[function_component(StorageEntryView)]
+fn storage_entry_view(props: &StorageEntryViewProps) -> Html {
+    let entry = &props.entry;
+
+    let ttl_text = match props.latest_ledger {
+        Some(latest_ledger) => match ttl_status(entry.live_until_ledger_seq, latest_ledger) {
+            TtlStatus::Permanent => "No TTL (entry does not expire)".to_string(),
+            TtlStatus::Live { remaining } => format!("✅ Live for {} more ledgers", remaining),
+            TtlStatus::ExpiringSoon { remaining } => format!("⚠️ Expiring in {} ledgers", remaining),
+            TtlStatus::Expired { ledgers_ago } => format!("❌ Expired {} ledgers ago", ledgers_ago),
+        },
+        None => "Unknown (latest ledger not reported)".to_string(),
+    };
+
+    // Fall back to the raw entry when the value couldn't be decoded
+    let json = props.value.clone()
+        .unwrap_or_else(|| serde_json::to_value(entry).unwrap_or_default());
+
+    html! {
+        <div class="storage-entry">
+            <div class="storage-entry-row">
+                <strong>{"Last Modified: "}</strong>
+                {entry.last_modified_ledger_seq.map(|s| s.to_string()).unwrap_or_else(|| "-".to_string())}
+            </div>
+            <div class="storage-entry-row">
+                <strong>{"Live Until: "}</strong>
+                {entry.live_until_ledger_seq.map(|s| s.to_string()).unwrap_or_else(|| "-".to_string())}
+            </div>
+            <div class="storage-entry-row">
+                <strong>{"TTL: "}</strong>
+                {ttl_text}
+            </div>
+            <JsonTree value={json} />
+        </div>
+    }
+}
@@ -0,0 +1,128 @@
+use yew::prelude::*;
+use serde_json::Value;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Nodes deeper than this start collapsed
+const DEFAULT_EXPANDED_DEPTH: usize = 2;
+
+#[derive(Properties, PartialEq)]
+pub struct JsonTreeProps {
+    pub value: Value,
+}
+
+/// Collapsible, syntax-colored JSON viewer
+#[function_component(JsonTree)]
+pub fn json_tree(props: &JsonTreeProps) -> Html {
+    html! {
+        <div class="json-tree">
+            <JsonNode name={None::<String>} value={props.value.clone()} depth={0} />
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct JsonNodeProps {
+    name: Option<String>,
+    value: Value,
+    depth: usize,
+}
+
+#[function_component(JsonNode)]
+fn json_node(props: &JsonNodeProps) -> Html {
+    let expanded = use_state(|| props.depth < DEFAULT_EXPANDED_DEPTH);
+
+    let on_toggle = {
+        let expanded = expanded.clone();
+        Callback::from(move |_| expanded.set(!*expanded))
+    };
+
+    let on_copy = {
+        let value = props.value.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            let text = serde_json::to_string_pretty(&value).unwrap_or_default();
+            copy_to_clipboard(&text);
+        })
+    };
+
+    let key = props.name.as_ref().map(|name| html! {
+        <span class="json-key">{format!("{}: ", name)}</span>
+    });
+
+    let copy_button = html! {
+        <button class="json-copy" title="Copy this node" onclick={on_copy}>{"⧉"}</button>
+    };
+
+    let children: Vec<(String, Value)> = match &props.value {
+        Value::Object(map) => map.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
+        Value::Array(items) => items.iter().enumerate().map(|(i, v)| (i.to_string(), v.clone())).collect(),
+        _ => {
+            return html! {
+                <div class="json-node">
+                    {key}
+                    <span class={value_class(&props.value)}>{props.value.to_string()}</span>
+                    {copy_button}
+                </div>
+            };
+        }
+    };
+
+    html! {
+        <div class="json-node">
+            <span class="json-toggle" onclick={on_toggle}>
+                {if *expanded { "▼ " } else { "▶ " }}
+                {key}
+                <span class="json-summary">{summary(&props.value)}</span>
+            </span>
+            {copy_button}
+            {if *expanded {
+                html! {
+                    <div class="json-children">
+                        { for children.into_iter().map(|(name, value)| html! {
+                            <JsonNode name={Some(name)} value={value} depth={props.depth + 1} />
+                        }) }
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
+
+/// Short description of a container node, e.g. `{3 keys}` or `[5 items]`
+fn summary(value: &Value) -> String {
+    match value {
+        Value::Object(map) => format!("{{{} {}}}", map.len(), if map.len() == 1 { "key" } else { "keys" }),
+        Value::Array(items) => format!("[{} {}]", items.len(), if items.len() == 1 { "item" } else { "items" }),
+        _ => String::new(),
+    }
+}
+
+/// CSS class for syntax coloring a primitive value
+fn value_class(value: &Value) -> &'static str {
+    match value {
+        Value::String(_) => "json-string",
+        Value::Number(_) => "json-number",
+        Value::Bool(_) => "json-bool",
+        Value::Null => "json-null",
+        _ => "",
+    }
+}
+
+/// Write text to the clipboard via `navigator.clipboard.writeText`
+fn copy_to_clipboard(text: &str) {
+    let clipboard = js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("navigator"))
+        .and_then(|navigator| js_sys::Reflect::get(&navigator, &JsValue::from_str("clipboard")));
+
+    if let Ok(clipboard) = clipboard {
+        if let Ok(write_text) = js_sys::Reflect::get(&clipboard, &JsValue::from_str("writeText")) {
+            if let Some(write_text) = write_text.dyn_ref::<js_sys::Function>() {
+                let _ = write_text.call1(&clipboard, &JsValue::from_str(text));
+                return;
+            }
+        }
+    }
+
+    web_sys::console::warn_1(&"⚠️ [JSON TREE] Clipboard API not available".into());
+}
@@ -6,10 +6,14 @@ use std::collections::HashMap;
 use serde_json;
 
 use shared::dto::soroban::*;
+use crate::services::backend_url;
+use crate::services::format::{format_oracle_price, NumberLocale};
 
-const BACKEND_URL: &str = "http://127.0.0.1:3001";
 const REFLECTOR_ORACLE_ID: &str = "CCYOZJCOPG34LLQQ7N24YXBM7LL62R7ONMZ3G6WZAAYPB5OYKOMJRN63";
 
+/// Refresh interval when the user hasn't set one
+const DEFAULT_REFRESH_MS: u32 = 30_000;
+
 // All supported assets on testnet
 const ASSETS: &[&str] = &[
     "BTC", "ETH", "XLM", "SOL", "USDT", "XRP", "USDC",
@@ -25,8 +29,19 @@ struct AssetPrice {
     loading: bool,
 }
 
+/// Props for LivePriceFeed
+#[derive(Properties, PartialEq)]
+pub struct LivePriceFeedProps {
+    /// Auto-refresh interval override (from user settings)
+    #[prop_or_default]
+    pub polling_interval_ms: Option<u32>,
+    /// Number formatting locale (from user settings)
+    #[prop_or_default]
+    pub locale: NumberLocale,
+}
+
 #[function_component(LivePriceFeed)]
-pub fn live_price_feed() -> Html {
+pub fn live_price_feed(props: &LivePriceFeedProps) -> Html {
     let prices = use_state(|| {
         let mut map = HashMap::new();
         for &asset in ASSETS {
@@ -47,34 +62,9 @@ pub fn live_price_feed() -> Html {
     let is_paused = use_state(|| false);
 
     // Format price with 14 decimals
-    let format_price = |price_str: &str| -> String {
-        if let Ok(price) = price_str.parse::<f64>() {
-            let actual_price = price / 100_000_000_000_000.0;
-
-            if actual_price >= 1000.0 {
-                let formatted = format!("{:.2}", actual_price);
-                let parts: Vec<&str> = formatted.split('.').collect();
-                let whole = parts[0];
-                let decimal = if parts.len() > 1 { parts[1] } else { "00" };
-                let mut result = String::new();
-                let chars: Vec<char> = whole.chars().collect();
-                for (i, ch) in chars.iter().enumerate() {
-                    if i > 0 && (chars.len() - i) % 3 == 0 {
-                        result.push(',');
-                    }
-                    result.push(*ch);
-                }
-                format!("${}.{}", result, decimal)
-            } else if actual_price >= 1.0 {
-                format!("${:.4}", actual_price)
-            } else if actual_price >= 0.01 {
-                format!("${:.6}", actual_price)
-            } else {
-                format!("${:.8}", actual_price)
-            }
-        } else {
-            "—".to_string()
-        }
+    let locale = props.locale;
+    let format_price = move |price_str: &str| -> String {
+        format_oracle_price(price_str, locale).unwrap_or_else(|| "—".to_string())
     };
 
     // Fetch price for a single asset
@@ -96,14 +86,16 @@ pub fn live_price_feed() -> Html {
                         )
                     ],
                     source_account: None,
+                    return_type: None,
+                    cache_mode: CallCacheMode::Ledger,
                 };
 
-                let url = format!("{}/api/soroban/call-function", BACKEND_URL);
+                let url = format!("{}/api/soroban/call-function", backend_url());
 
                 match Request::post(&url).json(&request).unwrap().send().await {
                     Ok(response) => {
                         if let Ok(response_text) = response.text().await {
-                            if let Ok(data) = serde_json::from_str::<CallContractFunctionResponse>(&response_text) {
+                            if let Ok(data) = CallContractFunctionResponse::from_body(response.ok(), &response_text) {
                                 let mut new_prices = (*prices).clone();
 
                                 if data.success {
@@ -162,14 +154,14 @@ pub fn live_price_feed() -> Html {
         let fetch_price = fetch_price.clone();
         let is_paused = is_paused.clone();
 
-        use_effect_with((), move |_| {
+        use_effect_with(props.polling_interval_ms.unwrap_or(DEFAULT_REFRESH_MS), move |&polling_interval_ms| {
             // Initial fetch for all assets
             for &asset in ASSETS {
                 fetch_price(asset.to_string());
             }
 
-            // Auto-refresh every 30 seconds
-            let interval = Interval::new(30_000, move || {
+            // Auto-refresh at the configured interval
+            let interval = Interval::new(polling_interval_ms, move || {
                 if !*is_paused {
                     for &asset in ASSETS {
                         fetch_price(asset.to_string());
@@ -7,6 +7,9 @@ pub mod soroban_metrics_live;
 pub mod reflector_oracle;
 pub mod live_price_feed;
 pub mod blend;
+pub mod contract_storage;
+pub mod json_tree;
+pub mod contract_call;
 
 pub use navigation::Navigation;
 pub use contract::ContractSection;
@@ -15,4 +18,7 @@ pub use soroban_test::SorobanTestSection;
 pub use soroban_metrics_live::SorobanMetricsLive;
 pub use reflector_oracle::ReflectorOracleSection;
 pub use live_price_feed::LivePriceFeed;
-pub use blend::BlendProtocol;
\ No newline at end of file
+pub use blend::BlendProtocol;
+pub use contract_storage::ContractStorage;
+pub use json_tree::JsonTree;
+pub use contract_call::ContractCallExplorer;
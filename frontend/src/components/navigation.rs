@@ -1,6 +1,9 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use shared::dto::account::AccountResponse;
 use crate::Route;
+use crate::services::ApiClient;
 
 #[derive(Properties, PartialEq)]
 pub struct NavigationProps {
@@ -42,6 +45,46 @@ pub fn navigation(props: &NavigationProps) -> Html {
                             {"Blend"}
                         </Link<Route>>
                     </li>
+                    <li>
+                        <Link<Route>
+                            to={Route::Storage}
+                            classes={if *current_route == "/storage" { "nav-link active" } else { "nav-link" }}
+                        >
+                            {"Storage"}
+                        </Link<Route>>
+                    </li>
+                    <li>
+                        <Link<Route>
+                            to={Route::Call}
+                            classes={if *current_route == "/call" { "nav-link active" } else { "nav-link" }}
+                        >
+                            {"Call"}
+                        </Link<Route>>
+                    </li>
+                    <li>
+                        <Link<Route>
+                            to={Route::Leaderboard}
+                            classes={if *current_route == "/leaderboard" { "nav-link active" } else { "nav-link" }}
+                        >
+                            {"Leaderboard"}
+                        </Link<Route>>
+                    </li>
+                    <li>
+                        <Link<Route>
+                            to={Route::Payment}
+                            classes={if *current_route == "/payment" { "nav-link active" } else { "nav-link" }}
+                        >
+                            {"Pay"}
+                        </Link<Route>>
+                    </li>
+                    <li>
+                        <Link<Route>
+                            to={Route::Settings}
+                            classes={if *current_route == "/settings" { "nav-link active" } else { "nav-link" }}
+                        >
+                            {"Settings"}
+                        </Link<Route>>
+                    </li>
                     {
                         if let Some(wallet) = &props.connected_wallet {
                             html! {
@@ -50,6 +93,7 @@ pub fn navigation(props: &NavigationProps) -> Html {
                                         <span class="user-badge">
                                             {&wallet[..6]}{"..."}{&wallet[wallet.len()-4..]}
                                         </span>
+                                        <BalanceChip address={wallet.clone()} />
                                     </li>
                                     <li>
                                         <button
@@ -88,6 +132,48 @@ pub fn navigation(props: &NavigationProps) -> Html {
     }
 }
 
+/// XLM balance of the connected wallet
+#[derive(Properties, PartialEq)]
+pub struct BalanceChipProps {
+    pub address: String,
+}
+
+#[function_component(BalanceChip)]
+pub fn balance_chip(props: &BalanceChipProps) -> Html {
+    let account = use_state(|| None::<Result<AccountResponse, String>>);
+
+    {
+        let account = account.clone();
+        use_effect_with(props.address.clone(), move |address| {
+            let address = address.clone();
+            account.set(None);
+            spawn_local(async move {
+                account.set(Some(ApiClient::new().get_account(&address).await));
+            });
+            || ()
+        });
+    }
+
+    let (class, label, title) = match &*account {
+        None => ("balance-chip", "… XLM".to_string(), String::new()),
+        Some(Ok(account)) if account.funded => (
+            "balance-chip",
+            format!("{} XLM", account.balance.as_deref().unwrap_or("0")),
+            format!("{} sub-entries", account.num_sub_entries.unwrap_or(0)),
+        ),
+        Some(Ok(_)) => (
+            "balance-chip balance-chip-unfunded",
+            "Account not found — fund it".to_string(),
+            "This address has no ledger entry yet. Fund it (e.g. with Friendbot on testnet) to activate it.".to_string(),
+        ),
+        Some(Err(e)) => ("balance-chip balance-chip-unfunded", "Balance unavailable".to_string(), e.clone()),
+    };
+
+    html! {
+        <span class={class} title={title}>{label}</span>
+    }
+}
+
 /// Dark mode toggle button component
 #[derive(Properties, PartialEq)]
 pub struct DarkModeToggleProps {
@@ -5,59 +5,39 @@ use serde_json;
 
 use shared::dto::soroban::*;
 use crate::components::LivePriceFeed;
+use crate::services::backend_url;
+use crate::services::format::{self, NumberLocale};
 
 /// Format oracle price with 14 decimals to human-readable USD value
-fn format_oracle_price(price_str: &str, _asset: &str) -> String {
-    if let Ok(price) = price_str.parse::<f64>() {
-        let actual_price = price / 100_000_000_000_000.0; // Divide by 10^14
-
-        // Use appropriate precision based on price magnitude
-        if actual_price >= 1000.0 {
-            // Add thousands separator manually for large values
-            let formatted = format!("{:.2}", actual_price);
-            add_thousands_separator(&formatted)
-        } else if actual_price >= 1.0 {
-            format!("${:.4}", actual_price) // Medium values: $227.2790
-        } else if actual_price >= 0.01 {
-            format!("${:.6}", actual_price) // Small values: $0.393352
-        } else {
-            format!("${:.8}", actual_price) // Very small values
-        }
-    } else {
-        format!("Invalid price: {}", price_str)
-    }
-}
-
-/// Add thousands separator to a formatted number string
-fn add_thousands_separator(num_str: &str) -> String {
-    let parts: Vec<&str> = num_str.split('.').collect();
-    let whole = parts[0];
-    let decimal = if parts.len() > 1 { parts[1] } else { "00" };
-
-    let mut result = String::new();
-    let chars: Vec<char> = whole.chars().collect();
-    for (i, ch) in chars.iter().enumerate() {
-        if i > 0 && (chars.len() - i) % 3 == 0 {
-            result.push(',');
-        }
-        result.push(*ch);
-    }
-
-    format!("${}.{}", result, decimal)
+fn format_oracle_price(price_str: &str, locale: NumberLocale) -> String {
+    format::format_oracle_price(price_str, locale)
+        .unwrap_or_else(|| format!("Invalid price: {}", price_str))
 }
 
-const BACKEND_URL: &str = "http://127.0.0.1:3001";
 // Note: On testnet, the FX oracle handles both crypto and fiat prices
 // The "crypto" oracle (CAVLP...) only supports Stellar DEX assets (contract addresses)
 const REFLECTOR_ORACLE_ID: &str = "CCYOZJCOPG34LLQQ7N24YXBM7LL62R7ONMZ3G6WZAAYPB5OYKOMJRN63"; // Using FX oracle for both
 const REFLECTOR_FX_ID: &str = "CCYOZJCOPG34LLQQ7N24YXBM7LL62R7ONMZ3G6WZAAYPB5OYKOMJRN63";
 
+/// Props for ReflectorOracleSection
+#[derive(Properties, PartialEq)]
+pub struct ReflectorOracleSectionProps {
+    /// Auto-refresh interval override (from user settings)
+    #[prop_or_default]
+    pub polling_interval_ms: Option<u32>,
+    /// Number formatting locale (from user settings)
+    #[prop_or_default]
+    pub locale: NumberLocale,
+}
+
 #[function_component(ReflectorOracleSection)]
-pub fn reflector_oracle_section() -> Html {
+pub fn reflector_oracle_section(props: &ReflectorOracleSectionProps) -> Html {
+    let locale = props.locale;
     let price_result = use_state(|| String::from("Select an asset to query its price"));
     let fx_result = use_state(|| String::from("Select a currency pair to query FX rate"));
     let info_result = use_state(|| String::from("Click 'Get Oracle Info' to view contract details"));
     let events_result = use_state(|| String::from("Click 'Query Events' to view oracle contract events"));
+    let price_events = use_state(Vec::<ReflectorPriceEventDto>::new);
 
     let is_querying_price = use_state(|| false);
     let is_querying_fx = use_state(|| false);
@@ -94,9 +74,11 @@ pub fn reflector_oracle_section() -> Html {
                         )
                     ],
                     source_account: None,
+                    return_type: None,
+                    cache_mode: CallCacheMode::Ttl,
                 };
 
-                let url = format!("{}/api/soroban/call-function", BACKEND_URL);
+                let url = format!("{}/api/soroban/call-function", backend_url());
                 web_sys::console::log_1(&format!("📤 [REFLECTOR CRYPTO] POST {}", url).into());
                 web_sys::console::log_1(&format!("📦 [REFLECTOR CRYPTO] Calling lastprice({}) on {}", asset_name, REFLECTOR_ORACLE_ID).into());
 
@@ -113,7 +95,7 @@ pub fn reflector_oracle_section() -> Html {
                         let response_text = response.text().await.unwrap_or_else(|_| "Failed to read response".to_string());
                         web_sys::console::log_1(&format!("📄 [REFLECTOR CRYPTO] Response body ({} bytes): {}", response_text.len(), response_text).into());
 
-                        match serde_json::from_str::<CallContractFunctionResponse>(&response_text) {
+                        match CallContractFunctionResponse::from_body(response.ok(), &response_text) {
                             Ok(data) => {
                                 web_sys::console::log_1(&"✅ [REFLECTOR CRYPTO] Successfully parsed response".into());
                                 if data.success {
@@ -123,7 +105,7 @@ pub fn reflector_oracle_section() -> Html {
                                             result_obj.get("price").and_then(|p| p.as_str()),
                                             result_obj.get("timestamp").and_then(|t| t.as_u64())
                                         ) {
-                                            let formatted_price = format_oracle_price(price, asset_name);
+                                            let formatted_price = format_oracle_price(price, locale);
                                             let datetime = chrono::DateTime::<chrono::Utc>::from_timestamp(timestamp as i64, 0)
                                                 .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
                                                 .unwrap_or_else(|| timestamp.to_string());
@@ -194,9 +176,11 @@ pub fn reflector_oracle_section() -> Html {
                         )
                     ],
                     source_account: None,
+                    return_type: None,
+                    cache_mode: CallCacheMode::Ttl,
                 };
 
-                let url = format!("{}/api/soroban/call-function", BACKEND_URL);
+                let url = format!("{}/api/soroban/call-function", backend_url());
                 web_sys::console::log_1(&format!("📤 [REFLECTOR FX] POST {}", url).into());
                 web_sys::console::log_1(&format!("📦 [REFLECTOR FX] Calling lastprice({}) on {}", pair_name, REFLECTOR_FX_ID).into());
 
@@ -213,7 +197,7 @@ pub fn reflector_oracle_section() -> Html {
                         let response_text = response.text().await.unwrap_or_else(|_| "Failed to read response".to_string());
                         web_sys::console::log_1(&format!("📄 [REFLECTOR FX] Response body ({} bytes): {}", response_text.len(), response_text).into());
 
-                        match serde_json::from_str::<CallContractFunctionResponse>(&response_text) {
+                        match CallContractFunctionResponse::from_body(response.ok(), &response_text) {
                             Ok(data) => {
                                 web_sys::console::log_1(&"✅ [REFLECTOR FX] Successfully parsed response".into());
                                 if data.success {
@@ -223,7 +207,7 @@ pub fn reflector_oracle_section() -> Html {
                                             result_obj.get("price").and_then(|p| p.as_str()),
                                             result_obj.get("timestamp").and_then(|t| t.as_u64())
                                         ) {
-                                            let formatted_price = format_oracle_price(price, pair_name);
+                                            let formatted_price = format_oracle_price(price, locale);
                                             let datetime = chrono::DateTime::<chrono::Utc>::from_timestamp(timestamp as i64, 0)
                                                 .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
                                                 .unwrap_or_else(|| timestamp.to_string());
@@ -280,7 +264,7 @@ pub fn reflector_oracle_section() -> Html {
             web_sys::console::log_1(&format!("📍 [REFLECTOR INFO] Oracle Contract: {}", REFLECTOR_ORACLE_ID).into());
 
             spawn_local(async move {
-                let url = format!("{}/api/soroban/contract/{}", BACKEND_URL, REFLECTOR_ORACLE_ID);
+                let url = format!("{}/api/soroban/contract/{}", backend_url(), REFLECTOR_ORACLE_ID);
                 web_sys::console::log_1(&format!("📤 [REFLECTOR INFO] GET {}", url).into());
 
                 match Request::get(&url)
@@ -322,13 +306,16 @@ pub fn reflector_oracle_section() -> Html {
     // Query Oracle Events Handler
     let on_query_events = {
         let events_result = events_result.clone();
+        let price_events = price_events.clone();
         let is_querying_events = is_querying_events.clone();
 
         Callback::from(move |_| {
             let events_result = events_result.clone();
+            let price_events = price_events.clone();
             let is_querying_events = is_querying_events.clone();
 
             is_querying_events.set(true);
+            price_events.set(Vec::new());
             events_result.set("🔄 Querying oracle contract events...".to_string());
 
             web_sys::console::log_1(&"📡 [REFLECTOR EVENTS] Starting events query".into());
@@ -344,9 +331,10 @@ pub fn reflector_oracle_section() -> Html {
                     }],
                     pagination: EventPagination::From { ledger: 0 },
                     limit: Some(10),
+                    subscription_id: None,
                 };
 
-                let url = format!("{}/api/soroban/events", BACKEND_URL);
+                let url = format!("{}/api/soroban/events", backend_url());
                 web_sys::console::log_1(&format!("📤 [REFLECTOR EVENTS] POST {}", url).into());
 
                 match Request::post(&url)
@@ -368,6 +356,9 @@ pub fn reflector_oracle_section() -> Html {
                                 if data.success {
                                     let events_count = data.events.events.len();
                                     let mut result = format!("✅ Found {} events\n\n", events_count);
+                                    if let Some(ledger) = data.events.clamped_to {
+                                        result.push_str(&format!("ℹ️ Older ledgers pruned, queried from ledger {}\n\n", ledger));
+                                    }
 
                                     for (i, event) in data.events.events.iter().enumerate() {
                                         result.push_str(&format!(
@@ -396,6 +387,11 @@ pub fn reflector_oracle_section() -> Html {
                                     }
 
                                     events_result.set(result);
+                                    price_events.set(
+                                        data.events.events.iter()
+                                            .filter_map(|event| event.price_update.clone())
+                                            .collect()
+                                    );
                                 } else {
                                     events_result.set("❌ Failed to query events".to_string());
                                 }
@@ -426,7 +422,7 @@ pub fn reflector_oracle_section() -> Html {
             </p>
 
             // Live Price Feed - Auto-updating prices for all assets
-            <LivePriceFeed />
+            <LivePriceFeed polling_interval_ms={props.polling_interval_ms} locale={props.locale} />
 
             <div class="oracle-grid">
                 // Crypto Price Queries
@@ -611,6 +607,35 @@ pub fn reflector_oracle_section() -> Html {
                     >
                         {if *is_querying_events { "Querying..." } else { "Query Events" }}
                     </button>
+                    {if !price_events.is_empty() {
+                        html! {
+                            <table class="oracle-price-table">
+                                <thead>
+                                    <tr>
+                                        <th>{"Asset"}</th>
+                                        <th>{"Price"}</th>
+                                        <th>{"Updated"}</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    { for price_events.iter().map(|update| {
+                                        let updated = chrono::DateTime::<chrono::Utc>::from_timestamp(update.timestamp as i64, 0)
+                                            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                                            .unwrap_or_else(|| update.timestamp.to_string());
+                                        html! {
+                                            <tr>
+                                                <td>{&update.asset}</td>
+                                                <td>{format_oracle_price(&update.price, locale)}</td>
+                                                <td>{updated}</td>
+                                            </tr>
+                                        }
+                                    }) }
+                                </tbody>
+                            </table>
+                        }
+                    } else {
+                        html! {}
+                    }}
                     <textarea
                         class="result-textarea"
                         readonly=true
@@ -3,8 +3,19 @@ use gloo_timers::callback::Interval;
 use crate::services::SorobanApiClient;
 use shared::dto::soroban::{MetricsResponse, SorobanHealthResponse};
 
+/// Refresh interval when the user hasn't set one
+const DEFAULT_REFRESH_MS: u32 = 10_000;
+
+/// Props for SorobanMetrics
+#[derive(Properties, PartialEq)]
+pub struct SorobanMetricsProps {
+    /// Auto-refresh interval override (from user settings)
+    #[prop_or_default]
+    pub polling_interval_ms: Option<u32>,
+}
+
 #[function_component(SorobanMetrics)]
-pub fn soroban_metrics() -> Html {
+pub fn soroban_metrics(props: &SorobanMetricsProps) -> Html {
     let metrics = use_state(|| Option::<MetricsResponse>::None);
     let health = use_state(|| Option::<SorobanHealthResponse>::None);
     let error = use_state(|| Option::<String>::None);
@@ -32,13 +43,13 @@ pub fn soroban_metrics() -> Html {
         });
     }
 
-    // Auto-refresh every 10 seconds
+    // Auto-refresh at the configured interval
     {
         let metrics = metrics.clone();
         let health = health.clone();
 
-        use_effect_with((), move |_| {
-            let interval = Interval::new(10_000, move || {
+        use_effect_with(props.polling_interval_ms.unwrap_or(DEFAULT_REFRESH_MS), move |&polling_interval_ms| {
+            let interval = Interval::new(polling_interval_ms, move || {
                 let metrics = metrics.clone();
                 let health = health.clone();
 
@@ -4,12 +4,22 @@ use wasm_bindgen_futures::spawn_local;
 use gloo_timers::callback::Interval;
 
 use shared::dto::soroban::*;
+use crate::services::backend_url;
 
-const BACKEND_URL: &str = "http://127.0.0.1:3001";
 const CONTRACT_ID: &str = "CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF";
+/// Refresh interval when the user hasn't set one
+const DEFAULT_REFRESH_MS: u32 = 2_000;
+
+/// Props for SorobanMetricsLive
+#[derive(Properties, PartialEq)]
+pub struct SorobanMetricsLiveProps {
+    /// Auto-refresh interval override (from user settings)
+    #[prop_or_default]
+    pub polling_interval_ms: Option<u32>,
+}
 
 #[function_component(SorobanMetricsLive)]
-pub fn soroban_metrics_live() -> Html {
+pub fn soroban_metrics_live(props: &SorobanMetricsLiveProps) -> Html {
     let metrics = use_state(|| None::<ContractMetrics>);
     let health = use_state(|| None::<HealthStatus>);
     let circuit_stats = use_state(|| None::<CircuitBreakerStats>);
@@ -36,21 +46,21 @@ pub fn soroban_metrics_live() -> Html {
 
             spawn_local(async move {
                 // Fetch metrics
-                if let Ok(response) = Request::get(&format!("{}/api/soroban/metrics", BACKEND_URL)).send().await {
+                if let Ok(response) = Request::get(&format!("{}/api/soroban/metrics", backend_url())).send().await {
                     if let Ok(data) = response.json::<MetricsResponse>().await {
                         metrics.set(Some(data.metrics));
                     }
                 }
 
                 // Fetch health
-                if let Ok(response) = Request::get(&format!("{}/api/soroban/health", BACKEND_URL)).send().await {
+                if let Ok(response) = Request::get(&format!("{}/api/soroban/health", backend_url())).send().await {
                     if let Ok(data) = response.json::<SorobanHealthResponse>().await {
                         health.set(Some(data.health));
                     }
                 }
 
                 // Fetch contract info (includes circuit breaker, pool, cache)
-                if let Ok(response) = Request::get(&format!("{}/api/soroban/contract/{}", BACKEND_URL, CONTRACT_ID)).send().await {
+                if let Ok(response) = Request::get(&format!("{}/api/soroban/contract/{}", backend_url(), CONTRACT_ID)).send().await {
                     if let Ok(data) = response.json::<ContractInfoResponse>().await {
                         circuit_stats.set(Some(data.info.circuit_breaker_stats));
                         pool_stats.set(Some(data.info.pool_stats));
@@ -69,15 +79,15 @@ pub fn soroban_metrics_live() -> Html {
         })
     };
 
-    // Auto-refresh every 2 seconds
+    // Auto-refresh at the configured interval
     {
         let fetch_metrics = fetch_metrics.clone();
-        use_effect_with((), move |_| {
+        use_effect_with(props.polling_interval_ms.unwrap_or(DEFAULT_REFRESH_MS), move |&polling_interval_ms| {
             // Initial fetch
             fetch_metrics.emit(());
 
             // Set up interval for auto-refresh
-            let interval = Interval::new(2000, move || {
+            let interval = Interval::new(polling_interval_ms, move || {
                 fetch_metrics.emit(());
             });
 
@@ -254,6 +264,17 @@ pub fn soroban_metrics_live() -> Html {
                                         <span class="detail-value">{if c.is_open { "Protecting" } else { "Normal" }}</span>
                                     </div>
                                 </div>
+                                {if let Some(last_error) = c.last_error.as_ref() {
+                                    html! {
+                                        <div class="circuit-last-error">
+                                            <div class="circuit-last-error-label">{"Last error"}</div>
+                                            <div class="circuit-last-error-message">{&last_error.message}</div>
+                                            <div class="circuit-last-error-at">{&last_error.at}</div>
+                                        </div>
+                                    }
+                                } else {
+                                    html! {}
+                                }}
                             </>
                         }
                     } else {
@@ -289,6 +310,12 @@ pub fn soroban_metrics_live() -> Html {
                                         <span>{"Utilization:"}</span>
                                         <span class="detail-value">{format!("{:.1}%", usage_pct)}</span>
                                     </div>
+                                    <div class="detail-row">
+                                        <span>{"Peak:"}</span>
+                                        <span class={if p.saturation_alerts > 0 { "detail-value warning" } else { "detail-value" }}>
+                                            {format!("{:.1}%", p.peak_utilization * 100.0)}
+                                        </span>
+                                    </div>
                                 </div>
                             </>
                         }
@@ -327,6 +354,14 @@ pub fn soroban_metrics_live() -> Html {
                                         <span>{"Failed:"}</span>
                                         <span class="detail-value error">{m.failed_operations}</span>
                                     </div>
+                                    <div class="detail-row">
+                                        <span>{"Simulations In Flight:"}</span>
+                                        <span class="detail-value">{format!("{} / {}", m.simulations_in_flight, m.max_concurrent_simulations)}</span>
+                                    </div>
+                                    <div class="detail-row">
+                                        <span>{"Simulations Rejected:"}</span>
+                                        <span class="detail-value error">{m.simulations_rejected}</span>
+                                    </div>
                                 </div>
                             </>
                         }
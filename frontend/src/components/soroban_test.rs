@@ -4,13 +4,15 @@ use wasm_bindgen_futures::spawn_local;
 use serde_json;
 
 use shared::dto::soroban::*;
+use crate::services::backend_url;
+use crate::components::JsonTree;
 
-const BACKEND_URL: &str = "http://127.0.0.1:3001";
 const CONTRACT_ID: &str = "CCFF5EA2CKR6VTHUTEKN7LNA26EPRSLZ6ZVBZFI2TRNTTD5C24BOKUIF";
 
 #[function_component(SorobanTestSection)]
 pub fn soroban_test_section() -> Html {
     let events_result = use_state(|| String::from("Click 'Query Events' to test"));
+    let events_json = use_state(|| None::<serde_json::Value>);
     let simulation_result = use_state(|| String::from("Click 'Simulate Transaction' to test"));
     let state_result = use_state(|| String::from("Click 'Get Contract State' to test"));
 
@@ -21,13 +23,16 @@ pub fn soroban_test_section() -> Html {
     // Query Events Handler
     let on_query_events = {
         let events_result = events_result.clone();
+        let events_json = events_json.clone();
         let is_querying_events = is_querying_events.clone();
 
         Callback::from(move |_| {
             let events_result = events_result.clone();
+            let events_json = events_json.clone();
             let is_querying_events = is_querying_events.clone();
 
             is_querying_events.set(true);
+            events_json.set(None);
             events_result.set("🔄 Querying events...".to_string());
 
             spawn_local(async move {
@@ -40,9 +45,10 @@ pub fn soroban_test_section() -> Html {
                         topics: vec![],
                     }],
                     limit: Some(10),
+                    subscription_id: None,
                 };
 
-                match Request::post(&format!("{}/api/soroban/events", BACKEND_URL))
+                match Request::post(&format!("{}/api/soroban/events", backend_url()))
                     .json(&request)
                     .unwrap()
                     .send()
@@ -51,9 +57,11 @@ pub fn soroban_test_section() -> Html {
                     Ok(response) => {
                         match response.json::<QueryEventsResponse>().await {
                             Ok(data) => {
-                                let pretty = serde_json::to_string_pretty(&data)
-                                    .unwrap_or_else(|_| "Error formatting response".to_string());
-                                events_result.set(format!("✅ Success:\n\n{}", pretty));
+                                let clamped = data.events.clamped_to
+                                    .map(|ledger| format!(" (start clamped to ledger {})", ledger))
+                                    .unwrap_or_default();
+                                events_result.set(format!("✅ Success: {} events{}", data.events.events.len(), clamped));
+                                events_json.set(serde_json::to_value(&data).ok());
                             }
                             Err(e) => {
                                 events_result.set(format!("❌ Parse Error: {}", e));
@@ -94,18 +102,32 @@ pub fn soroban_test_section() -> Html {
                     }),
                 };
 
-                match Request::post(&format!("{}/api/soroban/simulate", BACKEND_URL))
+                match Request::post(&format!("{}/api/soroban/simulate", backend_url()))
                     .json(&request)
                     .unwrap()
                     .send()
                     .await
                 {
+                    Ok(response) if !response.ok() => {
+                        // 422 CONTRACT_ERROR: the contract rejected the transaction
+                        let body: serde_json::Value = response.json().await.unwrap_or_default();
+                        let message = body["message"].as_str().unwrap_or("Simulation failed").to_string();
+                        simulation_result.set(format!("❌ {}: {}", body["error_type"].as_str().unwrap_or("ERROR"), message));
+                    }
                     Ok(response) => {
                         match response.json::<SimulateTransactionResponseDto>().await {
                             Ok(data) => {
                                 let pretty = serde_json::to_string_pretty(&data)
                                     .unwrap_or_else(|_| "Error formatting response".to_string());
-                                simulation_result.set(format!("✅ Success:\n\n{}", pretty));
+                                let restore = if data.requires_restore {
+                                    format!(
+                                        "⚠️ This data is archived, restore it for {} stroops before submitting\n\n",
+                                        data.restore_fee_stroops.unwrap_or_default()
+                                    )
+                                } else {
+                                    String::new()
+                                };
+                                simulation_result.set(format!("✅ Success:\n\n{}{}", restore, pretty));
                             }
                             Err(e) => {
                                 simulation_result.set(format!("❌ Parse Error: {}", e));
@@ -143,7 +165,7 @@ pub fn soroban_test_section() -> Html {
                     durability: DurabilityDto::Persistent,
                 };
 
-                match Request::post(&format!("{}/api/soroban/contract-data", BACKEND_URL))
+                match Request::post(&format!("{}/api/soroban/contract-data", backend_url()))
                     .json(&request)
                     .unwrap()
                     .send()
@@ -191,12 +213,12 @@ pub fn soroban_test_section() -> Html {
                     >
                         {if *is_querying_events { "Querying..." } else { "Query Events" }}
                     </button>
-                    <textarea
-                        class="result-textarea"
-                        readonly=true
-                        value={(*events_result).clone()}
-                        rows="12"
-                    />
+                    <p class="test-info">{(*events_result).clone()}</p>
+                    {if let Some(value) = (*events_json).clone() {
+                        html! { <JsonTree value={value} /> }
+                    } else {
+                        html! {}
+                    }}
                 </div>
 
                 // Transaction Simulation Test
@@ -20,12 +20,13 @@ mod router;
 use components::Navigation;
 use wallet::is_freighter_available;
 use state::{AppState, AppMessage};
+use services::Settings;
 use router::{Route, switch_with_state};
 
 /// Main application component with routing
 #[function_component(App)]
 fn app() -> Html {
-    let state = use_reducer(AppState::default);
+    let state = use_reducer(|| AppState::with_settings(Settings::load()));
 
     let on_toggle_dark_mode = {
         let state = state.clone();
@@ -42,6 +43,13 @@ fn app() -> Html {
         }
     });
 
+    // Persist settings whenever they change
+    use_effect_with(state.settings.clone(), |settings| {
+        if let Err(e) = settings.save() {
+            web_sys::console::error_1(&format!("❌ [SETTINGS] Failed to save settings: {}", e).into());
+        }
+    });
+
     // Check if Freighter is available on mount
     use_effect_with((), |_| {
         spawn_local(async { let _ = is_freighter_available().await; });
@@ -86,9 +86,12 @@ pub fn home_page(props: &HomePageProps) -> Html {
                 }}
             />
 
-            <SorobanMetricsLive />
+            <SorobanMetricsLive polling_interval_ms={state.settings.polling_interval_ms} />
 
-            <ReflectorOracleSection />
+            <ReflectorOracleSection
+                polling_interval_ms={state.settings.polling_interval_ms}
+                locale={state.settings.locale}
+            />
 
             <SorobanTestSection />
         </main>
@@ -0,0 +1,171 @@
+use yew::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+
+use shared::dto::leaderboard::{LeaderboardEntry, DEFAULT_TOP_N};
+use crate::services::{ApiClient, sign_and_submit_score};
+use crate::services::format::format_number;
+use crate::state::AppState;
+
+#[derive(Properties, PartialEq)]
+pub struct LeaderboardPageProps {
+    pub state: yew::UseReducerHandle<AppState>,
+}
+
+/// Leaderboard page - ranked scores from the demo contract, plus score submission
+#[function_component(LeaderboardPage)]
+pub fn leaderboard_page(props: &LeaderboardPageProps) -> Html {
+    let state = props.state.clone();
+    let entries = use_state(Vec::<LeaderboardEntry>::new);
+    let message = use_state(|| String::from("🔄 Loading leaderboard..."));
+    let score = use_state(String::new);
+    let is_submitting = use_state(|| false);
+
+    let load = {
+        let entries = entries.clone();
+        let message = message.clone();
+        Callback::from(move |_: ()| {
+            let entries = entries.clone();
+            let message = message.clone();
+            spawn_local(async move {
+                match ApiClient::new().get_leaderboard(DEFAULT_TOP_N).await {
+                    Ok(response) => {
+                        message.set(if response.entries.is_empty() {
+                            "No scores yet - be the first!".to_string()
+                        } else {
+                            String::new()
+                        });
+                        entries.set(response.entries);
+                    }
+                    Err(e) => message.set(format!("❌ Failed to load leaderboard: {}", e)),
+                }
+            });
+        })
+    };
+
+    // Load once on mount
+    {
+        let load = load.clone();
+        use_effect_with((), move |_| {
+            load.emit(());
+            || ()
+        });
+    }
+
+    let on_score_input = {
+        let score = score.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            score.set(input.value());
+        })
+    };
+
+    let on_submit = {
+        let state = state.clone();
+        let score = score.clone();
+        let message = message.clone();
+        let is_submitting = is_submitting.clone();
+        let load = load.clone();
+        Callback::from(move |_| {
+            let Some(wallet) = state.connected_wallet.clone() else {
+                message.set("❌ Connect your wallet on the Home page first".to_string());
+                return;
+            };
+            let Ok(value) = score.trim().parse::<u64>() else {
+                message.set("❌ Score must be a whole number".to_string());
+                return;
+            };
+
+            let message = message.clone();
+            let is_submitting = is_submitting.clone();
+            let load = load.clone();
+            is_submitting.set(true);
+            message.set(format!("🔄 Submitting score {}... approve the transaction in Freighter", value));
+
+            spawn_local(async move {
+                match sign_and_submit_score(&wallet, value).await {
+                    Ok(_) => {
+                        message.set(format!("✅ Score {} submitted", value));
+                        load.emit(());
+                    }
+                    Err(e) => message.set(format!("❌ {}", e)),
+                }
+                is_submitting.set(false);
+            });
+        })
+    };
+
+    let wallet_address = state.connected_wallet.as_ref().map(|w| w.address.clone());
+    let my_rank = wallet_address.as_ref()
+        .and_then(|address| entries.iter().find(|entry| &entry.player == address))
+        .map(|entry| entry.rank);
+    let locale = state.settings.locale;
+
+    html! {
+        <div class="soroban-test-section leaderboard-section">
+            <h2>{"🏆 Leaderboard"}</h2>
+            <p class="test-description">
+                {"Top scores read from the leaderboard contract. Submitting a score builds a transaction for your wallet to sign."}
+            </p>
+
+            <div class="test-card">
+                <label>{"Your Score"}</label>
+                <input
+                    class="form-input"
+                    type="number"
+                    min="0"
+                    value={(*score).clone()}
+                    oninput={on_score_input}
+                    placeholder="1200"
+                />
+                <button
+                    class="btn btn-test"
+                    onclick={on_submit}
+                    disabled={*is_submitting || wallet_address.is_none()}
+                >
+                    {if *is_submitting { "Submitting..." } else { "Submit My Score" }}
+                </button>
+
+                {match (&wallet_address, my_rank) {
+                    (None, _) => html! { <p class="test-info">{"Connect your wallet to submit a score"}</p> },
+                    (Some(_), Some(rank)) => html! { <p class="test-info">{format!("You are ranked #{}", rank)}</p> },
+                    (Some(_), None) => html! { <p class="test-info">{format!("You are not in the top {}", DEFAULT_TOP_N)}</p> },
+                }}
+
+                {if message.is_empty() {
+                    html! {}
+                } else {
+                    html! { <p class="test-info">{(*message).clone()}</p> }
+                }}
+
+                {if !entries.is_empty() {
+                    html! {
+                        <table class="oracle-price-table leaderboard-table">
+                            <thead>
+                                <tr>
+                                    <th>{"Rank"}</th>
+                                    <th>{"Player"}</th>
+                                    <th>{"Score"}</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                { for entries.iter().map(|entry| {
+                                    let is_me = wallet_address.as_deref() == Some(entry.player.as_str());
+                                    html! {
+                                        <tr class={classes!(is_me.then_some("leaderboard-me"))}>
+                                            <td>{format!("#{}", entry.rank)}</td>
+                                            <td class="leaderboard-player">{&entry.player}</td>
+                                            <td>{format_number(entry.score as f64, 0, locale)}</td>
+                                        </tr>
+                                    }
+                                }) }
+                            </tbody>
+                        </table>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+        </div>
+    }
+}
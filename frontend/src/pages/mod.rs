@@ -1,5 +1,11 @@
 pub mod login;
 pub mod home;
+pub mod settings;
+pub mod leaderboard;
+pub mod payment;
 
 pub use login::LoginPage;
-pub use home::HomePage;
\ No newline at end of file
+pub use home::HomePage;
+pub use settings::SettingsPage;
+pub use leaderboard::LeaderboardPage;
+pub use payment::PaymentPage;
\ No newline at end of file
@@ -0,0 +1,132 @@
+use yew::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+
+use shared::amount::{parse_amount, STELLAR_DECIMALS};
+use shared::dto::payment::NATIVE_ASSET;
+use crate::services::sign_and_submit_payment;
+use crate::state::AppState;
+
+#[derive(Properties, PartialEq)]
+pub struct PaymentPageProps {
+    pub state: yew::UseReducerHandle<AppState>,
+}
+
+/// Payment page - send XLM (or an issued asset) with a classic payment operation
+#[function_component(PaymentPage)]
+pub fn payment_page(props: &PaymentPageProps) -> Html {
+    let state = props.state.clone();
+    let destination = use_state(String::new);
+    let amount = use_state(String::new);
+    let asset = use_state(|| NATIVE_ASSET.to_string());
+    let message = use_state(String::new);
+    let is_sending = use_state(|| false);
+
+    let input_setter = |field: UseStateHandle<String>| {
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            field.set(input.value());
+        })
+    };
+
+    let on_send = {
+        let state = state.clone();
+        let destination = destination.clone();
+        let amount = amount.clone();
+        let asset = asset.clone();
+        let message = message.clone();
+        let is_sending = is_sending.clone();
+        Callback::from(move |_| {
+            let Some(wallet) = state.connected_wallet.clone() else {
+                message.set("❌ Connect your wallet on the Home page first".to_string());
+                return;
+            };
+            if destination.trim().is_empty() || amount.trim().is_empty() {
+                message.set("❌ Destination and amount are required".to_string());
+                return;
+            }
+            // Classic assets all use 7 decimals; catch typos before Freighter opens
+            if let Err(e) = parse_amount(&amount, STELLAR_DECIMALS) {
+                message.set(format!("❌ {}", e));
+                return;
+            }
+
+            let destination = destination.trim().to_string();
+            let amount = amount.trim().to_string();
+            let asset = Some(asset.trim().to_string()).filter(|a| !a.is_empty());
+            let message = message.clone();
+            let is_sending = is_sending.clone();
+            is_sending.set(true);
+            message.set(format!("🔄 Sending {}... approve the transaction in Freighter", amount));
+
+            spawn_local(async move {
+                match sign_and_submit_payment(&wallet, &destination, &amount, asset).await {
+                    Ok(result) => message.set(format!("✅ {}", result)),
+                    Err(e) => message.set(format!("❌ {}", e)),
+                }
+                is_sending.set(false);
+            });
+        })
+    };
+
+    let wallet_connected = state.connected_wallet.is_some();
+
+    html! {
+        <div class="soroban-test-section payment-section">
+            <h2>{"💸 Send Payment"}</h2>
+            <p class="test-description">
+                {"Builds a classic payment transaction for your wallet to sign. Amounts are in whole units, up to 7 decimal places."}
+            </p>
+
+            <div class="test-card">
+                <label>{"Destination"}</label>
+                <input
+                    class="form-input"
+                    type="text"
+                    value={(*destination).clone()}
+                    oninput={input_setter(destination.clone())}
+                    placeholder="G..."
+                />
+
+                <label>{"Amount"}</label>
+                <input
+                    class="form-input"
+                    type="text"
+                    inputmode="decimal"
+                    value={(*amount).clone()}
+                    oninput={input_setter(amount.clone())}
+                    placeholder="10.5"
+                />
+
+                <label>{"Asset"}</label>
+                <input
+                    class="form-input"
+                    type="text"
+                    value={(*asset).clone()}
+                    oninput={input_setter(asset.clone())}
+                    placeholder="XLM or CODE:ISSUER"
+                />
+
+                <button
+                    class="btn btn-test"
+                    onclick={on_send}
+                    disabled={*is_sending || !wallet_connected}
+                >
+                    {if *is_sending { "Sending..." } else { "Send Payment" }}
+                </button>
+
+                {if !wallet_connected {
+                    html! { <p class="test-info">{"Connect your wallet to send a payment"}</p> }
+                } else {
+                    html! {}
+                }}
+
+                {if message.is_empty() {
+                    html! {}
+                } else {
+                    html! { <pre class="test-info payment-result">{(*message).clone()}</pre> }
+                }}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,156 @@
+use yew::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+
+use crate::services::format::{NumberLocale, format_number};
+use crate::services::settings::{Settings, Theme, all_networks, network_name};
+use crate::state::{AppState, AppMessage};
+
+#[derive(Properties, PartialEq)]
+pub struct SettingsPageProps {
+    pub state: yew::UseReducerHandle<AppState>,
+}
+
+/// Settings page - backend URL, network, polling interval and theme
+#[function_component(SettingsPage)]
+pub fn settings_page(props: &SettingsPageProps) -> Html {
+    let state = props.state.clone();
+    let draft = use_state(|| state.settings.clone());
+    let status = use_state(String::new);
+
+    let on_backend_url_input = {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            draft.set(Settings { backend_url: input.value(), ..(*draft).clone() });
+        })
+    };
+
+    let on_network_change = {
+        let draft = draft.clone();
+        Callback::from(move |e: Event| {
+            let select = e.target().unwrap().dyn_into::<HtmlSelectElement>().unwrap();
+            if let Some(network) = all_networks().into_iter().find(|n| network_name(*n) == select.value()) {
+                draft.set(Settings { network, ..(*draft).clone() });
+            }
+        })
+    };
+
+    let on_interval_input = {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            // Empty input keeps each component's default cadence
+            let value = input.value();
+            if value.trim().is_empty() {
+                draft.set(Settings { polling_interval_ms: None, ..(*draft).clone() });
+            } else if let Ok(seconds) = value.trim().parse::<u32>() {
+                draft.set(Settings { polling_interval_ms: Some(seconds.saturating_mul(1000)), ..(*draft).clone() });
+            }
+        })
+    };
+
+    let on_theme_change = {
+        let draft = draft.clone();
+        Callback::from(move |e: Event| {
+            let select = e.target().unwrap().dyn_into::<HtmlSelectElement>().unwrap();
+            let theme = if select.value() == "Dark" { Theme::Dark } else { Theme::Light };
+            draft.set(Settings { theme, ..(*draft).clone() });
+        })
+    };
+
+    let on_locale_change = {
+        let draft = draft.clone();
+        Callback::from(move |e: Event| {
+            let select = e.target().unwrap().dyn_into::<HtmlSelectElement>().unwrap();
+            if let Some(locale) = NumberLocale::from_code(&select.value()) {
+                draft.set(Settings { locale, ..(*draft).clone() });
+            }
+        })
+    };
+
+    let on_save = {
+        let state = state.clone();
+        let draft = draft.clone();
+        let status = status.clone();
+        Callback::from(move |_| {
+            let settings = (*draft).clone().sanitized();
+            draft.set(settings.clone());
+            state.dispatch(AppMessage::UpdateSettings(settings));
+            status.set("✅ Settings saved".to_string());
+        })
+    };
+
+    let on_reset = {
+        let state = state.clone();
+        let draft = draft.clone();
+        let status = status.clone();
+        Callback::from(move |_| {
+            draft.set(Settings::default());
+            state.dispatch(AppMessage::UpdateSettings(Settings::default()));
+            status.set("↩️ Settings reset to defaults".to_string());
+        })
+    };
+
+    html! {
+        <div class="soroban-test-section settings-section">
+            <h2>{"⚙️ Settings"}</h2>
+            <p class="test-description">
+                {"Settings are stored in this browser and apply immediately across the app."}
+            </p>
+
+            <div class="test-card">
+                <label>{"Backend URL"}</label>
+                <input
+                    class="form-input"
+                    type="text"
+                    value={draft.backend_url.clone()}
+                    oninput={on_backend_url_input}
+                    placeholder="http://127.0.0.1:3001"
+                />
+
+                <label>{"Preferred Network"}</label>
+                <select class="function-dropdown" onchange={on_network_change}>
+                    { for all_networks().into_iter().map(|network| html! {
+                        <option value={network_name(network)} selected={network == draft.network}>
+                            {network_name(network)}
+                        </option>
+                    }) }
+                </select>
+
+                <label>{"Polling Interval (seconds)"}</label>
+                <input
+                    class="form-input"
+                    type="number"
+                    min="1"
+                    max="300"
+                    value={draft.polling_interval_ms.map(|ms| (ms / 1000).to_string()).unwrap_or_default()}
+                    oninput={on_interval_input}
+                    placeholder="Component default"
+                />
+
+                <label>{"Theme"}</label>
+                <select class="function-dropdown" onchange={on_theme_change}>
+                    <option value="Light" selected={!draft.theme.is_dark()}>{"Light"}</option>
+                    <option value="Dark" selected={draft.theme.is_dark()}>{"Dark"}</option>
+                </select>
+
+                <label>{"Number Format"}</label>
+                <select class="function-dropdown" onchange={on_locale_change}>
+                    { for NumberLocale::all().into_iter().map(|locale| html! {
+                        <option value={locale.code()} selected={locale == draft.locale}>
+                            {format!("{} ({})", locale.code(), format_number(1234567.89, 2, locale))}
+                        </option>
+                    }) }
+                </select>
+
+                <div class="button-group">
+                    <button class="btn btn-test" onclick={on_save}>{"Save"}</button>
+                    <button class="btn btn-secondary" onclick={on_reset}>{"Reset to Defaults"}</button>
+                </div>
+
+                <p class="test-info">{(*status).clone()}</p>
+            </div>
+        </div>
+    }
+}
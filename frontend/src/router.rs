@@ -1,7 +1,7 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
-use crate::components::{AboutPage, BlendProtocol};
-use crate::pages::LoginPage;
+use crate::components::{AboutPage, BlendProtocol, ContractCallExplorer, ContractStorage};
+use crate::pages::{LeaderboardPage, LoginPage, PaymentPage, SettingsPage};
 use crate::state::AppState;
 
 #[derive(Clone, Routable, PartialEq)]
@@ -14,6 +14,16 @@ pub enum Route {
     About,
     #[at("/blend")]
     Blend,
+    #[at("/storage")]
+    Storage,
+    #[at("/call")]
+    Call,
+    #[at("/leaderboard")]
+    Leaderboard,
+    #[at("/payment")]
+    Payment,
+    #[at("/settings")]
+    Settings,
 }
 
 /// Route switching logic with state
@@ -31,5 +41,20 @@ pub fn switch_with_state(state: yew::UseReducerHandle<AppState>) -> impl Fn(Rout
         Route::Blend => {
             html! { <BlendProtocol /> }
         },
+        Route::Storage => {
+            html! { <ContractStorage /> }
+        },
+        Route::Call => {
+            html! { <ContractCallExplorer /> }
+        },
+        Route::Leaderboard => {
+            html! { <LeaderboardPage state={state.clone()} /> }
+        },
+        Route::Payment => {
+            html! { <PaymentPage state={state.clone()} /> }
+        },
+        Route::Settings => {
+            html! { <SettingsPage state={state.clone()} /> }
+        },
     }
 }
\ No newline at end of file
@@ -1,5 +1,12 @@
-use gloo_net::http::Request;
+use gloo_net::http::{Request, Response};
+use serde::de::DeserializeOwned;
 use shared::dto::{auth::Guest, user::SignUpResponse, common::ApiResponse};
+use shared::dto::account::AccountResponse;
+use shared::dto::favorites::{AddFavoriteRequest, Favorite};
+use shared::dto::leaderboard::{LeaderboardResponse, SubmitScoreRequest, SubmitScoreResponse};
+use shared::dto::payment::{PaymentRequest, PaymentXdrResponse};
+use web_sys::RequestCredentials;
+use super::settings::backend_url;
 
 #[derive(Default)]
 pub struct ApiClient {
@@ -9,7 +16,7 @@ pub struct ApiClient {
 impl ApiClient {
     pub fn new() -> Self {
         Self {
-            base_url: "http://localhost:3001".to_string(),
+            base_url: backend_url(),
         }
     }
 
@@ -47,4 +54,164 @@ impl ApiClient {
             }
         }
     }
-}
\ No newline at end of file
+
+    /// Look up a classic account's XLM balance (unfunded accounts return `funded: false`)
+    pub async fn get_account(&self, address: &str) -> Result<AccountResponse, String> {
+        let url = format!("{}/api/account/{}", self.base_url, urlencoding::encode(address));
+
+        let response = Request::get(&url)
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {e}"))?;
+
+        if response.ok() {
+            response
+                .json::<AccountResponse>()
+                .await
+                .map_err(|e| format!("Response parse error: {e}"))
+        } else {
+            Err(Self::error_message(response).await)
+        }
+    }
+
+    /// Read the `n` highest leaderboard scores, best first
+    pub async fn get_leaderboard(&self, n: u32) -> Result<LeaderboardResponse, String> {
+        let url = format!("{}/api/leaderboard/top?n={}", self.base_url, n);
+
+        let response = Request::get(&url)
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {e}"))?;
+
+        if response.ok() {
+            response
+                .json::<LeaderboardResponse>()
+                .await
+                .map_err(|e| format!("Response parse error: {e}"))
+        } else {
+            Err(Self::error_message(response).await)
+        }
+    }
+
+    /// Get an unsigned `submit_score` transaction for the player's wallet to sign
+    pub async fn submit_score(&self, player: &str, score: u64) -> Result<SubmitScoreResponse, String> {
+        let url = format!("{}/api/leaderboard/submit", self.base_url);
+        let request = SubmitScoreRequest {
+            player: player.to_string(),
+            score,
+        };
+
+        let response = Request::post(&url)
+            .json(&request)
+            .map_err(|e| format!("Request error: {e}"))?
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {e}"))?;
+
+        if response.ok() {
+            response
+                .json::<SubmitScoreResponse>()
+                .await
+                .map_err(|e| format!("Response parse error: {e}"))
+        } else {
+            Err(Self::error_message(response).await)
+        }
+    }
+
+    /// Get an unsigned classic payment transaction for the source account's wallet to sign
+    pub async fn generate_payment_xdr(&self, request: &PaymentRequest) -> Result<PaymentXdrResponse, String> {
+        let url = format!("{}/api/payment/generate-xdr", self.base_url);
+
+        let response = Request::post(&url)
+            .json(request)
+            .map_err(|e| format!("Request error: {e}"))?
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {e}"))?;
+
+        if response.ok() {
+            response
+                .json::<PaymentXdrResponse>()
+                .await
+                .map_err(|e| format!("Response parse error: {e}"))
+        } else {
+            Err(Self::error_message(response).await)
+        }
+    }
+
+    /// List the logged-in user's favorite contracts
+    pub async fn list_favorites(&self) -> Result<Vec<Favorite>, String> {
+        let url = format!("{}/api/favorites", self.base_url);
+
+        let response = Request::get(&url)
+            .credentials(RequestCredentials::Include)
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {e}"))?;
+
+        Self::parse_response(response).await
+    }
+
+    /// Add a contract to the logged-in user's favorites
+    pub async fn add_favorite(&self, contract_id: &str, label: Option<String>) -> Result<Favorite, String> {
+        let url = format!("{}/api/favorites", self.base_url);
+        let request = AddFavoriteRequest {
+            contract_id: contract_id.to_string(),
+            label,
+        };
+
+        let response = Request::post(&url)
+            .credentials(RequestCredentials::Include)
+            .json(&request)
+            .map_err(|e| format!("Request error: {e}"))?
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {e}"))?;
+
+        Self::parse_response(response).await
+    }
+
+    /// Remove a contract from the logged-in user's favorites
+    pub async fn remove_favorite(&self, contract_id: &str) -> Result<(), String> {
+        let url = format!("{}/api/favorites/{}", self.base_url, urlencoding::encode(contract_id));
+
+        let response = Request::delete(&url)
+            .credentials(RequestCredentials::Include)
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {e}"))?;
+
+        if response.ok() {
+            Ok(())
+        } else {
+            Err(Self::error_message(response).await)
+        }
+    }
+
+    async fn parse_response<T: DeserializeOwned>(response: Response) -> Result<T, String> {
+        if !response.ok() {
+            return Err(Self::error_message(response).await);
+        }
+
+        let api_response: ApiResponse<T> = response
+            .json()
+            .await
+            .map_err(|e| format!("Response parse error: {e}"))?;
+
+        if api_response.success {
+            api_response.data
+                .ok_or_else(|| "No data in successful response".to_string())
+        } else {
+            Err(api_response.message)
+        }
+    }
+
+    async fn error_message(response: Response) -> String {
+        if response.status() == 401 {
+            return "Log in to use favorites".to_string();
+        }
+        let body: serde_json::Value = response.json().await.unwrap_or_default();
+        body["message"].as_str().map(str::to_string)
+            .unwrap_or_else(|| format!("HTTP error: {}", response.status()))
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use shared::dto::soroban::CallContractFunctionRequest;
+
+// Include tests module
+#[cfg(test)]
+#[path = "call_history_test.rs"]
+mod call_history_test;
+
+/// localStorage key for the contract call history
+pub const CALL_HISTORY_STORAGE_KEY: &str = "yew_scaffold_call_history";
+
+/// Calls remembered per contract
+pub const MAX_HISTORY_PER_CONTRACT: usize = 10;
+
+/// A previously executed contract call
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CallHistoryEntry {
+    pub request: CallContractFunctionRequest,
+    pub success: bool,
+    /// Unix timestamp (seconds) when the result came back
+    pub timestamp: i64,
+}
+
+impl CallHistoryEntry {
+    /// Whether two entries describe the same call (function + parameters)
+    fn same_call(&self, other: &CallHistoryEntry) -> bool {
+        self.request.function_name == other.request.function_name
+            && format!("{:?}", self.request.parameters) == format!("{:?}", other.request.parameters)
+    }
+}
+
+/// Most-recent-first call history, keyed by contract id
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CallHistory {
+    entries: HashMap<String, Vec<CallHistoryEntry>>,
+}
+
+impl CallHistory {
+    /// Load history from localStorage (empty if missing or unreadable)
+    pub fn load() -> Self {
+        LocalStorage::get(CALL_HISTORY_STORAGE_KEY).unwrap_or_default()
+    }
+
+    /// Persist history to localStorage
+    pub fn save(&self) -> Result<(), String> {
+        LocalStorage::set(CALL_HISTORY_STORAGE_KEY, self).map_err(|e| e.to_string())
+    }
+
+    /// Calls for a contract, most recent first
+    pub fn for_contract(&self, contract_id: &str) -> &[CallHistoryEntry] {
+        self.entries.get(contract_id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Record a call; repeating a call moves it to the top instead of duplicating it
+    pub fn record(&mut self, entry: CallHistoryEntry) {
+        let calls = self.entries.entry(entry.request.contract_id.clone()).or_default();
+        calls.retain(|existing| !existing.same_call(&entry));
+        calls.insert(0, entry);
+        calls.truncate(MAX_HISTORY_PER_CONTRACT);
+    }
+
+    /// Forget all calls for a contract
+    pub fn clear_contract(&mut self, contract_id: &str) {
+        self.entries.remove(contract_id);
+    }
+}
@@ -0,0 +1,81 @@
+/// Unit tests for the contract call history
+///
+/// Tests pure Rust logic that doesn't require WASM runtime
+/// These tests run with `cargo test` (not wasm-bindgen-test)
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use shared::dto::soroban::{CallCacheMode, FunctionParameter};
+
+    const CONTRACT: &str = "CCYOZJCOPG34LLQQ7N24YXBM7LL62R7ONMZ3G6WZAAYPB5OYKOMJRN63";
+
+    fn entry(contract_id: &str, function_name: &str, parameters: Vec<FunctionParameter>, timestamp: i64) -> CallHistoryEntry {
+        CallHistoryEntry {
+            request: CallContractFunctionRequest {
+                contract_id: contract_id.to_string(),
+                function_name: function_name.to_string(),
+                parameters,
+                source_account: None,
+                return_type: None,
+                cache_mode: CallCacheMode::Ttl,
+            },
+            success: true,
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn test_most_recent_first() {
+        let mut history = CallHistory::default();
+        history.record(entry(CONTRACT, "decimals", vec![], 1));
+        history.record(entry(CONTRACT, "base", vec![], 2));
+
+        let calls = history.for_contract(CONTRACT);
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].request.function_name, "base");
+        assert_eq!(calls[1].request.function_name, "decimals");
+    }
+
+    #[test]
+    fn test_repeated_call_moves_to_top() {
+        let mut history = CallHistory::default();
+        let btc = || vec![FunctionParameter::Symbol("BTC".to_string())];
+        let eth = || vec![FunctionParameter::Symbol("ETH".to_string())];
+
+        history.record(entry(CONTRACT, "lastprice", btc(), 1));
+        history.record(entry(CONTRACT, "lastprice", eth(), 2));
+        history.record(entry(CONTRACT, "lastprice", btc(), 3));
+
+        let calls = history.for_contract(CONTRACT);
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].timestamp, 3);
+        assert_eq!(calls[1].timestamp, 2);
+    }
+
+    #[test]
+    fn test_history_is_capped_and_per_contract() {
+        let mut history = CallHistory::default();
+        for i in 0..(MAX_HISTORY_PER_CONTRACT as u32 + 5) {
+            history.record(entry(CONTRACT, "lastprice", vec![FunctionParameter::U32(i)], i as i64));
+        }
+        history.record(entry("COTHER", "decimals", vec![], 0));
+
+        assert_eq!(history.for_contract(CONTRACT).len(), MAX_HISTORY_PER_CONTRACT);
+        assert_eq!(history.for_contract("COTHER").len(), 1);
+        assert!(history.for_contract("CUNKNOWN").is_empty());
+
+        history.clear_contract(CONTRACT);
+        assert!(history.for_contract(CONTRACT).is_empty());
+    }
+
+    #[test]
+    fn test_history_roundtrip() {
+        let mut history = CallHistory::default();
+        history.record(entry(CONTRACT, "lastprice", vec![FunctionParameter::Symbol("BTC".to_string())], 42));
+
+        let json = serde_json::to_string(&history).unwrap();
+        let parsed: CallHistory = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.for_contract(CONTRACT)[0].timestamp, 42);
+    }
+}
@@ -0,0 +1,45 @@
+use shared::dto::favorites::Favorite;
+use shared::dto::soroban::ContractMetadata;
+
+// Include tests module
+#[cfg(test)]
+#[path = "contract_list_test.rs"]
+mod contract_list_test;
+
+/// A contract offered in the explorer's contract picker
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractListItem {
+    pub contract_id: String,
+    pub label: String,
+    pub favorite: bool,
+}
+
+/// Contract list with the user's favorites pinned to the top
+///
+/// Favorites keep the user's order; the remaining registered contracts follow
+/// in registry order. A favorite without a label falls back to the registered
+/// name, or the contract id if it isn't registered.
+pub fn pinned_contract_list(favorites: &[Favorite], registered: &[ContractMetadata]) -> Vec<ContractListItem> {
+    let registered_name = |contract_id: &str| {
+        registered.iter().find(|c| c.contract_id == contract_id).map(|c| c.name.clone())
+    };
+
+    let pinned = favorites.iter().map(|favorite| ContractListItem {
+        contract_id: favorite.contract_id.clone(),
+        label: favorite.label.clone()
+            .or_else(|| registered_name(&favorite.contract_id))
+            .unwrap_or_else(|| favorite.contract_id.clone()),
+        favorite: true,
+    });
+
+    let rest = registered
+        .iter()
+        .filter(|contract| !favorites.iter().any(|f| f.contract_id == contract.contract_id))
+        .map(|contract| ContractListItem {
+            contract_id: contract.contract_id.clone(),
+            label: contract.name.clone(),
+            favorite: false,
+        });
+
+    pinned.chain(rest).collect()
+}
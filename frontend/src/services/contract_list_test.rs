@@ -0,0 +1,68 @@
+/// Unit tests for the explorer's contract list
+///
+/// Tests pure Rust logic that doesn't require WASM runtime
+/// These tests run with `cargo test` (not wasm-bindgen-test)
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use shared::dto::soroban::NetworkType;
+
+    fn favorite(contract_id: &str, label: Option<&str>) -> Favorite {
+        Favorite {
+            contract_id: contract_id.to_string(),
+            label: label.map(str::to_string),
+            created_at: None,
+        }
+    }
+
+    fn registered(contract_id: &str, name: &str) -> ContractMetadata {
+        ContractMetadata {
+            contract_id: contract_id.to_string(),
+            name: name.to_string(),
+            network: NetworkType::Testnet,
+            network_passphrase: NetworkType::Testnet.default_passphrase().to_string(),
+            rpc_url: "https://soroban-testnet.stellar.org".to_string(),
+            description: None,
+            version: None,
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_favorites_pinned_first() {
+        let favorites = vec![favorite("CFAV", Some("Mine")), favorite("CORACLE", None)];
+        let contracts = vec![registered("CHELLO", "Hello"), registered("CORACLE", "Oracle")];
+
+        let list = pinned_contract_list(&favorites, &contracts);
+
+        let ids: Vec<&str> = list.iter().map(|item| item.contract_id.as_str()).collect();
+        assert_eq!(ids, vec!["CFAV", "CORACLE", "CHELLO"]);
+        assert_eq!(list.iter().filter(|item| item.favorite).count(), 2);
+        assert!(!list[2].favorite);
+    }
+
+    #[test]
+    fn test_favorite_label_falls_back_to_registered_name() {
+        let favorites = vec![favorite("CORACLE", None), favorite("CUNKNOWN", None)];
+        let contracts = vec![registered("CORACLE", "Oracle")];
+
+        let list = pinned_contract_list(&favorites, &contracts);
+
+        assert_eq!(list[0].label, "Oracle");
+        assert_eq!(list[1].label, "CUNKNOWN");
+    }
+
+    #[test]
+    fn test_no_favorites_lists_registry() {
+        let contracts = vec![registered("CHELLO", "Hello")];
+
+        let list = pinned_contract_list(&[], &contracts);
+
+        assert_eq!(list, vec![ContractListItem {
+            contract_id: "CHELLO".to_string(),
+            label: "Hello".to_string(),
+            favorite: false,
+        }]);
+    }
+}
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+
+// Include tests module
+#[cfg(test)]
+#[path = "format_test.rs"]
+mod format_test;
+
+/// Reflector oracle prices use 14 decimals
+pub const ORACLE_PRICE_DECIMALS: i32 = 14;
+
+/// Number formatting conventions for displayed prices and amounts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum NumberLocale {
+    /// 1,234.56
+    #[default]
+    EnUs,
+    /// 1.234,56
+    DeDe,
+    /// 1 234,56
+    FrFr,
+    /// 1'234.56
+    DeCh,
+}
+
+impl NumberLocale {
+    /// All selectable locales
+    pub fn all() -> Vec<NumberLocale> {
+        vec![NumberLocale::EnUs, NumberLocale::DeDe, NumberLocale::FrFr, NumberLocale::DeCh]
+    }
+
+    /// BCP 47 tag, e.g. "en-US"
+    pub fn code(&self) -> &'static str {
+        match self {
+            NumberLocale::EnUs => "en-US",
+            NumberLocale::DeDe => "de-DE",
+            NumberLocale::FrFr => "fr-FR",
+            NumberLocale::DeCh => "de-CH",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<NumberLocale> {
+        NumberLocale::all().into_iter().find(|locale| locale.code() == code)
+    }
+
+    pub fn grouping_separator(&self) -> &'static str {
+        match self {
+            NumberLocale::EnUs => ",",
+            NumberLocale::DeDe => ".",
+            NumberLocale::FrFr => "\u{202f}", // narrow no-break space
+            NumberLocale::DeCh => "'",
+        }
+    }
+
+    pub fn decimal_separator(&self) -> &'static str {
+        match self {
+            NumberLocale::EnUs | NumberLocale::DeCh => ".",
+            NumberLocale::DeDe | NumberLocale::FrFr => ",",
+        }
+    }
+}
+
+/// Format a number with a fixed number of decimals using the locale's separators
+pub fn format_number(value: f64, decimals: usize, locale: NumberLocale) -> String {
+    let formatted = format!("{:.*}", decimals, value.abs());
+    let (whole, fraction) = match formatted.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (formatted.as_str(), None),
+    };
+
+    let mut result = String::new();
+    if value < 0.0 && formatted.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        result.push('-');
+    }
+
+    let digits: Vec<char> = whole.chars().collect();
+    for (i, digit) in digits.iter().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            result.push_str(locale.grouping_separator());
+        }
+        result.push(*digit);
+    }
+
+    if let Some(fraction) = fraction {
+        result.push_str(locale.decimal_separator());
+        result.push_str(fraction);
+    }
+
+    result
+}
+
+/// Format a raw oracle price (14 decimals) as USD, with precision based on magnitude
+pub fn format_oracle_price(price_str: &str, locale: NumberLocale) -> Option<String> {
+    let price = price_str.parse::<f64>().ok()? / 10f64.powi(ORACLE_PRICE_DECIMALS);
+
+    let decimals = if price >= 1000.0 {
+        2 // Large values: $67,234.12
+    } else if price >= 1.0 {
+        4 // Medium values: $227.2790
+    } else if price >= 0.01 {
+        6 // Small values: $0.393352
+    } else {
+        8 // Very small values
+    };
+
+    Some(format!("${}", format_number(price, decimals, locale)))
+}
@@ -0,0 +1,57 @@
+/// Unit tests for locale-aware number formatting
+///
+/// Tests pure Rust logic that doesn't require WASM runtime
+/// These tests run with `cargo test` (not wasm-bindgen-test)
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn test_en_us_formatting() {
+        let locale = NumberLocale::EnUs;
+
+        assert_eq!(format_number(1234567.891, 2, locale), "1,234,567.89");
+        assert_eq!(format_number(999.5, 1, locale), "999.5");
+        assert_eq!(format_number(-1234.0, 0, locale), "-1,234");
+    }
+
+    #[test]
+    fn test_de_de_formatting() {
+        let locale = NumberLocale::DeDe;
+
+        assert_eq!(format_number(1234567.891, 2, locale), "1.234.567,89");
+        assert_eq!(format_number(0.5, 2, locale), "0,50");
+    }
+
+    #[test]
+    fn test_fr_fr_and_de_ch_formatting() {
+        assert_eq!(format_number(1234.5, 2, NumberLocale::FrFr), "1\u{202f}234,50");
+        assert_eq!(format_number(1234.5, 2, NumberLocale::DeCh), "1'234.50");
+    }
+
+    #[test]
+    fn test_negative_zero_has_no_sign() {
+        assert_eq!(format_number(-0.001, 2, NumberLocale::EnUs), "0.00");
+    }
+
+    #[test]
+    fn test_oracle_price_uses_locale() {
+        // 67,234.12 USD with 14 decimals
+        let raw = "6723412000000000000";
+
+        assert_eq!(format_oracle_price(raw, NumberLocale::EnUs).unwrap(), "$67,234.12");
+        assert_eq!(format_oracle_price(raw, NumberLocale::DeDe).unwrap(), "$67.234,12");
+        assert_eq!(format_oracle_price("39335200000000", NumberLocale::DeDe).unwrap(), "$0,393352");
+        assert!(format_oracle_price("not a number", NumberLocale::EnUs).is_none());
+    }
+
+    #[test]
+    fn test_locale_codes_roundtrip() {
+        for locale in NumberLocale::all() {
+            assert_eq!(NumberLocale::from_code(locale.code()), Some(locale));
+        }
+        assert_eq!(NumberLocale::default(), NumberLocale::EnUs);
+        assert_eq!(NumberLocale::from_code("xx-XX"), None);
+    }
+}
@@ -1,7 +1,14 @@
 pub mod api;
 pub mod transaction;
 pub mod soroban_api;
+pub mod storage_key;
+pub mod settings;
+pub mod call_history;
+pub mod contract_list;
+pub mod format;
 
 pub use api::ApiClient;
-pub use transaction::sign_hello_transaction;
-pub use soroban_api::SorobanApiClient;
\ No newline at end of file
+pub use transaction::{sign_hello_transaction, sign_and_submit_score, sign_and_submit_payment};
+pub use soroban_api::SorobanApiClient;
+pub use storage_key::{StorageKeyType, TtlStatus, build_storage_key, ttl_status};
+pub use settings::{Settings, backend_url};
\ No newline at end of file
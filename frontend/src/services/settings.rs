@@ -0,0 +1,139 @@
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use shared::dto::soroban::NetworkType;
+use std::cell::RefCell;
+
+use super::format::NumberLocale;
+
+// Include tests module
+#[cfg(test)]
+#[path = "settings_test.rs"]
+mod settings_test;
+
+/// localStorage key for persisted user settings
+pub const SETTINGS_STORAGE_KEY: &str = "yew_scaffold_settings";
+
+pub const DEFAULT_BACKEND_URL: &str = "http://127.0.0.1:3001";
+pub const MIN_POLLING_INTERVAL_MS: u32 = 1_000;
+pub const MAX_POLLING_INTERVAL_MS: u32 = 300_000;
+
+/// UI theme preference
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Theme {
+    #[default]
+    Light,
+    Dark,
+}
+
+impl Theme {
+    pub fn is_dark(&self) -> bool {
+        matches!(self, Theme::Dark)
+    }
+}
+
+/// Runtime-configurable user settings, persisted to localStorage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub backend_url: String,
+    /// Network transactions are signed for
+    pub network: NetworkType,
+    /// Auto-refresh interval override; `None` keeps each component's own cadence
+    pub polling_interval_ms: Option<u32>,
+    pub theme: Theme,
+    /// Grouping and decimal separators for displayed numbers
+    pub locale: NumberLocale,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            backend_url: DEFAULT_BACKEND_URL.to_string(),
+            network: NetworkType::Testnet,
+            polling_interval_ms: None,
+            theme: Theme::Light,
+            locale: NumberLocale::EnUs,
+        }
+    }
+}
+
+thread_local! {
+    /// Last loaded or saved settings, so lookups don't re-read localStorage
+    static CURRENT: RefCell<Option<Settings>> = const { RefCell::new(None) };
+}
+
+impl Settings {
+    /// Load settings from localStorage, falling back to defaults
+    pub fn load() -> Self {
+        if let Some(settings) = CURRENT.with(|current| current.borrow().clone()) {
+            return settings;
+        }
+
+        let settings = LocalStorage::get::<Settings>(SETTINGS_STORAGE_KEY)
+            .map(Settings::sanitized)
+            .unwrap_or_default();
+        CURRENT.with(|current| *current.borrow_mut() = Some(settings.clone()));
+        settings
+    }
+
+    /// Persist settings to localStorage
+    pub fn save(&self) -> Result<(), String> {
+        CURRENT.with(|current| *current.borrow_mut() = Some(self.clone()));
+        LocalStorage::set(SETTINGS_STORAGE_KEY, self).map_err(|e| e.to_string())
+    }
+
+    /// Normalize user input: trim the URL and keep the interval within bounds
+    pub fn sanitized(mut self) -> Self {
+        let url = self.backend_url.trim().trim_end_matches('/');
+        self.backend_url = if url.is_empty() {
+            DEFAULT_BACKEND_URL.to_string()
+        } else {
+            url.to_string()
+        };
+        self.polling_interval_ms = self
+            .polling_interval_ms
+            .map(|ms| ms.clamp(MIN_POLLING_INTERVAL_MS, MAX_POLLING_INTERVAL_MS));
+        self
+    }
+}
+
+/// Backend base URL from the saved settings
+pub fn backend_url() -> String {
+    Settings::load().backend_url
+}
+
+/// Passphrase of the preferred network from the saved settings
+pub fn network_passphrase() -> &'static str {
+    Settings::load().network.default_passphrase()
+}
+
+/// Passphrase to sign with: the preferred network's, once it matches what the backend built for
+///
+/// A signature for the wrong network is rejected on submission, so a mismatch is
+/// reported before the wallet is asked to sign. An empty `built_for` is not checked.
+pub fn signing_network(preferred: NetworkType, built_for: &str) -> Result<&'static str, String> {
+    let passphrase = preferred.default_passphrase();
+    if !built_for.is_empty() && built_for != passphrase {
+        return Err(format!(
+            "Backend built the transaction for '{}', but your preferred network is {}",
+            built_for,
+            network_name(preferred)
+        ));
+    }
+    Ok(passphrase)
+}
+
+/// Display name for a network option
+pub fn network_name(network: NetworkType) -> &'static str {
+    match network {
+        NetworkType::Testnet => "Testnet",
+        NetworkType::Mainnet => "Mainnet",
+        NetworkType::Futurenet => "Futurenet",
+        NetworkType::Standalone => "Standalone",
+    }
+}
+
+/// All selectable networks
+pub fn all_networks() -> Vec<NetworkType> {
+    NetworkType::ALL.to_vec()
+}
@@ -0,0 +1,94 @@
+/// Unit tests for user settings
+///
+/// Tests pure Rust logic that doesn't require WASM runtime
+/// These tests run with `cargo test` (not wasm-bindgen-test)
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn test_default_settings() {
+        let settings = Settings::default();
+
+        assert_eq!(settings.backend_url, DEFAULT_BACKEND_URL);
+        assert_eq!(settings.network, NetworkType::Testnet);
+        assert_eq!(settings.polling_interval_ms, None);
+        assert!(!settings.theme.is_dark());
+        assert_eq!(settings.locale, NumberLocale::EnUs);
+    }
+
+    #[test]
+    fn test_sanitized_trims_url_and_clamps_interval() {
+        let settings = Settings {
+            backend_url: "  https://api.example.com/ ".to_string(),
+            polling_interval_ms: Some(10),
+            ..Settings::default()
+        }
+        .sanitized();
+
+        assert_eq!(settings.backend_url, "https://api.example.com");
+        assert_eq!(settings.polling_interval_ms, Some(MIN_POLLING_INTERVAL_MS));
+
+        let settings = Settings {
+            backend_url: "   ".to_string(),
+            polling_interval_ms: Some(u32::MAX),
+            ..Settings::default()
+        }
+        .sanitized();
+
+        assert_eq!(settings.backend_url, DEFAULT_BACKEND_URL);
+        assert_eq!(settings.polling_interval_ms, Some(MAX_POLLING_INTERVAL_MS));
+    }
+
+    #[test]
+    fn test_sanitized_keeps_component_defaults() {
+        let settings = Settings::default().sanitized();
+
+        assert_eq!(settings.polling_interval_ms, None);
+    }
+
+    #[test]
+    fn test_partial_json_uses_defaults() {
+        // Older saved settings may be missing newer fields
+        let settings: Settings = serde_json::from_str(r#"{"theme":"Dark"}"#).unwrap();
+
+        assert!(settings.theme.is_dark());
+        assert_eq!(settings.backend_url, DEFAULT_BACKEND_URL);
+        assert_eq!(settings.network, NetworkType::Testnet);
+        assert_eq!(settings.polling_interval_ms, None);
+    }
+
+    #[test]
+    fn test_settings_roundtrip() {
+        let settings = Settings {
+            backend_url: "http://localhost:4000".to_string(),
+            network: NetworkType::Futurenet,
+            polling_interval_ms: Some(5_000),
+            theme: Theme::Dark,
+            locale: NumberLocale::DeDe,
+        };
+
+        let json = serde_json::to_string(&settings).unwrap();
+        let parsed: Settings = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, settings);
+    }
+
+    #[test]
+    fn test_signing_network_must_match_backend() {
+        let testnet = NetworkType::Testnet.default_passphrase();
+
+        assert_eq!(signing_network(NetworkType::Testnet, testnet), Ok(testnet));
+        assert_eq!(signing_network(NetworkType::Testnet, ""), Ok(testnet));
+
+        let err = signing_network(NetworkType::Mainnet, testnet).unwrap_err();
+        assert!(err.contains("Mainnet"), "{}", err);
+    }
+
+    #[test]
+    fn test_every_network_has_a_name() {
+        let names: Vec<&str> = all_networks().into_iter().map(network_name).collect();
+
+        assert_eq!(names, vec!["Testnet", "Mainnet", "Futurenet", "Standalone"]);
+    }
+}
@@ -1,7 +1,11 @@
 use gloo_net::http::Request;
 use shared::dto::soroban::{
-    MetricsResponse, SorobanHealthResponse
+    MetricsResponse, SorobanHealthResponse, ConvertContractIdRequest, ConvertContractIdResponse,
+    SimulateBatchRequest, SimulateBatchResponse, SimulateTransactionRequest, ListFunctionsResponse,
+    ContractsInfoRequest, ContractsInfoResponse, ListContractsResponse,
 };
+use shared::dto::presets::ContractPresetsResponse;
+use super::settings::backend_url;
 
 #[derive(Default, Clone)]
 pub struct SorobanApiClient {
@@ -11,7 +15,7 @@ pub struct SorobanApiClient {
 impl SorobanApiClient {
     pub fn new() -> Self {
         Self {
-            base_url: "http://localhost:3001".to_string(),
+            base_url: backend_url(),
         }
     }
 
@@ -53,4 +57,132 @@ impl SorobanApiClient {
         }
     }
 
+    /// List the contracts registered with the backend
+    pub async fn list_contracts(&self) -> Result<ListContractsResponse, String> {
+        let url = format!("{}/api/soroban/contracts", self.base_url);
+
+        let response = Request::get(&url)
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {e}"))?;
+
+        if response.ok() {
+            response
+                .json::<ListContractsResponse>()
+                .await
+                .map_err(|e| format!("Response parse error: {e}"))
+        } else {
+            Err(format!("HTTP error: {}", response.status()))
+        }
+    }
+
+    /// List the contract functions available to the generate-xdr flow
+    pub async fn list_functions(&self) -> Result<ListFunctionsResponse, String> {
+        let url = format!("{}/api/soroban/functions", self.base_url);
+
+        let response = Request::get(&url)
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {e}"))?;
+
+        if response.ok() {
+            response
+                .json::<ListFunctionsResponse>()
+                .await
+                .map_err(|e| format!("Response parse error: {e}"))
+        } else {
+            Err(format!("HTTP error: {}", response.status()))
+        }
+    }
+
+    /// Ready-made parameter presets for a known contract's functions
+    pub async fn get_presets(&self, contract_id: &str) -> Result<ContractPresetsResponse, String> {
+        let url = format!("{}/api/soroban/contract/{}/presets", self.base_url, contract_id);
+
+        let response = Request::get(&url)
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {e}"))?;
+
+        if response.ok() {
+            response
+                .json::<ContractPresetsResponse>()
+                .await
+                .map_err(|e| format!("Response parse error: {e}"))
+        } else {
+            Err(format!("HTTP error: {}", response.status()))
+        }
+    }
+
+    /// Normalize a pasted contract id (strkey or hex) into both forms
+    pub async fn convert_contract_id(&self, contract_id: &str) -> Result<ConvertContractIdResponse, String> {
+        let url = format!("{}/api/soroban/convert-contract-id", self.base_url);
+        let request = ConvertContractIdRequest {
+            contract_id: contract_id.trim().to_string(),
+        };
+
+        let response = Request::post(&url)
+            .json(&request)
+            .map_err(|e| format!("Request build error: {e}"))?
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {e}"))?;
+
+        if response.ok() {
+            response
+                .json::<ConvertContractIdResponse>()
+                .await
+                .map_err(|e| format!("Response parse error: {e}"))
+        } else {
+            let body: serde_json::Value = response.json().await.unwrap_or_default();
+            Err(body["message"].as_str().map(str::to_string)
+                .unwrap_or_else(|| format!("HTTP error: {}", response.status())))
+        }
+    }
+
+    /// Simulate several transaction variants concurrently, for side-by-side cost comparison
+    pub async fn simulate_batch(&self, simulations: Vec<SimulateTransactionRequest>) -> Result<SimulateBatchResponse, String> {
+        let url = format!("{}/api/soroban/simulate-batch", self.base_url);
+
+        let response = Request::post(&url)
+            .json(&SimulateBatchRequest { simulations })
+            .map_err(|e| format!("Request build error: {e}"))?
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {e}"))?;
+
+        if response.ok() {
+            response
+                .json::<SimulateBatchResponse>()
+                .await
+                .map_err(|e| format!("Response parse error: {e}"))
+        } else {
+            let body: serde_json::Value = response.json().await.unwrap_or_default();
+            Err(body["message"].as_str().map(str::to_string)
+                .unwrap_or_else(|| format!("HTTP error: {}", response.status())))
+        }
+    }
+
+    /// Fetch info for several contracts in one request; unregistered ids come back in `not_found`
+    pub async fn get_contracts_info(&self, contract_ids: &[String]) -> Result<ContractsInfoResponse, String> {
+        let url = format!("{}/api/soroban/contracts/info", self.base_url);
+
+        let response = Request::post(&url)
+            .json(&ContractsInfoRequest { contract_ids: contract_ids.to_vec() })
+            .map_err(|e| format!("Request build error: {e}"))?
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {e}"))?;
+
+        if response.ok() {
+            response
+                .json::<ContractsInfoResponse>()
+                .await
+                .map_err(|e| format!("Response parse error: {e}"))
+        } else {
+            let body: serde_json::Value = response.json().await.unwrap_or_default();
+            Err(body["message"].as_str().map(str::to_string)
+                .unwrap_or_else(|| format!("HTTP error: {}", response.status())))
+        }
+    }
 }
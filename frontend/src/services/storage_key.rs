@@ -0,0 +1,190 @@
+/// Storage key builder for contract data queries
+///
+/// The backend expects storage keys as base64-encoded `ScVal` XDR. This module
+/// encodes the common key shapes (symbol, address, contract instance) without
+/// pulling a full XDR library into the WASM bundle.
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+/// `ScVal` discriminants used by the supported key types
+const SCV_SYMBOL: u32 = 15;
+const SCV_ADDRESS: u32 = 18;
+const SCV_LEDGER_KEY_CONTRACT_INSTANCE: u32 = 20;
+
+/// `ScAddress` discriminants
+const SC_ADDRESS_ACCOUNT: u32 = 0;
+const SC_ADDRESS_CONTRACT: u32 = 1;
+
+/// Strkey version bytes for account (G...) and contract (C...) addresses
+const STRKEY_ACCOUNT: u8 = 6 << 3;
+const STRKEY_CONTRACT: u8 = 2 << 3;
+
+/// Kind of storage key to build
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StorageKeyType {
+    Symbol,
+    Address,
+    Instance,
+}
+
+impl StorageKeyType {
+    pub fn all() -> Vec<StorageKeyType> {
+        vec![StorageKeyType::Symbol, StorageKeyType::Address, StorageKeyType::Instance]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StorageKeyType::Symbol => "symbol",
+            StorageKeyType::Address => "address",
+            StorageKeyType::Instance => "instance",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<StorageKeyType> {
+        Self::all().into_iter().find(|t| t.name() == name)
+    }
+
+    /// Whether this key type needs a user-supplied value
+    pub fn needs_value(&self) -> bool {
+        !matches!(self, StorageKeyType::Instance)
+    }
+}
+
+/// Build a base64 XDR `ScVal` storage key
+pub fn build_storage_key(key_type: StorageKeyType, value: &str) -> Result<String, String> {
+    let value = value.trim();
+    let mut xdr = Vec::new();
+
+    match key_type {
+        StorageKeyType::Symbol => {
+            if value.is_empty() || value.len() > 32 {
+                return Err("Symbol must be 1-32 characters".to_string());
+            }
+            if !value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err("Symbol may only contain a-z, A-Z, 0-9 and '_'".to_string());
+            }
+            push_u32(&mut xdr, SCV_SYMBOL);
+            push_opaque(&mut xdr, value.as_bytes());
+        }
+        StorageKeyType::Address => {
+            let (version, payload) = decode_strkey(value)?;
+            push_u32(&mut xdr, SCV_ADDRESS);
+            match version {
+                STRKEY_ACCOUNT => {
+                    push_u32(&mut xdr, SC_ADDRESS_ACCOUNT);
+                    // PublicKey::Ed25519
+                    push_u32(&mut xdr, 0);
+                }
+                STRKEY_CONTRACT => push_u32(&mut xdr, SC_ADDRESS_CONTRACT),
+                _ => return Err("Address must be a G... account or C... contract".to_string()),
+            }
+            xdr.extend_from_slice(&payload);
+        }
+        StorageKeyType::Instance => {
+            push_u32(&mut xdr, SCV_LEDGER_KEY_CONTRACT_INSTANCE);
+        }
+    }
+
+    Ok(STANDARD.encode(xdr))
+}
+
+fn push_u32(xdr: &mut Vec<u8>, value: u32) {
+    xdr.extend_from_slice(&value.to_be_bytes());
+}
+
+/// Variable-length opaque data: length prefix, bytes, zero padding to 4 bytes
+fn push_opaque(xdr: &mut Vec<u8>, bytes: &[u8]) {
+    push_u32(xdr, bytes.len() as u32);
+    xdr.extend_from_slice(bytes);
+    let padding = (4 - bytes.len() % 4) % 4;
+    xdr.extend(std::iter::repeat(0u8).take(padding));
+}
+
+/// Decode a Stellar strkey into its version byte and 32-byte payload
+fn decode_strkey(address: &str) -> Result<(u8, [u8; 32]), String> {
+    if address.len() != 56 {
+        return Err(format!("Address must be 56 characters, got {}", address.len()));
+    }
+
+    let bytes = decode_base32(address).ok_or_else(|| "Address is not valid base32".to_string())?;
+    if bytes.len() != 35 {
+        return Err("Address has an invalid length".to_string());
+    }
+
+    let (data, checksum) = bytes.split_at(33);
+    let expected = crc16_xmodem(data).to_le_bytes();
+    if checksum != expected {
+        return Err("Address checksum does not match".to_string());
+    }
+
+    let mut payload = [0u8; 32];
+    payload.copy_from_slice(&data[1..]);
+    Ok((data[0], payload))
+}
+
+/// RFC 4648 base32 decode (no padding)
+fn decode_base32(input: &str) -> Option<Vec<u8>> {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+    let mut output = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for c in input.bytes() {
+        let value = ALPHABET.iter().position(|&a| a == c)? as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(output)
+}
+
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+    }
+    crc
+}
+
+/// Remaining ledgers at which an entry is flagged as expiring (about a day of 5s ledgers)
+pub const EXPIRING_SOON_LEDGERS: u32 = 17_280;
+
+/// Lifetime of a storage entry relative to the latest ledger
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TtlStatus {
+    /// No TTL; the entry never expires
+    Permanent,
+    Live { remaining: u32 },
+    /// Fewer than `EXPIRING_SOON_LEDGERS` ledgers left
+    ExpiringSoon { remaining: u32 },
+    /// Past its TTL (archived or deleted)
+    Expired { ledgers_ago: u32 },
+}
+
+/// TTL status of an entry live until `live_until`, as of `latest_ledger`
+pub fn ttl_status(live_until: Option<u32>, latest_ledger: u32) -> TtlStatus {
+    match live_until {
+        None => TtlStatus::Permanent,
+        Some(live_until) if live_until < latest_ledger => TtlStatus::Expired {
+            ledgers_ago: latest_ledger - live_until,
+        },
+        Some(live_until) if live_until - latest_ledger < EXPIRING_SOON_LEDGERS => TtlStatus::ExpiringSoon {
+            remaining: live_until - latest_ledger,
+        },
+        Some(live_until) => TtlStatus::Live {
+            remaining: live_until - latest_ledger,
+        },
+    }
+}
+
+#[cfg(test)]
+#[path = "storage_key_test.rs"]
+mod storage_key_test;
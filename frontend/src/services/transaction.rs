@@ -1,14 +1,17 @@
 use gloo_net::http::Request;
 use serde::Serialize;
+use shared::dto::payment::PaymentRequest;
 use crate::types::{ContractFunction, XdrResponse, SubmitResponse};
 use crate::wallet::{ConnectedWallet, sign_transaction};
+use super::api::ApiClient;
+use super::settings::{Settings, backend_url, network_passphrase, signing_network};
 
 /// Generate XDR via backend service
-pub async fn generate_xdr(source_account: &str, wallet_type: &str, function: &ContractFunction) -> Result<String, String> {
+pub async fn generate_xdr(source_account: &str, wallet_type: &str, function: &ContractFunction) -> Result<XdrResponse, String> {
     let function_name = function.name();
 
-    let url = format!("http://127.0.0.1:3001/generate-xdr?source_account={}&wallet_type={}&function_name={}",
-                     source_account, wallet_type, function_name);
+    let url = format!("{}/generate-xdr?source_account={}&wallet_type={}&function_name={}",
+                     backend_url(), source_account, wallet_type, function_name);
 
     let response = Request::get(&url)
         .send()
@@ -25,7 +28,7 @@ pub async fn generate_xdr(source_account: &str, wallet_type: &str, function: &Co
         .map_err(|e| format!("Failed to parse response: {:?}", e))?;
 
     if xdr_response.success {
-        Ok(xdr_response.xdr)
+        Ok(xdr_response)
     } else {
         Err(xdr_response.message)
     }
@@ -33,6 +36,11 @@ pub async fn generate_xdr(source_account: &str, wallet_type: &str, function: &Co
 
 /// Submit signed transaction to backend and get contract result
 pub async fn submit_signed_transaction(signed_xdr: &str, wallet_type: &str, function: &ContractFunction) -> Result<String, String> {
+    submit_signed_xdr(signed_xdr, wallet_type, function.name()).await
+}
+
+/// Submit a signed envelope for any contract function by name
+async fn submit_signed_xdr(signed_xdr: &str, wallet_type: &str, function_name: &str) -> Result<String, String> {
     #[derive(Serialize)]
     struct SubmitRequest {
         signed_xdr: String,
@@ -43,10 +51,10 @@ pub async fn submit_signed_transaction(signed_xdr: &str, wallet_type: &str, func
     let payload = SubmitRequest {
         signed_xdr: signed_xdr.to_string(),
         wallet_type: Some(wallet_type.to_string()),
-        function_name: Some(function.name().to_string()),
+        function_name: Some(function_name.to_string()),
     };
 
-    let response = Request::post("http://127.0.0.1:3001/submit-transaction")
+    let response = Request::post(&format!("{}/submit-transaction", backend_url()))
         .json(&payload)
         .map_err(|e| format!("Failed to serialize request: {:?}", e))?
         .send()
@@ -77,14 +85,17 @@ pub async fn sign_hello_transaction(connected_wallet: &ConnectedWallet, function
     let source_account = &connected_wallet.address;
 
     // Step 1: Generate XDR via backend
-    let xdr = match generate_xdr(source_account, "freighter", function).await {
-        Ok(xdr) => xdr,
+    let generated = match generate_xdr(source_account, "freighter", function).await {
+        Ok(generated) => generated,
         Err(error) => return format!("XDR generation failed: {}", error),
     };
 
-    // Step 2: Sign with wallet
-    let network = "Test SDF Network ; September 2015";
-    let signed_xdr = match sign_transaction(&xdr, network).await {
+    // Step 2: Sign with wallet, for the preferred network
+    let network = match signing_network(Settings::load().network, &generated.network) {
+        Ok(network) => network,
+        Err(error) => return format!("Transaction signing failed: {}", error),
+    };
+    let signed_xdr = match sign_transaction(&generated.xdr, network).await {
         Ok(signed_xdr) => signed_xdr,
         Err(error) => return format!("Transaction signing failed: {}", error),
     };
@@ -94,4 +105,53 @@ pub async fn sign_hello_transaction(connected_wallet: &ConnectedWallet, function
         Ok(result) => result,
         Err(error) => format!("Transaction submission failed: {}", error),
     }
-}
\ No newline at end of file
+}
+
+/// Submit a leaderboard score: backend builds the transaction, the wallet signs, backend submits
+pub async fn sign_and_submit_score(connected_wallet: &ConnectedWallet, score: u64) -> Result<String, String> {
+    let player = &connected_wallet.address;
+
+    // Step 1: Build the submit_score transaction for this player
+    let unsigned = ApiClient::new().submit_score(player, score).await
+        .map_err(|error| format!("XDR generation failed: {}", error))?;
+
+    // Step 2: Sign with wallet, for the preferred network
+    let network = signing_network(Settings::load().network, &unsigned.network)
+        .map_err(|error| format!("Transaction signing failed: {}", error))?;
+    let signed_xdr = sign_transaction(&unsigned.xdr, network).await
+        .map_err(|error| format!("Transaction signing failed: {}", error))?;
+
+    // Step 3: Submit signed transaction to backend
+    submit_signed_xdr(&signed_xdr, "freighter", &unsigned.function_name).await
+        .map_err(|error| format!("Transaction submission failed: {}", error))
+}
+
+/// Send a classic payment: backend builds the transaction, the wallet signs, backend submits
+pub async fn sign_and_submit_payment(
+    connected_wallet: &ConnectedWallet,
+    destination: &str,
+    amount: &str,
+    asset: Option<String>,
+) -> Result<String, String> {
+    let request = PaymentRequest {
+        source: connected_wallet.address.clone(),
+        destination: destination.to_string(),
+        amount: amount.to_string(),
+        asset,
+        network: Some(network_passphrase().to_string()),
+    };
+
+    // Step 1: Build the payment transaction for the preferred network
+    let unsigned = ApiClient::new().generate_payment_xdr(&request).await
+        .map_err(|error| format!("XDR generation failed: {}", error))?;
+
+    // Step 2: Sign with wallet, for the network the backend built the transaction for
+    let network = signing_network(Settings::load().network, &unsigned.network)
+        .map_err(|error| format!("Transaction signing failed: {}", error))?;
+    let signed_xdr = sign_transaction(&unsigned.xdr, network).await
+        .map_err(|error| format!("Transaction signing failed: {}", error))?;
+
+    // Step 3: Submit through the same path as contract calls
+    submit_signed_xdr(&signed_xdr, "freighter", "payment").await
+        .map_err(|error| format!("Transaction submission failed: {}", error))
+}
@@ -2,6 +2,7 @@ use std::rc::Rc;
 use yew::Reducible;
 use crate::wallet::ConnectedWallet;
 use crate::types::ContractFunction;
+use crate::services::settings::{Settings, Theme};
 
 // Include tests module
 #[cfg(test)]
@@ -17,6 +18,7 @@ pub struct AppState {
     pub is_processing: bool,
     pub selected_function: Option<ContractFunction>,
     pub dark_mode: bool,
+    pub settings: Settings,
 }
 
 impl PartialEq for AppState {
@@ -27,6 +29,7 @@ impl PartialEq for AppState {
             && self.is_processing == other.is_processing
             && self.selected_function == other.selected_function
             && self.dark_mode == other.dark_mode
+            && self.settings == other.settings
     }
 }
 
@@ -39,6 +42,18 @@ impl Default for AppState {
             is_processing: false,
             selected_function: None,
             dark_mode: false,
+            settings: Settings::default(),
+        }
+    }
+}
+
+impl AppState {
+    /// Initial state using previously saved settings
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            dark_mode: settings.theme.is_dark(),
+            settings,
+            ..Self::default()
         }
     }
 }
@@ -52,6 +67,7 @@ pub enum AppMessage {
     SignTransaction,
     TransactionResult(String),
     ToggleDarkMode,
+    UpdateSettings(Settings),
 }
 
 impl Reducible for AppState {
@@ -94,8 +110,21 @@ impl Reducible for AppState {
 
             AppMessage::ToggleDarkMode => Self {
                 dark_mode: !self.dark_mode,
+                settings: Settings {
+                    theme: if self.dark_mode { Theme::Light } else { Theme::Dark },
+                    ..self.settings.clone()
+                },
                 ..(*self).clone()
             }.into(),
+
+            AppMessage::UpdateSettings(settings) => {
+                let settings = settings.sanitized();
+                Self {
+                    dark_mode: settings.theme.is_dark(),
+                    settings,
+                    ..(*self).clone()
+                }.into()
+            }
         }
     }
 }
\ No newline at end of file
@@ -6,6 +6,7 @@
 mod tests {
     use super::super::*;
     use crate::wallet::ConnectedWallet;
+    use crate::services::settings::{Settings, Theme};
     use std::rc::Rc;
 
     #[test]
@@ -99,6 +100,45 @@ mod tests {
         assert_eq!(new_state.dark_mode, false);
     }
 
+    #[test]
+    fn test_toggle_dark_mode_updates_theme_setting() {
+        let state = Rc::new(AppState::default());
+
+        let new_state = state.reduce(AppMessage::ToggleDarkMode);
+        assert_eq!(new_state.settings.theme, Theme::Dark);
+
+        let new_state = new_state.reduce(AppMessage::ToggleDarkMode);
+        assert_eq!(new_state.settings.theme, Theme::Light);
+    }
+
+    #[test]
+    fn test_update_settings() {
+        let state = Rc::new(AppState::default());
+
+        let settings = Settings {
+            backend_url: "http://localhost:4000/".to_string(),
+            polling_interval_ms: Some(5_000),
+            theme: Theme::Dark,
+            ..Settings::default()
+        };
+        let new_state = state.reduce(AppMessage::UpdateSettings(settings));
+
+        assert_eq!(new_state.settings.backend_url, "http://localhost:4000");
+        assert_eq!(new_state.settings.polling_interval_ms, Some(5_000));
+        assert_eq!(new_state.dark_mode, true);
+    }
+
+    #[test]
+    fn test_with_settings_applies_theme() {
+        let state = AppState::with_settings(Settings {
+            theme: Theme::Dark,
+            ..Settings::default()
+        });
+
+        assert_eq!(state.dark_mode, true);
+        assert_eq!(state.result_message, "Ready to connect with Freighter wallet");
+    }
+
     #[test]
     fn test_state_equality() {
         let state1 = AppState::default();
@@ -125,6 +165,7 @@ mod tests {
             is_processing: false,
             selected_function: Some(ContractFunction::Simple),
             dark_mode: true,
+            settings: Settings::default(),
         };
 
         let cloned = state.clone();
@@ -8,6 +8,9 @@ pub struct XdrResponse {
     pub success: bool,
     pub xdr: String,
     pub message: String,
+    /// Network passphrase the transaction was built for
+    #[serde(default)]
+    pub network: String,
 }
 
 /// Backend response for transaction submission
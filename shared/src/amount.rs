@@ -0,0 +1,173 @@
+//! Decimal amount parsing and formatting
+//!
+//! Amounts travel as integers in their smallest unit: classic Stellar amounts
+//! in stroops (7 decimals), Soroban tokens in whatever their `decimals()` says.
+//! Both directions are exact string arithmetic, never floats, so a value never
+//! drifts by a factor of 10^n between the UI and the ledger.
+
+use std::fmt;
+
+/// Decimal places of classic Stellar amounts (1 XLM = 10^7 stroops)
+pub const STELLAR_DECIMALS: u32 = 7;
+
+/// Why an amount string was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AmountError {
+    /// Nothing to parse
+    Empty,
+    /// Not a plain decimal number ("1e5", "1.2.3", "abc")
+    Malformed(String),
+    /// Amounts are never negative
+    Negative,
+    /// More fractional digits than the asset supports; amounts are never rounded
+    TooPrecise { decimals: u32 },
+    /// Doesn't fit in an i128 once scaled
+    Overflow,
+}
+
+impl fmt::Display for AmountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AmountError::Empty => write!(f, "Amount is required"),
+            AmountError::Malformed(amount) => write!(f, "Invalid amount: '{}'", amount),
+            AmountError::Negative => write!(f, "Amount cannot be negative"),
+            AmountError::TooPrecise { decimals } => write!(f, "Amount supports at most {} decimal places", decimals),
+            AmountError::Overflow => write!(f, "Amount is too large"),
+        }
+    }
+}
+
+impl std::error::Error for AmountError {}
+
+/// Parse a decimal string ("12.5") into an integer of the smallest unit
+///
+/// `parse_amount("12.5", 7) == Ok(125_000_000)`. Surrounding whitespace is
+/// ignored and trailing zeros beyond `decimals` are accepted, but any other
+/// excess precision is rejected rather than rounded. Zero is allowed; callers
+/// that need a positive amount check for it.
+pub fn parse_amount(amount: &str, decimals: u32) -> Result<i128, AmountError> {
+    let amount = amount.trim();
+    if amount.is_empty() {
+        return Err(AmountError::Empty);
+    }
+    if amount.starts_with('-') {
+        return Err(AmountError::Negative);
+    }
+
+    let malformed = || AmountError::Malformed(amount.to_string());
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    let is_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+        return Err(malformed());
+    }
+
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > decimals as usize {
+        return Err(AmountError::TooPrecise { decimals });
+    }
+
+    let scale = 10i128.checked_pow(decimals).ok_or(AmountError::Overflow)?;
+    let whole: i128 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().map_err(|_| AmountError::Overflow)?
+    };
+    let fraction: i128 = if fraction.is_empty() {
+        0
+    } else {
+        format!("{:0<width$}", fraction, width = decimals as usize)
+            .parse()
+            .map_err(|_| AmountError::Overflow)?
+    };
+
+    whole
+        .checked_mul(scale)
+        .and_then(|w| w.checked_add(fraction))
+        .ok_or(AmountError::Overflow)
+}
+
+/// Format an integer of the smallest unit with exactly `decimals` places
+///
+/// `format_amount(-1500, 2) == "-15.00"`; the inverse of [`parse_amount`].
+pub fn format_amount(value: i128, decimals: u32) -> String {
+    let sign = if value < 0 { "-" } else { "" };
+    let digits = value.unsigned_abs().to_string();
+    if decimals == 0 {
+        return format!("{}{}", sign, digits);
+    }
+
+    let decimals = decimals as usize;
+    let padded = format!("{:0>width$}", digits, width = decimals + 1);
+    let (whole, fraction) = padded.split_at(padded.len() - decimals);
+
+    format!("{}{}.{}", sign, whole, fraction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_amount() {
+        assert_eq!(parse_amount("1", STELLAR_DECIMALS), Ok(10_000_000));
+        assert_eq!(parse_amount("0.0000001", STELLAR_DECIMALS), Ok(1));
+        assert_eq!(parse_amount(" 12.5 ", STELLAR_DECIMALS), Ok(125_000_000));
+        assert_eq!(parse_amount(".5", STELLAR_DECIMALS), Ok(5_000_000));
+        assert_eq!(parse_amount("0", STELLAR_DECIMALS), Ok(0));
+        assert_eq!(parse_amount("42", 0), Ok(42));
+        assert_eq!(parse_amount("1.000000000000000001", 18), Ok(1_000_000_000_000_000_001));
+
+        for malformed in ["1e5", "abc", "1.2.3", ".", "+1", "1,5"] {
+            assert!(
+                matches!(parse_amount(malformed, STELLAR_DECIMALS), Err(AmountError::Malformed(_))),
+                "{} should be malformed",
+                malformed
+            );
+        }
+        assert_eq!(parse_amount("  ", STELLAR_DECIMALS), Err(AmountError::Empty));
+    }
+
+    #[test]
+    fn test_parse_amount_never_rounds() {
+        assert_eq!(
+            parse_amount("1.00000001", STELLAR_DECIMALS),
+            Err(AmountError::TooPrecise { decimals: STELLAR_DECIMALS })
+        );
+        assert_eq!(parse_amount("0.5", 0), Err(AmountError::TooPrecise { decimals: 0 }));
+
+        // Trailing zeros don't change the value, so they aren't excess precision
+        assert_eq!(parse_amount("1.50000000000", STELLAR_DECIMALS), Ok(15_000_000));
+        assert_eq!(parse_amount("7.0", 0), Ok(7));
+    }
+
+    #[test]
+    fn test_parse_amount_rejects_negative() {
+        assert_eq!(parse_amount("-1", STELLAR_DECIMALS), Err(AmountError::Negative));
+        assert_eq!(parse_amount(" -0.5", STELLAR_DECIMALS), Err(AmountError::Negative));
+    }
+
+    #[test]
+    fn test_parse_amount_overflow() {
+        let max_whole = (i128::MAX / 10_000_000).to_string();
+        assert!(parse_amount(&max_whole, STELLAR_DECIMALS).is_ok());
+        assert_eq!(parse_amount(&format!("{}0", max_whole), STELLAR_DECIMALS), Err(AmountError::Overflow));
+        assert_eq!(parse_amount("1", 39), Err(AmountError::Overflow));
+        assert_eq!(parse_amount(&"9".repeat(60), 0), Err(AmountError::Overflow));
+    }
+
+    #[test]
+    fn test_format_amount() {
+        assert_eq!(format_amount(125_000_000, STELLAR_DECIMALS), "12.5000000");
+        assert_eq!(format_amount(5, 3), "0.005");
+        assert_eq!(format_amount(-1500, 2), "-15.00");
+        assert_eq!(format_amount(42, 0), "42");
+        assert_eq!(format_amount(i128::MIN, 0), i128::MIN.to_string());
+    }
+
+    #[test]
+    fn test_format_parse_roundtrip() {
+        for (value, decimals) in [(1, 7), (123_456_789, 7), (10i128.pow(20), 18), (i128::MAX, 14), (0, 4)] {
+            assert_eq!(parse_amount(&format_amount(value, decimals), decimals), Ok(value));
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/// Classic Stellar account summary
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AccountResponse {
+    /// Account address (G...)
+    pub address: String,
+
+    /// Whether the account exists on the ledger (has been funded)
+    pub funded: bool,
+
+    /// Native XLM balance, e.g. "123.4567890"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance: Option<String>,
+
+    /// Native balance in stroops
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance_stroops: Option<i64>,
+
+    /// Current sequence number
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<String>,
+
+    /// Trustlines, offers, signers and data entries owned by the account
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_sub_entries: Option<u32>,
+
+    /// Ledger the account was read at
+    pub latest_ledger: u32,
+}
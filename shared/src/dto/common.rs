@@ -1,5 +1,13 @@
 use serde::{Deserialize, Serialize};
 
+/// Body of a non-2xx backend response (`AppError` rendered as JSON)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ErrorResponse {
+    pub success: bool,
+    pub error_type: String,
+    pub message: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ApiResponse<T> {
     pub success: bool,
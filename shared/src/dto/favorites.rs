@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// Request to add a contract to the current user's favorites
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AddFavoriteRequest {
+    pub contract_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+/// A contract in the current user's favorites
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Favorite {
+    pub contract_id: String,
+    pub label: Option<String>,
+    pub created_at: Option<String>,
+}
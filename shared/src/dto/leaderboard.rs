@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+/// Default number of entries returned by `GET /api/leaderboard/top`
+pub const DEFAULT_TOP_N: u32 = 10;
+
+/// Largest `n` accepted by `GET /api/leaderboard/top`
+pub const MAX_TOP_N: u32 = 100;
+
+/// Submit a score to the leaderboard contract
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitScoreRequest {
+    /// Player account (G...); also the transaction source that signs
+    pub player: String,
+    pub score: u64,
+}
+
+/// Unsigned transaction for the player to sign and submit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitScoreResponse {
+    pub success: bool,
+
+    /// Base64 transaction envelope, ready for wallet signing
+    pub xdr: String,
+
+    /// Contract function the transaction invokes, e.g. "submit_score"
+    pub function_name: String,
+
+    /// Network passphrase the transaction was built for, to sign with
+    pub network: String,
+}
+
+/// One ranked leaderboard entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    /// 1-based position, highest score first
+    pub rank: u32,
+    pub player: String,
+    pub score: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardResponse {
+    pub success: bool,
+    pub entries: Vec<LeaderboardEntry>,
+    pub count: usize,
+}
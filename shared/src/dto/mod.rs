@@ -1,4 +1,9 @@
 pub mod auth;
 pub mod user;
 pub mod common;
-pub mod soroban;
\ No newline at end of file
+pub mod soroban;
+pub mod favorites;
+pub mod account;
+pub mod leaderboard;
+pub mod presets;
+pub mod payment;
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// Asset code used for lumens; `"native"` is accepted too
+pub const NATIVE_ASSET: &str = "XLM";
+
+/// Build an unsigned classic payment for the source account to sign
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentRequest {
+    /// Paying account (G...); also the transaction source that signs
+    pub source: String,
+
+    /// Receiving account (G...)
+    pub destination: String,
+
+    /// Decimal amount in whole units, e.g. "12.5" (up to 7 decimal places)
+    pub amount: String,
+
+    /// `"XLM"`/`"native"`, or `"CODE:ISSUER"` for an issued asset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset: Option<String>,
+
+    /// Passphrase of the network the wallet will sign for; must match the server's
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// Unsigned payment transaction, ready for wallet signing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentXdrResponse {
+    pub success: bool,
+
+    /// Base64 transaction envelope
+    pub xdr: String,
+
+    /// Amount in stroops (1 unit = 10,000,000 stroops)
+    pub amount_stroops: i64,
+
+    /// Normalized asset, e.g. "XLM" or "USDC:GA5Z..."
+    pub asset: String,
+
+    /// Network passphrase the transaction was built for, to sign with
+    pub network: String,
+
+    /// RFC 3339 time after which the network rejects the transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<String>,
+}
@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::soroban::FunctionParameter;
+
+/// Reflector oracle (testnet)
+pub const REFLECTOR_ORACLE_ID: &str = "CCYOZJCOPG34LLQQ7N24YXBM7LL62R7ONMZ3G6WZAAYPB5OYKOMJRN63";
+/// Blend lending pool (testnet)
+pub const BLEND_POOL_ID: &str = "CDDG7DLOWSHRYQ2HWGZEZ4UTR7LPTKFFHN3QUCSZEXOWOPARMONX6T65";
+/// Blend backstop (testnet)
+pub const BLEND_BACKSTOP_ID: &str = "CBHWKF4RHIKOKSURAKXSJRIIA7RJAMJH4VHRVPYGUF4AJ5L544LYZ35X";
+
+/// Native XLM asset contract on testnet, used as the example asset address
+const TESTNET_XLM_SAC: &str = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
+/// Example account address for functions that take a user
+const EXAMPLE_ACCOUNT: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+
+/// Ready-made parameters for a known contract function
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParameterPreset {
+    pub function_name: String,
+
+    /// Short label for pickers, e.g. "BTC price"
+    pub label: String,
+
+    /// Parameters with example values filled in
+    pub parameters: Vec<FunctionParameter>,
+
+    /// What each example value stands for, e.g. "asset symbol (BTC, ETH, XLM)"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub placeholders: Vec<String>,
+}
+
+impl ParameterPreset {
+    fn new(function_name: &str, label: &str, parameters: Vec<FunctionParameter>) -> Self {
+        Self {
+            function_name: function_name.to_string(),
+            label: label.to_string(),
+            parameters,
+            placeholders: Vec::new(),
+        }
+    }
+
+    fn placeholder(mut self, description: &str) -> Self {
+        self.placeholders.push(description.to_string());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractPresetsResponse {
+    pub success: bool,
+    pub contract_id: String,
+    pub presets: Vec<ParameterPreset>,
+}
+
+/// Reflector's `Asset::Other(symbol)`
+fn reflector_asset(symbol: &str) -> FunctionParameter {
+    FunctionParameter::Enum("Other".to_string(), Some(Box::new(FunctionParameter::Symbol(symbol.to_string()))))
+}
+
+/// Presets for the protocols this scaffold ships with, keyed by (contract id, function name)
+pub fn parameter_presets() -> BTreeMap<(&'static str, String), ParameterPreset> {
+    let presets = [
+        (REFLECTOR_ORACLE_ID, ParameterPreset::new("lastprice", "Latest price", vec![reflector_asset("BTC")])
+            .placeholder("asset symbol (BTC, ETH, XLM, EUR)")),
+        (REFLECTOR_ORACLE_ID, ParameterPreset::new("price", "Price at timestamp", vec![
+            reflector_asset("BTC"),
+            FunctionParameter::U64(1_700_000_000),
+        ])
+            .placeholder("asset symbol (BTC, ETH, XLM, EUR)")
+            .placeholder("unix timestamp in seconds")),
+        (REFLECTOR_ORACLE_ID, ParameterPreset::new("decimals", "Price decimals", vec![])),
+        (REFLECTOR_ORACLE_ID, ParameterPreset::new("assets", "Supported assets", vec![])),
+        (BLEND_POOL_ID, ParameterPreset::new("get_reserve_list", "Reserve assets", vec![])),
+        (BLEND_POOL_ID, ParameterPreset::new("get_reserve", "Reserve details", vec![
+            FunctionParameter::Address(TESTNET_XLM_SAC.to_string()),
+        ])
+            .placeholder("asset contract address (C...)")),
+        (BLEND_POOL_ID, ParameterPreset::new("get_positions", "User positions", vec![
+            FunctionParameter::Address(EXAMPLE_ACCOUNT.to_string()),
+        ])
+            .placeholder("user account address (G...)")),
+        (BLEND_POOL_ID, ParameterPreset::new("get_admin", "Pool admin", vec![])),
+        (BLEND_BACKSTOP_ID, ParameterPreset::new("reward_zone", "Reward zone pools", vec![])),
+    ];
+
+    presets
+        .into_iter()
+        .map(|(contract_id, preset)| ((contract_id, preset.function_name.clone()), preset))
+        .collect()
+}
+
+/// Presets for one contract, in function-name order (empty for unknown contracts)
+pub fn presets_for_contract(contract_id: &str) -> Vec<ParameterPreset> {
+    parameter_presets()
+        .into_iter()
+        .filter(|((id, _), _)| *id == contract_id)
+        .map(|(_, preset)| preset)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reflector_lastprice_uses_asset_enum() {
+        let presets = parameter_presets();
+        let lastprice = &presets[&(REFLECTOR_ORACLE_ID, "lastprice".to_string())];
+
+        assert_eq!(
+            serde_json::to_value(&lastprice.parameters).unwrap(),
+            serde_json::json!([{"type": "enum", "value": ["Other", {"type": "symbol", "value": "BTC"}]}])
+        );
+        assert_eq!(lastprice.placeholders.len(), lastprice.parameters.len());
+    }
+
+    #[test]
+    fn test_presets_for_contract() {
+        let blend = presets_for_contract(BLEND_POOL_ID);
+        assert!(blend.iter().any(|p| p.function_name == "get_reserve_list"));
+        assert!(blend.iter().all(|p| p.function_name != "lastprice"));
+
+        assert!(presets_for_contract("CUNKNOWN").is_empty());
+    }
+}
@@ -13,6 +13,12 @@ pub struct ContractMetrics {
     pub cache_misses: u64,
     pub xdr_generated: u64,
     pub transactions_submitted: u64,
+    #[serde(default)]
+    pub simulations_in_flight: u64,
+    #[serde(default)]
+    pub max_concurrent_simulations: u64,
+    #[serde(default)]
+    pub simulations_rejected: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,6 +36,17 @@ pub struct PoolStats {
     pub total_connections: usize,
     pub max_connections: usize,
     pub available: usize,
+    /// Outbound RPC requests sent during the last second
+    #[serde(default)]
+    pub rpc_requests_per_second: usize,
+    #[serde(default)]
+    pub max_requests_per_second: u32,
+    /// Highest fraction of connections in use at once since startup
+    #[serde(default)]
+    pub peak_utilization: f64,
+    /// Times the pool stayed saturated long enough to raise a warning
+    #[serde(default)]
+    pub saturation_alerts: u64,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -45,6 +62,16 @@ pub struct CircuitBreakerStats {
     pub failure_count: u32,
     pub success_count: u32,
     pub is_open: bool,
+    /// Most recent failure, so operators can see why the circuit opened
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<LastError>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LastError {
+    pub message: String,
+    /// RFC 3339 timestamp of the failure
+    pub at: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -54,7 +81,7 @@ pub struct CacheStats {
     pub active_entries: usize,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum NetworkType {
     Testnet,
     Mainnet,
@@ -62,6 +89,55 @@ pub enum NetworkType {
     Standalone,
 }
 
+impl NetworkType {
+    pub const ALL: [NetworkType; 4] = [
+        NetworkType::Testnet,
+        NetworkType::Mainnet,
+        NetworkType::Futurenet,
+        NetworkType::Standalone,
+    ];
+
+    pub fn default_passphrase(&self) -> &'static str {
+        match self {
+            NetworkType::Testnet => "Test SDF Network ; September 2015",
+            NetworkType::Mainnet => "Public Global Stellar Network ; September 2015",
+            NetworkType::Futurenet => "Test SDF Future Network ; October 2022",
+            NetworkType::Standalone => "Standalone Network ; February 2017",
+        }
+    }
+
+    pub fn default_rpc_url(&self) -> &'static str {
+        match self {
+            NetworkType::Testnet => "https://soroban-testnet.stellar.org",
+            NetworkType::Mainnet => "https://mainnet.sorobanrpc.com",
+            NetworkType::Futurenet => "https://rpc-futurenet.stellar.org",
+            NetworkType::Standalone => "http://localhost:8000/soroban/rpc",
+        }
+    }
+
+    /// Identify the network from its passphrase (exact match, surrounding whitespace ignored)
+    pub fn from_passphrase(passphrase: &str) -> Option<Self> {
+        let passphrase = passphrase.trim();
+        Self::ALL.into_iter().find(|network| network.default_passphrase() == passphrase)
+    }
+}
+
+impl std::str::FromStr for NetworkType {
+    type Err = String;
+
+    /// Parse a network name ("testnet", "mainnet"/"public", "futurenet", "standalone"/"local"),
+    /// case-insensitively
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "testnet" => Ok(NetworkType::Testnet),
+            "mainnet" | "public" => Ok(NetworkType::Mainnet),
+            "futurenet" => Ok(NetworkType::Futurenet),
+            "standalone" | "local" => Ok(NetworkType::Standalone),
+            other => Err(format!("Unknown network: {}", other)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContractMetadata {
     pub contract_id: String,
@@ -80,6 +156,12 @@ pub struct ContractInfo {
     pub pool_stats: PoolStats,
     pub circuit_breaker_stats: CircuitBreakerStats,
     pub cache_stats: CacheStats,
+    /// Wasm hash the contract instance currently points to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wasm_hash: Option<String>,
+    /// True when the on-chain Wasm hash changed (contract was upgraded)
+    #[serde(default)]
+    pub upgraded: bool,
 }
 
 // API Response types - these are what the frontend receives
@@ -109,6 +191,22 @@ pub struct ListContractsResponse {
     pub count: usize,
 }
 
+/// Request for several contracts' info in one call (max 50)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractsInfoRequest {
+    pub contract_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractsInfoResponse {
+    pub success: bool,
+    /// Info for each registered contract, in request order
+    pub contracts: Vec<ContractInfo>,
+    /// Requested ids that aren't registered with the manager
+    #[serde(default)]
+    pub not_found: Vec<String>,
+}
+
 // ==================== EVENT QUERYING TYPES ====================
 
 /// Event type filter
@@ -173,6 +271,20 @@ pub struct EventDto {
     /// Transaction hash that emitted this event
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_hash: Option<String>,
+    /// Decoded price update (only for events from known price oracles)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub price_update: Option<ReflectorPriceEventDto>,
+}
+
+/// Typed Reflector oracle price update
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReflectorPriceEventDto {
+    /// Asset code (e.g. "BTC") or contract address for Stellar assets
+    pub asset: String,
+    /// Raw price value (14 decimals for Reflector)
+    pub price: String,
+    /// Unix timestamp (seconds) of the price update
+    pub timestamp: u64,
 }
 
 /// Response from get_events
@@ -195,6 +307,9 @@ pub struct GetEventsDto {
     /// Unix timestamp of oldest ledger close
     #[serde(skip_serializing_if = "Option::is_none")]
     pub oldest_ledger_close_time: Option<String>,
+    /// Ledger the query actually started from, when the requested start had been pruned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clamped_to: Option<u32>,
 }
 
 /// Request to query contract events
@@ -209,6 +324,10 @@ pub struct QueryEventsRequest {
     /// Optional result limit
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
+    /// Optional client-chosen subscription id; reconnecting with the same id
+    /// resumes from the last cursor delivered to it
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub subscription_id: Option<String>,
 }
 
 /// Response from query events endpoint
@@ -257,12 +376,12 @@ pub struct SimulateTransactionRequest {
 }
 
 /// Response from transaction simulation
+///
+/// Only successful simulations produce this; a contract rejection is returned
+/// as a 422 `CONTRACT_ERROR` instead.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SimulateTransactionResponseDto {
-    /// Success status
-    pub success: bool,
-
     /// Latest ledger at simulation time
     pub latest_ledger: u32,
 
@@ -270,10 +389,6 @@ pub struct SimulateTransactionResponseDto {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub min_resource_fee: Option<String>,
 
-    /// Error message if simulation failed
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<String>,
-
     /// Results from host function invocation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<SimulationResultDto>>,
@@ -286,6 +401,14 @@ pub struct SimulateTransactionResponseDto {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub restore_preamble: Option<RestorePreambleDto>,
 
+    /// Archived entries must be restored before this transaction can succeed
+    #[serde(default)]
+    pub requires_restore: bool,
+
+    /// Resource fee of the restore transaction, in stroops
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restore_fee_stroops: Option<u64>,
+
     /// Events emitted during simulation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub events: Option<Vec<String>>,
@@ -343,6 +466,48 @@ pub enum StateChangeKindDto {
     Deleted,
 }
 
+/// Request to simulate several transaction variants at once
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulateBatchRequest {
+    /// Simulations to run concurrently (max 10)
+    pub simulations: Vec<SimulateTransactionRequest>,
+}
+
+/// Cost summary of one simulation in a batch, for side-by-side comparison
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulateBatchItem {
+    /// Position of the simulation in the request
+    pub index: usize,
+
+    pub contract_id: String,
+
+    /// Whether the simulation succeeded
+    pub success: bool,
+
+    /// Recommended minimum resource fee (stringified number)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_resource_fee: Option<String>,
+
+    /// CPU instructions used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_instructions: Option<u64>,
+
+    /// Error message if this simulation failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// Error category (e.g. CONTRACT_ERROR, STELLAR_RPC_ERROR) if this simulation failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_type: Option<String>,
+}
+
+/// Response from the simulate batch endpoint, in request order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulateBatchResponse {
+    pub success: bool,
+    pub results: Vec<SimulateBatchItem>,
+}
+
 // ==================== CONTRACT STATE QUERYING TYPES ====================
 
 /// Storage durability for contract data
@@ -369,7 +534,7 @@ pub struct GetContractDataRequest {
 }
 
 /// Ledger entry result
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LedgerEntryResultDto {
     /// Ledger sequence when entry was last modified
@@ -401,6 +566,69 @@ pub struct GetContractDataResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<LedgerEntryResultDto>,
 
+    /// Stored value decoded to JSON (contract data entries only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
+
+    /// Latest ledger when the entry was read, for its remaining TTL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_ledger: Option<u32>,
+
+    /// Error message if failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// A storage key to include in a TTL report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TtlReportKey {
+    /// Storage key (base64 XDR encoded ScVal)
+    pub key: String,
+
+    /// Storage durability type
+    pub durability: DurabilityDto,
+}
+
+/// Request for a TTL report over a set of storage keys
+/// (the RPC cannot enumerate a contract's keys, so callers list them)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TtlReportRequest {
+    pub keys: Vec<TtlReportKey>,
+}
+
+/// TTL status of one storage entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TtlReportEntryDto {
+    /// Storage key as requested
+    pub key: String,
+
+    pub durability: DurabilityDto,
+
+    /// Whether the entry exists on the ledger
+    pub found: bool,
+
+    /// Ledger sequence when the entry expires
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_until_ledger_seq: Option<u32>,
+
+    /// Ledgers left before archival (negative once expired)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ledgers_until_expiry: Option<i64>,
+
+    /// Entry is past its TTL
+    pub expired: bool,
+}
+
+/// Response from the TTL report endpoint, entries sorted most at-risk first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TtlReportResponse {
+    pub success: bool,
+
+    /// Ledger the TTLs were computed against
+    pub latest_ledger: u32,
+
+    pub entries: Vec<TtlReportEntryDto>,
+
     /// Error message if failed
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
@@ -438,6 +666,41 @@ pub enum FunctionParameter {
     Enum(String, Option<Box<FunctionParameter>>),
 }
 
+/// Expected type of a contract call result, used to guide decoding
+///
+/// E.g. `{"type": "decimal", "value": 7}` renders an i128 price of `12345678` as `"1.2345678"`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
+pub enum ScTypeHint {
+    /// Address or 32-byte value rendered as an account strkey (G...)
+    AccountId,
+    /// Address or 32-byte value rendered as a contract strkey (C...)
+    ContractId,
+    /// Bytes rendered as hex
+    Bytes,
+    /// Bytes rendered as UTF-8 text
+    String,
+    /// Integer rendered as a decimal string with the given number of decimal places
+    Decimal(u32),
+    /// Vector whose elements all have the given type
+    Vec(Box<ScTypeHint>),
+    /// Map whose values all have the given type
+    Map(Box<ScTypeHint>),
+    /// Optional value (`Void` renders as null)
+    Option(Box<ScTypeHint>),
+    /// Tuple rendered as an object with named fields, e.g. `(Address, i128)` as `{"player": .., "score": ..}`
+    Tuple(Vec<TupleField>),
+}
+
+/// Named element of a tuple type hint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TupleField {
+    pub name: String,
+    /// Expected type of this element; decoded heuristically when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<ScTypeHint>,
+}
+
 /// Request to call a contract function (read-only via simulation)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CallContractFunctionRequest {
@@ -455,6 +718,31 @@ pub struct CallContractFunctionRequest {
     /// If not provided, uses a default testnet account
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_account: Option<String>,
+
+    /// Optional expected return type; when unset the result is decoded heuristically
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_type: Option<ScTypeHint>,
+
+    /// How the result may be served from cache
+    #[serde(default, skip_serializing_if = "CallCacheMode::is_default")]
+    pub cache_mode: CallCacheMode,
+}
+
+/// Caching policy for a contract function call
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CallCacheMode {
+    /// Reuse results for a fixed 60 seconds
+    #[default]
+    Ttl,
+    /// Reuse results only until the next ledger closes, so reads follow on-chain state
+    Ledger,
+}
+
+impl CallCacheMode {
+    pub fn is_default(&self) -> bool {
+        *self == Self::Ttl
+    }
 }
 
 /// Response from contract function call
@@ -478,6 +766,57 @@ pub struct CallContractFunctionResponse {
     /// Error message if failed
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+
+    /// Unprepared transaction XDR (explain mode only; nothing was simulated)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_xdr: Option<String>,
+
+    /// The invoke operation decoded from `transaction_xdr` (explain mode only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decoded_operation: Option<DecodedOperation>,
+}
+
+impl CallContractFunctionResponse {
+    /// Decode a call-function response body
+    ///
+    /// Calls the contract rejects come back as an error body (e.g. 422
+    /// CONTRACT_ERROR) rather than this type; those decode to an unsuccessful
+    /// response carrying the error message.
+    pub fn from_body(status_ok: bool, body: &str) -> Result<Self, serde_json::Error> {
+        if status_ok {
+            return serde_json::from_str(body);
+        }
+
+        let error: super::common::ErrorResponse = serde_json::from_str(body)?;
+        Ok(Self {
+            success: false,
+            result: None,
+            result_xdr: None,
+            simulation: None,
+            error: Some(error.message),
+            transaction_xdr: None,
+            decoded_operation: None,
+        })
+    }
+}
+
+/// Decoded view of a transaction operation, shown before the user signs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecodedOperation {
+    /// Operation type, e.g. "InvokeHostFunction" or "Payment"
+    pub kind: String,
+
+    /// Contract (C...) being invoked, for contract calls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract_id: Option<String>,
+
+    /// Contract function being invoked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function: Option<String>,
+
+    /// Call arguments decoded to JSON
+    #[serde(default)]
+    pub args: Vec<serde_json::Value>,
 }
 
 /// Simulation execution details
@@ -497,4 +836,200 @@ pub struct SimulationDetailsDto {
     /// Events emitted during simulation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub events: Option<Vec<String>>,
+
+    /// Archived entries must be restored before this call can succeed
+    #[serde(default)]
+    pub requires_restore: bool,
+
+    /// Resource fee of the restore transaction, in stroops
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restore_fee_stroops: Option<u64>,
+}
+
+// ==================== SERVER-SIDE SIGNING TYPES ====================
+
+/// Request to sign a transaction with the server's local signer and submit it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignAndSubmitRequest {
+    /// Unsigned transaction envelope (base64 XDR)
+    pub transaction_xdr: String,
+}
+
+/// Result of a server-side sign and submit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignAndSubmitResponse {
+    /// Whether the network accepted the transaction
+    pub success: bool,
+
+    /// Public key of the signer
+    pub signer: String,
+
+    /// Submission status (PENDING, DUPLICATE, TRY_AGAIN_LATER, ERROR)
+    pub status: String,
+
+    /// Transaction hash
+    pub hash: String,
+
+    /// Signed envelope that was submitted (base64 XDR)
+    pub signed_xdr: String,
+
+    /// Error message if failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+// ==================== OPERATION QUEUE TYPES ====================
+
+/// Operation waiting in the submission queue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedOperationDto {
+    pub id: String,
+    pub contract_id: String,
+    pub function_name: String,
+    pub source_account: String,
+    /// "Low", "Normal", "High" or "Critical"
+    pub priority: String,
+    pub retry_count: u32,
+    /// RFC 3339 timestamp the operation was queued
+    pub created_at: String,
+}
+
+/// Pending operations in the submission queue (admin only)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueueStatusResponse {
+    pub success: bool,
+    /// Total pending operations
+    pub pending: usize,
+    /// Pending operations per priority level
+    pub counts_by_priority: std::collections::BTreeMap<String, usize>,
+    /// In the order the worker will run them
+    pub operations: Vec<QueuedOperationDto>,
+}
+
+/// Result of flushing the submission queue (admin only)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueueFlushResponse {
+    pub success: bool,
+    /// Pending operations that were cancelled
+    pub dropped: usize,
+}
+
+// ==================== CONTRACT ID CONVERSION TYPES ====================
+
+/// Request to convert a contract id between strkey and hex forms
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertContractIdRequest {
+    /// Contract id as a `C...` strkey or 64-character hex string
+    pub contract_id: String,
+}
+
+/// Both forms of a contract id
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertContractIdResponse {
+    pub success: bool,
+
+    /// Contract strkey (C...)
+    pub strkey: String,
+
+    /// Contract hash as lowercase hex
+    pub hex: String,
+}
+
+// ==================== TOKEN BALANCE TYPES ====================
+
+/// Request for an account's balance of a token contract
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenBalanceRequest {
+    /// Account (G...) or contract (C...) holding the tokens
+    pub account: String,
+
+    /// Also read the token's `decimals()` and return a decimal-scaled amount
+    #[serde(default)]
+    pub scaled: bool,
+}
+
+/// Token balance as returned by the contract's `balance(Address)`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenBalanceResponse {
+    pub success: bool,
+
+    pub contract_id: String,
+
+    pub account: String,
+
+    /// Raw balance in the token's smallest unit (i128, as a string)
+    pub balance: String,
+
+    /// Token decimals (scaled requests only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decimals: Option<u32>,
+
+    /// Balance scaled by `decimals`, e.g. "12.5000000" (scaled requests only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+}
+
+// ==================== CONTRACT FUNCTION CATALOG TYPES ====================
+
+/// A contract function known to the generate-xdr flow
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractFunctionInfo {
+    /// Function name as it appears in the contract, e.g. "hello_yew"
+    pub name: String,
+
+    /// Display signature, e.g. "hello_yew(to: string) -> vec<string>"
+    pub signature: String,
+
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListFunctionsResponse {
+    pub success: bool,
+    pub functions: Vec<ContractFunctionInfo>,
+    pub count: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_passphrase_roundtrip() {
+        for network in NetworkType::ALL {
+            assert_eq!(NetworkType::from_passphrase(network.default_passphrase()), Some(network));
+        }
+
+        assert_eq!(
+            NetworkType::from_passphrase("  Test SDF Network ; September 2015\n"),
+            Some(NetworkType::Testnet)
+        );
+        assert_eq!(NetworkType::from_passphrase("Test SDF Network"), None);
+    }
+
+    #[test]
+    fn test_network_from_str() {
+        for network in NetworkType::ALL {
+            let name = format!("{:?}", network);
+            assert_eq!(name.parse::<NetworkType>(), Ok(network));
+            assert_eq!(name.to_uppercase().parse::<NetworkType>(), Ok(network));
+        }
+
+        assert_eq!("public".parse::<NetworkType>(), Ok(NetworkType::Mainnet));
+        assert!("devnet".parse::<NetworkType>().is_err());
+    }
+
+    #[test]
+    fn test_call_response_decodes_error_body() {
+        let body = r#"{"success":false,"error_type":"CONTRACT_ERROR","message":"Contract error: Error(Contract, #10)"}"#;
+        let response = CallContractFunctionResponse::from_body(false, body).unwrap();
+        assert!(!response.success);
+        assert_eq!(response.error.as_deref(), Some("Contract error: Error(Contract, #10)"));
+
+        // A success body is not mistaken for an error, nor an error body for a success
+        let response = CallContractFunctionResponse::from_body(true, r#"{"success":true,"result":7}"#).unwrap();
+        assert_eq!(response.result, Some(serde_json::json!(7)));
+        assert!(response.error.is_none());
+        assert!(CallContractFunctionResponse::from_body(false, r#"{"success":true,"result":7}"#).is_err());
+    }
 }